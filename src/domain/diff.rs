@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+use super::migration::{plan_migrations, SqlDialect, SqlMigration};
 use super::model::*;
+use super::registry::DomainRegistry;
 use super::to_snake;
+use super::types::{classify_change, TypeChange};
 
 /// Represents a change to the domain model for refactoring planning.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +34,32 @@ pub struct RefactoringPlan {
     pub model_changes: Vec<ModelChange>,
     pub code_actions: Vec<CodeAction>,
     pub migration_notes: Vec<String>,
+    /// SQL migration skeletons for `tech_stack.database` (PostgreSQL or SQLite)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub migrations: Vec<SqlMigration>,
+    /// Staged retirement of newly deprecated elements
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deprecations: Vec<DeprecationPlan>,
+}
+
+/// Retires a deprecated element in stages — add the replacement, migrate usages,
+/// delete — instead of one breaking delete.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeprecationPlan {
+    pub context: String,
+    pub kind: String,
+    pub element: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub replaced_by: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub sunset: String,
+    pub stages: Vec<PlanStage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanStage {
+    pub name: String,
+    pub actions: Vec<CodeAction>,
 }
 
 /// A concrete code action to perform.
@@ -105,6 +134,8 @@ pub fn diff_models(old: &DomainModel, new: &DomainModel) -> Vec<ModelChange> {
         }
     }
 
+    diff_external_systems(old, new, &mut changes);
+
     // Diff rules
     for new_rule in &new.rules {
         if !old.rules.iter().any(|r| r.id == new_rule.id) {
@@ -149,6 +180,84 @@ pub fn diff_models(old: &DomainModel, new: &DomainModel) -> Vec<ModelChange> {
     changes
 }
 
+/// Systems are matched by name; each integration is its own change so the planner
+/// can point at the adapter that gains or loses the call.
+fn diff_external_systems(old: &DomainModel, new: &DomainModel, changes: &mut Vec<ModelChange>) {
+    let integration_change = |kind, system: &ExternalSystem, integration: &Integration| {
+        let value = json!({
+            "system": system.name,
+            "protocol": system.protocol,
+            "context": integration.context,
+            "adapter": integration.adapter,
+        });
+        let (description, before, after) = match kind {
+            ChangeKind::Added => (
+                format!("'{}' integrates with external system '{}'", integration.context, system.name),
+                None,
+                Some(value),
+            ),
+            _ => (
+                format!("'{}' no longer integrates with external system '{}'", integration.context, system.name),
+                Some(value),
+                None,
+            ),
+        };
+        ModelChange {
+            kind,
+            path: format!("external_systems.{}.integrations.{}", system.name, integration.context),
+            description,
+            before,
+            after,
+        }
+    };
+
+    for system in &new.external_systems {
+        let old_system = old.find_external_system(&system.name);
+        match old_system {
+            None => changes.push(ModelChange {
+                kind: ChangeKind::Added,
+                path: format!("external_systems.{}", system.name),
+                description: format!("New external system: {}", system.name),
+                before: None,
+                after: Some(serde_json::to_value(system).unwrap()),
+            }),
+            Some(old_system) if old_system.protocol != system.protocol || old_system.description != system.description => {
+                changes.push(ModelChange {
+                    kind: ChangeKind::Modified,
+                    path: format!("external_systems.{}", system.name),
+                    description: format!("Modified external system: {}", system.name),
+                    before: Some(serde_json::to_value(old_system).unwrap()),
+                    after: Some(serde_json::to_value(system).unwrap()),
+                });
+            }
+            Some(_) => {}
+        }
+        for integration in &system.integrations {
+            if old_system.and_then(|s| s.integration(&integration.context)) != Some(integration) {
+                changes.push(integration_change(ChangeKind::Added, system, integration));
+            }
+        }
+    }
+
+    for old_system in &old.external_systems {
+        let new_system = new.find_external_system(&old_system.name);
+        if new_system.is_none() {
+            changes.push(ModelChange {
+                kind: ChangeKind::Removed,
+                path: format!("external_systems.{}", old_system.name),
+                description: format!("Removed external system: {}", old_system.name),
+                before: Some(serde_json::to_value(old_system).unwrap()),
+                after: None,
+            });
+        }
+        for integration in &old_system.integrations {
+            if new_system.and_then(|s| s.integration(&integration.context)) != Some(integration) {
+                changes.push(integration_change(ChangeKind::Removed, old_system, integration));
+            }
+        }
+    }
+}
+
 fn diff_context(old: &BoundedContext, new: &BoundedContext, changes: &mut Vec<ModelChange>) {
     let ctx = &new.name;
 
@@ -242,18 +351,19 @@ fn diff_context(old: &BoundedContext, new: &BoundedContext, changes: &mut Vec<Mo
 
     // Events
     for new_ev in &new.events {
-        if !old
+        match old
             .events
             .iter()
-            .any(|e| e.name.eq_ignore_ascii_case(&new_ev.name))
+            .find(|e| e.name.eq_ignore_ascii_case(&new_ev.name))
         {
-            changes.push(ModelChange {
+            None => changes.push(ModelChange {
                 kind: ChangeKind::Added,
                 path: format!("{ctx}.events.{}", new_ev.name),
                 description: format!("New event '{}' in context '{}'", new_ev.name, ctx),
                 before: None,
                 after: Some(serde_json::to_value(new_ev).unwrap()),
-            });
+            }),
+            Some(old_ev) => diff_event(ctx, old_ev, new_ev, changes),
         }
     }
     for old_ev in &old.events {
@@ -274,18 +384,26 @@ fn diff_context(old: &BoundedContext, new: &BoundedContext, changes: &mut Vec<Mo
 
     // Value objects
     for new_vo in &new.value_objects {
-        if !old
+        match old
             .value_objects
             .iter()
-            .any(|v| v.name.eq_ignore_ascii_case(&new_vo.name))
+            .find(|v| v.name.eq_ignore_ascii_case(&new_vo.name))
         {
-            changes.push(ModelChange {
+            None => changes.push(ModelChange {
                 kind: ChangeKind::Added,
                 path: format!("{ctx}.value_objects.{}", new_vo.name),
                 description: format!("New value object '{}' in context '{}'", new_vo.name, ctx),
                 before: None,
                 after: Some(serde_json::to_value(new_vo).unwrap()),
-            });
+            }),
+            Some(old_vo) => diff_fields(
+                &format!("{ctx}.value_objects.{}", new_vo.name),
+                "value object",
+                &new_vo.name,
+                &old_vo.fields,
+                &new_vo.fields,
+                changes,
+            ),
         }
     }
     for old_vo in &old.value_objects {
@@ -304,6 +422,80 @@ fn diff_context(old: &BoundedContext, new: &BoundedContext, changes: &mut Vec<Mo
         }
     }
 
+    // Enumerations
+    for new_en in &new.enumerations {
+        match old.find_enumeration(&new_en.name) {
+            None => changes.push(ModelChange {
+                kind: ChangeKind::Added,
+                path: format!("{ctx}.enumerations.{}", new_en.name),
+                description: format!("New enumeration '{}' in context '{}'", new_en.name, ctx),
+                before: None,
+                after: Some(serde_json::to_value(new_en).unwrap()),
+            }),
+            Some(old_en) => diff_variants(ctx, old_en, new_en, changes),
+        }
+    }
+    for old_en in &old.enumerations {
+        if new.find_enumeration(&old_en.name).is_none() {
+            changes.push(ModelChange {
+                kind: ChangeKind::Removed,
+                path: format!("{ctx}.enumerations.{}", old_en.name),
+                description: format!("Removed enumeration '{}' from context '{}'", old_en.name, ctx),
+                before: Some(serde_json::to_value(old_en).unwrap()),
+                after: None,
+            });
+        }
+    }
+
+    // Aggregates
+    for new_a in &new.aggregates {
+        match old
+            .aggregates
+            .iter()
+            .find(|a| a.name.eq_ignore_ascii_case(&new_a.name))
+        {
+            None => changes.push(ModelChange {
+                kind: ChangeKind::Added,
+                path: format!("{ctx}.aggregates.{}", new_a.name),
+                description: format!(
+                    "New aggregate '{}' with root '{}' in context '{}'",
+                    new_a.name, new_a.root, ctx
+                ),
+                before: None,
+                after: Some(serde_json::to_value(new_a).unwrap()),
+            }),
+            Some(old_a)
+                if !old_a.root.eq_ignore_ascii_case(&new_a.root)
+                    || old_a.entities != new_a.entities
+                    || old_a.value_objects != new_a.value_objects =>
+            {
+                changes.push(ModelChange {
+                    kind: ChangeKind::Modified,
+                    path: format!("{ctx}.aggregates.{}", new_a.name),
+                    description: format!("Aggregate '{}' boundary changed", new_a.name),
+                    before: Some(serde_json::to_value(old_a).unwrap()),
+                    after: Some(serde_json::to_value(new_a).unwrap()),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for old_a in &old.aggregates {
+        if !new
+            .aggregates
+            .iter()
+            .any(|a| a.name.eq_ignore_ascii_case(&old_a.name))
+        {
+            changes.push(ModelChange {
+                kind: ChangeKind::Removed,
+                path: format!("{ctx}.aggregates.{}", old_a.name),
+                description: format!("Removed aggregate '{}' from context '{}'", old_a.name, ctx),
+                before: Some(serde_json::to_value(old_a).unwrap()),
+                after: None,
+            });
+        }
+    }
+
     // Repositories
     for new_r in &new.repositories {
         if !old
@@ -336,6 +528,43 @@ fn diff_context(old: &BoundedContext, new: &BoundedContext, changes: &mut Vec<Mo
         }
     }
 
+    // Commands, queries and use cases
+    for kind in OperationKind::ALL {
+        diff_operations(ctx, kind, old.operations(kind), new.operations(kind), changes);
+    }
+
+    // Policies
+    for new_p in &new.policies {
+        match old.policies.iter().find(|p| p.name.eq_ignore_ascii_case(&new_p.name)) {
+            None => changes.push(ModelChange {
+                kind: ChangeKind::Added,
+                path: format!("{ctx}.policies.{}", new_p.name),
+                description: format!("New policy '{}' in context '{}'", new_p.name, ctx),
+                before: None,
+                after: Some(serde_json::to_value(new_p).unwrap()),
+            }),
+            Some(old_p) if old_p != new_p => changes.push(ModelChange {
+                kind: ChangeKind::Modified,
+                path: format!("{ctx}.policies.{}", new_p.name),
+                description: format!("Modified policy '{}'", new_p.name),
+                before: Some(serde_json::to_value(old_p).unwrap()),
+                after: Some(serde_json::to_value(new_p).unwrap()),
+            }),
+            Some(_) => {}
+        }
+    }
+    for old_p in &old.policies {
+        if !new.policies.iter().any(|p| p.name.eq_ignore_ascii_case(&old_p.name)) {
+            changes.push(ModelChange {
+                kind: ChangeKind::Removed,
+                path: format!("{ctx}.policies.{}", old_p.name),
+                description: format!("Removed policy '{}' from context '{}'", old_p.name, ctx),
+                before: Some(serde_json::to_value(old_p).unwrap()),
+                after: None,
+            });
+        }
+    }
+
     // Dependency changes
     for dep in &new.dependencies {
         if !old
@@ -367,6 +596,124 @@ fn diff_context(old: &BoundedContext, new: &BoundedContext, changes: &mut Vec<Mo
             });
         }
     }
+
+    // Lifecycle status of elements present in both models
+    let old_elements = old.elements();
+    for (kind, name, meta) in new.elements() {
+        let Some((_, _, old_meta)) = old_elements
+            .iter()
+            .find(|(k, n, _)| *k == kind && n.eq_ignore_ascii_case(name))
+        else {
+            continue;
+        };
+        if old_meta.status == meta.status {
+            continue;
+        }
+        let label = kind.replace('_', " ");
+        let description = match meta.status {
+            Status::Deprecated if !meta.replaced_by.is_empty() => {
+                format!("Deprecated {label} '{name}' in favour of '{}'", meta.replaced_by)
+            }
+            Status::Deprecated => format!("Deprecated {label} '{name}'"),
+            _ => format!("Status of {label} '{name}': {} → {}", old_meta.status.as_str(), meta.status.as_str()),
+        };
+        changes.push(ModelChange {
+            kind: ChangeKind::Modified,
+            path: format!("{ctx}.{}.{name}.status", element_key(kind)),
+            description,
+            before: Some(serde_json::to_value(old_meta).unwrap()),
+            after: Some(serde_json::to_value(meta).unwrap()),
+        });
+    }
+}
+
+/// The model key an element kind is listed under, e.g. `entity` → `entities`.
+fn element_key(kind: &str) -> &'static str {
+    match kind {
+        "entity" => "entities",
+        "value_object" => "value_objects",
+        "enumeration" => "enumerations",
+        "aggregate" => "aggregates",
+        "command" => "commands",
+        "query" => "queries",
+        "use_case" => "use_cases",
+        "policy" => "policies",
+        "service" => "services",
+        "repository" => "repositories",
+        _ => "events",
+    }
+}
+
+/// Whether a model key lists elements, e.g. `entities` — not an entity or field name.
+fn is_element_key(key: &str) -> bool {
+    ELEMENT_KINDS.iter().any(|&kind| element_key(kind) == key)
+}
+
+fn diff_operations(
+    ctx: &str,
+    kind: OperationKind,
+    old: &[Operation],
+    new: &[Operation],
+    changes: &mut Vec<ModelChange>,
+) {
+    let key = kind.key();
+    let label = kind.label().to_lowercase();
+    for new_op in new {
+        match old.iter().find(|o| o.name.eq_ignore_ascii_case(&new_op.name)) {
+            None => changes.push(ModelChange {
+                kind: ChangeKind::Added,
+                path: format!("{ctx}.{key}.{}", new_op.name),
+                description: format!("New {label} '{}' in context '{}'", new_op.name, ctx),
+                before: None,
+                after: Some(serde_json::to_value(new_op).unwrap()),
+            }),
+            Some(old_op) if old_op != new_op => changes.push(ModelChange {
+                kind: ChangeKind::Modified,
+                path: format!("{ctx}.{key}.{}", new_op.name),
+                description: format!("Modified {label} '{}'", new_op.name),
+                before: Some(serde_json::to_value(old_op).unwrap()),
+                after: Some(serde_json::to_value(new_op).unwrap()),
+            }),
+            Some(_) => {}
+        }
+    }
+    for old_op in old {
+        if !new.iter().any(|o| o.name.eq_ignore_ascii_case(&old_op.name)) {
+            changes.push(ModelChange {
+                kind: ChangeKind::Removed,
+                path: format!("{ctx}.{key}.{}", old_op.name),
+                description: format!("Removed {label} '{}' from context '{}'", old_op.name, ctx),
+                before: Some(serde_json::to_value(old_op).unwrap()),
+                after: None,
+            });
+        }
+    }
+}
+
+fn diff_variants(ctx: &str, old: &Enumeration, new: &Enumeration, changes: &mut Vec<ModelChange>) {
+    let name = &new.name;
+    for v in &new.variants {
+        if !old.variants.iter().any(|o| o.name.eq_ignore_ascii_case(&v.name)) {
+            changes.push(ModelChange {
+                kind: ChangeKind::Added,
+                path: format!("{ctx}.enumerations.{name}.variants.{}", v.name),
+                description: format!("New variant '{}' on enumeration '{}'", v.name, name),
+                before: None,
+                after: Some(serde_json::to_value(v).unwrap()),
+            });
+        }
+    }
+    for v in &old.variants {
+        if !new.variants.iter().any(|n| n.name.eq_ignore_ascii_case(&v.name)) {
+            changes.push(ModelChange {
+                kind: ChangeKind::Removed,
+                path: format!("{ctx}.enumerations.{name}.variants.{}", v.name),
+                description: format!("Removed variant '{}' from enumeration '{}'", v.name, name),
+                before: Some(serde_json::to_value(v).unwrap()),
+                after: None,
+            });
+        }
+    }
 }
 
 fn diff_entity(
@@ -391,18 +738,54 @@ fn diff_entity(
         });
     }
 
+    diff_fields(&format!("{ctx}.{name}"), "entity", name, &old.fields, &new.fields, changes);
+
+    // Lifecycle change
+    if old.state_machine != new.state_machine {
+        changes.push(ModelChange {
+            kind: ChangeKind::Modified,
+            path: format!("{ctx}.{name}.state_machine"),
+            description: format!("State machine of '{}' changed", name),
+            before: old.state_machine.as_ref().map(|sm| serde_json::to_value(sm).unwrap()),
+            after: new.state_machine.as_ref().map(|sm| serde_json::to_value(sm).unwrap()),
+        });
+    }
+
+    // Invariant changes
+    for inv in &new.invariants {
+        if !old.invariants.iter().any(|i| i == inv) {
+            changes.push(ModelChange {
+                kind: ChangeKind::Added,
+                path: format!("{ctx}.{name}.invariants"),
+                description: format!("New invariant on '{}': {}", name, inv),
+                before: None,
+                after: Some(json!(inv)),
+            });
+        }
+    }
+}
+
+/// Field additions, removals and type changes of one element; `owner` is the path
+/// prefix of its fields, e.g. `Identity.User` or `Identity.events.UserRegistered`.
+fn diff_fields(
+    owner: &str,
+    label: &str,
+    name: &str,
+    old: &[Field],
+    new: &[Field],
+    changes: &mut Vec<ModelChange>,
+) {
     // Fields added/removed
-    for new_f in &new.fields {
+    for new_f in new {
         if !old
-            .fields
             .iter()
             .any(|f| f.name.eq_ignore_ascii_case(&new_f.name))
         {
             changes.push(ModelChange {
                 kind: ChangeKind::Added,
-                path: format!("{ctx}.{name}.fields.{}", new_f.name),
+                path: format!("{owner}.fields.{}", new_f.name),
                 description: format!(
-                    "New field '{}: {}' on entity '{}'",
+                    "New field '{}: {}' on {label} '{}'",
                     new_f.name, new_f.field_type, name
                 ),
                 before: None,
@@ -410,56 +793,86 @@ fn diff_entity(
             });
         }
     }
-    for old_f in &old.fields {
+    for old_f in old {
         if !new
-            .fields
             .iter()
             .any(|f| f.name.eq_ignore_ascii_case(&old_f.name))
         {
             changes.push(ModelChange {
                 kind: ChangeKind::Removed,
-                path: format!("{ctx}.{name}.fields.{}", old_f.name),
-                description: format!("Removed field '{}' from entity '{}'", old_f.name, name),
+                path: format!("{owner}.fields.{}", old_f.name),
+                description: format!("Removed field '{}' from {label} '{}'", old_f.name, name),
                 before: Some(serde_json::to_value(old_f).unwrap()),
                 after: None,
             });
         }
     }
 
-    // Field type changes
-    for new_f in &new.fields {
+    // Field type and nullability changes
+    for new_f in new {
         if let Some(old_f) = old
-            .fields
             .iter()
             .find(|f| f.name.eq_ignore_ascii_case(&new_f.name))
         {
-            if old_f.field_type != new_f.field_type {
+            if let Some(change) = field_change(old_f, new_f) {
+                let what = match change {
+                    TypeChange::MadeOptional => "made optional",
+                    TypeChange::MadeRequired => "made required",
+                    TypeChange::Changed => "type changed",
+                };
+                let detail = match classify_change(&old_f.field_type, &new_f.field_type) {
+                    Some(_) => format!("{} → {}", old_f.field_type, new_f.field_type),
+                    None => format!("required {} → {}", old_f.required, new_f.required),
+                };
                 changes.push(ModelChange {
                     kind: ChangeKind::Modified,
-                    path: format!("{ctx}.{name}.fields.{}", new_f.name),
-                    description: format!(
-                        "Field '{}' on '{}' type changed: {} → {}",
-                        new_f.name, name, old_f.field_type, new_f.field_type
-                    ),
-                    before: Some(json!(old_f.field_type)),
-                    after: Some(json!(new_f.field_type)),
+                    path: format!("{owner}.fields.{}", new_f.name),
+                    description: format!("Field '{}' on '{}' {}: {}", new_f.name, name, what, detail),
+                    before: Some(serde_json::to_value(old_f).unwrap()),
+                    after: Some(serde_json::to_value(new_f).unwrap()),
                 });
             }
         }
     }
+}
 
-    // Invariant changes
-    for inv in &new.invariants {
-        if !old.invariants.iter().any(|i| i == inv) {
-            changes.push(ModelChange {
-                kind: ChangeKind::Added,
-                path: format!("{ctx}.{name}.invariants"),
-                description: format!("New invariant on '{}': {}", name, inv),
-                before: None,
-                after: Some(json!(inv)),
-            });
-        }
+/// How a field changed: its type, or — when only `required` flipped — whether it may be missing.
+fn field_change(old: &Field, new: &Field) -> Option<TypeChange> {
+    classify_change(&old.field_type, &new.field_type).or(match (old.required, new.required) {
+        (true, false) => Some(TypeChange::MadeOptional),
+        (false, true) => Some(TypeChange::MadeRequired),
+        _ => None,
+    })
+}
+
+fn diff_event(ctx: &str, old: &DomainEvent, new: &DomainEvent, changes: &mut Vec<ModelChange>) {
+    let name = &new.name;
+    let path = format!("{ctx}.events.{name}");
+
+    if old.published != new.published {
+        changes.push(ModelChange {
+            kind: ChangeKind::Modified,
+            path: format!("{path}.published"),
+            description: format!(
+                "Event '{}' {}",
+                name,
+                if new.published { "is now published to other contexts" } else { "is no longer published" }
+            ),
+            before: Some(json!(old.published)),
+            after: Some(json!(new.published)),
+        });
+    }
+    if old.channel != new.channel {
+        changes.push(ModelChange {
+            kind: ChangeKind::Modified,
+            path: format!("{path}.channel"),
+            description: format!("Channel of event '{}' changed: '{}' → '{}'", name, old.channel, new.channel),
+            before: Some(json!(old.channel)),
+            after: Some(json!(new.channel)),
+        });
     }
+
+    diff_fields(&path, "event", name, &old.fields, &new.fields, changes);
 }
 
 fn diff_service(
@@ -536,19 +949,25 @@ fn diff_service(
 }
 
 /// Generate a refactoring plan from model changes.
+/// `model` is the target model: its conventions drive file paths and its
+/// `tech_stack.database` selects the SQL dialect for migration skeletons.
 pub fn plan_refactoring(
     changes: &[ModelChange],
-    conventions: &Conventions,
+    model: &DomainModel,
 ) -> RefactoringPlan {
+    let conventions = &model.conventions;
     let mut code_actions = Vec::new();
     let mut migration_notes = Vec::new();
-    let pattern = &conventions.file_structure.pattern;
+    let mut deprecations = Vec::new();
 
     for change in changes {
         match &change.kind {
             ChangeKind::Added => {
                 let parts: Vec<&str> = change.path.split('.').collect();
                 match parts.as_slice() {
+                    ["external_systems", ..] => {
+                        code_actions.extend(integration_action(model, change));
+                    }
                     // New bounded context
                     [_bc_key, ctx_name] if change.path.starts_with("bounded_contexts.") => {
                         let ctx_snake = to_snake(ctx_name);
@@ -563,7 +982,7 @@ pub fn plan_refactoring(
                     }
                     // New entity
                     [ctx, _, entity_name] if change.path.contains(".entities.") => {
-                        let file = resolve_path(pattern, ctx, "domain", entity_name);
+                        let file = resolve_path(model, ctx, "entity", entity_name);
                         code_actions.push(CodeAction {
                             action: ActionKind::CreateFile,
                             file_path: file,
@@ -572,7 +991,7 @@ pub fn plan_refactoring(
                         });
                         code_actions.push(CodeAction {
                             action: ActionKind::AddTest,
-                            file_path: resolve_path(pattern, ctx, "domain", entity_name),
+                            file_path: resolve_path(model, ctx, "entity", entity_name),
                             description: format!("Add unit tests for entity '{entity_name}'"),
                             priority: Priority::Medium,
                         });
@@ -583,7 +1002,7 @@ pub fn plan_refactoring(
                     }
                     // New field on entity
                     [ctx, entity, _, field_name] if change.path.contains(".fields.") => {
-                        let file = resolve_path(pattern, ctx, "domain", entity);
+                        let file = resolve_path(model, ctx, "entity", entity);
                         code_actions.push(CodeAction {
                             action: ActionKind::ModifyFile,
                             file_path: file,
@@ -594,9 +1013,111 @@ pub fn plan_refactoring(
                             "New field '{field_name}' on '{entity}' — needs ALTER TABLE migration"
                         ));
                     }
+                    // New field on an event or value object
+                    [ctx, key @ ("events" | "value_objects"), owner, "fields", field_name] => {
+                        let kind = if *key == "events" { "event" } else { "value_object" };
+                        code_actions.push(CodeAction {
+                            action: ActionKind::ModifyFile,
+                            file_path: resolve_path(model, ctx, kind, owner),
+                            description: format!("Add field '{field_name}' to {} '{owner}'", kind.replace('_', " ")),
+                            priority: Priority::High,
+                        });
+                    }
+                    // New aggregate boundary
+                    [ctx, "aggregates", _] => {
+                        if let Some(agg) = change.after.as_ref().and_then(|v| serde_json::from_value::<Aggregate>(v.clone()).ok()) {
+                            code_actions.push(aggregate_boundary_action(model, ctx, &agg));
+                        }
+                    }
+                    // New enumeration
+                    [ctx, "enumerations", enum_name] => {
+                        code_actions.push(CodeAction {
+                            action: ActionKind::CreateFile,
+                            file_path: resolve_path(model, ctx, "enumeration", enum_name),
+                            description: format!("Create enumeration '{enum_name}'"),
+                            priority: Priority::Medium,
+                        });
+                    }
+                    // New variant: every exhaustive match and stored value constraint must learn it
+                    [ctx, "enumerations", enum_name, "variants", variant] => {
+                        code_actions.push(CodeAction {
+                            action: ActionKind::ModifyFile,
+                            file_path: resolve_path(model, ctx, "enumeration", enum_name),
+                            description: format!("Add variant '{variant}' to enumeration '{enum_name}'"),
+                            priority: Priority::High,
+                        });
+                        let usages = enumeration_usages(model, enum_name);
+                        code_actions.extend(usages.iter().map(|u| CodeAction {
+                            action: ActionKind::ModifyFile,
+                            file_path: resolve_path(model, &u.context, u.kind, &u.owner),
+                            description: format!(
+                                "Handle new variant '{variant}' in exhaustive matches on '{}.{}'",
+                                u.owner, u.field
+                            ),
+                            priority: Priority::High,
+                        }));
+                        migration_notes.push(format!(
+                            "New variant '{variant}' on '{enum_name}' — extend CHECK constraints or database enum types{}",
+                            usage_suffix(&usages)
+                        ));
+                    }
+                    // New command / query handler
+                    [ctx, key @ ("commands" | "queries"), op_name] => {
+                        let file = operation_file(model, ctx, key, op_name);
+                        let label = if *key == "commands" { "command" } else { "query" };
+                        code_actions.push(CodeAction {
+                            action: ActionKind::CreateFile,
+                            file_path: file.clone(),
+                            description: format!("Create handler for {label} '{op_name}'"),
+                            priority: Priority::High,
+                        });
+                        code_actions.push(CodeAction {
+                            action: ActionKind::AddTest,
+                            file_path: file,
+                            description: format!("Add tests for {label} '{op_name}' (invariants, emitted events)"),
+                            priority: Priority::Medium,
+                        });
+                    }
+                    // New use case
+                    [ctx, "use_cases", op_name] => {
+                        code_actions.push(CodeAction {
+                            action: ActionKind::CreateFile,
+                            file_path: operation_file(model, ctx, "use_cases", op_name),
+                            description: format!("Create use case '{op_name}'"),
+                            priority: Priority::High,
+                        });
+                    }
+                    // New policy
+                    [ctx, "policies", policy_name] => {
+                        let subscriptions = change
+                            .after
+                            .as_ref()
+                            .and_then(|v| serde_json::from_value::<Policy>(v.clone()).ok())
+                            .map(|p| p.reacts_to.join(", "))
+                            .unwrap_or_default();
+                        code_actions.push(CodeAction {
+                            action: ActionKind::CreateFile,
+                            file_path: resolve_path(model, ctx, "policy", policy_name),
+                            description: format!("Create policy '{policy_name}' subscribed to {subscriptions}"),
+                            priority: Priority::High,
+                        });
+                    }
+                    // New repository
+                    [ctx, "repositories", repo_name] => {
+                        let own = element_files(model, ctx, "repository", repo_name, None).swap_remove(0);
+                        code_actions.push(CodeAction {
+                            action: ActionKind::CreateFile,
+                            file_path: own.path,
+                            description: match own.role.as_str() {
+                                "source" => format!("Create repository '{repo_name}'"),
+                                role => format!("Create repository {role} '{repo_name}'"),
+                            },
+                            priority: Priority::High,
+                        });
+                    }
                     // New service
                     [ctx, _, svc_name] if change.path.contains(".services.") => {
-                        let file = resolve_path(pattern, ctx, "application", svc_name);
+                        let file = resolve_path(model, ctx, "service", svc_name);
                         code_actions.push(CodeAction {
                             action: ActionKind::CreateFile,
                             file_path: file,
@@ -606,7 +1127,7 @@ pub fn plan_refactoring(
                     }
                     // New event
                     [ctx, _, event_name] if change.path.contains(".events.") => {
-                        let file = resolve_path(pattern, ctx, "domain", event_name);
+                        let file = resolve_path(model, ctx, "event", event_name);
                         code_actions.push(CodeAction {
                             action: ActionKind::CreateFile,
                             file_path: file,
@@ -618,7 +1139,7 @@ pub fn plan_refactoring(
                     [ctx, entity, _] if change.path.contains(".invariants") => {
                         code_actions.push(CodeAction {
                             action: ActionKind::AddTest,
-                            file_path: resolve_path(pattern, ctx, "domain", entity),
+                            file_path: resolve_path(model, ctx, "entity", entity),
                             description: format!("Add test for new invariant on '{entity}'"),
                             priority: Priority::Medium,
                         });
@@ -634,17 +1155,30 @@ pub fn plan_refactoring(
                     }
                     _ => {}
                 }
+                code_actions.extend(companions(model, &parts, true));
             }
             ChangeKind::Removed => {
                 let parts: Vec<&str> = change.path.split('.').collect();
                 match parts.as_slice() {
+                    ["external_systems", ..] => {
+                        code_actions.extend(integration_action(model, change));
+                    }
                     [ctx, _, entity_name] if change.path.contains(".entities.") => {
-                        let file = resolve_path(pattern, ctx, "domain", entity_name);
-                        code_actions.push(CodeAction {
-                            action: ActionKind::DeleteFile,
-                            file_path: file,
-                            description: format!("Remove entity '{entity_name}' and all references"),
-                            priority: Priority::Critical,
+                        let file = resolve_path(model, ctx, "entity", entity_name);
+                        code_actions.push(if was_deprecated(change) {
+                            CodeAction {
+                                action: ActionKind::DeleteFile,
+                                file_path: file,
+                                description: format!("Delete deprecated entity '{entity_name}' — its usages were migrated while it was deprecated"),
+                                priority: Priority::High,
+                            }
+                        } else {
+                            CodeAction {
+                                action: ActionKind::DeleteFile,
+                                file_path: file,
+                                description: format!("Remove entity '{entity_name}' and all references"),
+                                priority: Priority::Critical,
+                            }
                         });
                         migration_notes.push(format!(
                             "Removed entity '{}' — needs DROP TABLE migration",
@@ -652,7 +1186,7 @@ pub fn plan_refactoring(
                         ));
                     }
                     [ctx, entity, _, field_name] if change.path.contains(".fields.") => {
-                        let file = resolve_path(pattern, ctx, "domain", entity);
+                        let file = resolve_path(model, ctx, "entity", entity);
                         code_actions.push(CodeAction {
                             action: ActionKind::ModifyFile,
                             file_path: file,
@@ -665,27 +1199,180 @@ pub fn plan_refactoring(
                             "Removed field '{field_name}' from '{entity}' — needs ALTER TABLE migration"
                         ));
                     }
-                    _ => {}
-                }
-            }
-            ChangeKind::Modified => {
-                let parts: Vec<&str> = change.path.split('.').collect();
-                match parts.as_slice() {
-                    [ctx, entity, _, field_name] if change.path.contains(".fields.") => {
-                        let file = resolve_path(pattern, ctx, "domain", entity);
+                    [ctx, key @ ("events" | "value_objects"), owner, "fields", field_name] => {
+                        let kind = if *key == "events" { "event" } else { "value_object" };
                         code_actions.push(CodeAction {
                             action: ActionKind::ModifyFile,
-                            file_path: file,
-                            description: format!(
-                                "Update field type for '{field_name}' on '{entity}'"
-                            ),
+                            file_path: resolve_path(model, ctx, kind, owner),
+                            description: format!("Remove field '{field_name}' from {} '{owner}'", kind.replace('_', " ")),
+                            priority: Priority::High,
+                        });
+                    }
+                    [ctx, "enumerations", enum_name] => {
+                        code_actions.push(CodeAction {
+                            action: ActionKind::DeleteFile,
+                            file_path: resolve_path(model, ctx, "enumeration", enum_name),
+                            description: format!("Remove enumeration '{enum_name}' and all references"),
                             priority: Priority::Critical,
                         });
-                        migration_notes.push(format!(
-                            "Field type change on '{entity}.{field_name}' — needs data migration"
-                        ));
                     }
-                    _ => {}
+                    [ctx, "enumerations", enum_name, "variants", variant] => {
+                        code_actions.push(CodeAction {
+                            action: ActionKind::ModifyFile,
+                            file_path: resolve_path(model, ctx, "enumeration", enum_name),
+                            description: format!("Remove variant '{variant}' from enumeration '{enum_name}'"),
+                            priority: Priority::Critical,
+                        });
+                        let usages = enumeration_usages(model, enum_name);
+                        code_actions.extend(usages.iter().map(|u| CodeAction {
+                            action: ActionKind::ModifyFile,
+                            file_path: resolve_path(model, &u.context, u.kind, &u.owner),
+                            description: format!(
+                                "Drop match arms and constructors for '{enum_name}::{variant}' on '{}.{}'",
+                                u.owner, u.field
+                            ),
+                            priority: Priority::Critical,
+                        }));
+                        migration_notes.push(format!(
+                            "Removed variant '{variant}' from '{enum_name}' — migrate stored '{variant}' values before deploying{}",
+                            usage_suffix(&usages)
+                        ));
+                    }
+                    [ctx, "repositories", repo_name] => {
+                        let own = element_files(model, ctx, "repository", repo_name, None).swap_remove(0);
+                        code_actions.push(CodeAction {
+                            action: ActionKind::DeleteFile,
+                            file_path: own.path,
+                            description: match own.role.as_str() {
+                                "source" => format!("Remove repository '{repo_name}'"),
+                                role => format!("Remove repository {role} '{repo_name}'"),
+                            },
+                            priority: Priority::High,
+                        });
+                    }
+                    [ctx, "policies", policy_name] => {
+                        code_actions.push(CodeAction {
+                            action: ActionKind::DeleteFile,
+                            file_path: resolve_path(model, ctx, "policy", policy_name),
+                            description: format!("Remove policy '{policy_name}' and its event subscriptions"),
+                            priority: Priority::High,
+                        });
+                    }
+                    [ctx, key @ ("commands" | "queries" | "use_cases"), op_name] => {
+                        code_actions.push(CodeAction {
+                            action: ActionKind::DeleteFile,
+                            file_path: operation_file(model, ctx, key, op_name),
+                            description: format!("Remove '{op_name}' and its callers"),
+                            priority: Priority::High,
+                        });
+                    }
+                    _ => {}
+                }
+                code_actions.extend(companions(model, &parts, false));
+            }
+            ChangeKind::Modified => {
+                let parts: Vec<&str> = change.path.split('.').collect();
+                match parts.as_slice() {
+                    [ctx, key, name, "status"] if is_element_key(key) => {
+                        let Some(meta) = change.after.as_ref().and_then(|v| serde_json::from_value::<Metadata>(v.clone()).ok()) else {
+                            continue;
+                        };
+                        if meta.status == Status::Deprecated {
+                            let plan = deprecation_plan(changes, model, ctx, key, name, &meta);
+                            let usages = plan.stages[1].actions.len();
+                            migration_notes.push(format!(
+                                "'{name}' deprecated — follow its staged plan: {}migrate {usages} usage(s), then delete",
+                                if meta.replaced_by.is_empty() { String::new() } else { format!("introduce '{}', ", meta.replaced_by) }
+                            ));
+                            deprecations.push(plan);
+                        }
+                    }
+                    [ctx, "aggregates", _] => {
+                        if let Some(agg) = change.after.as_ref().and_then(|v| serde_json::from_value::<Aggregate>(v.clone()).ok()) {
+                            code_actions.push(aggregate_boundary_action(model, ctx, &agg));
+                        }
+                    }
+                    [ctx, "policies", policy_name] => {
+                        code_actions.push(CodeAction {
+                            action: ActionKind::ModifyFile,
+                            file_path: resolve_path(model, ctx, "policy", policy_name),
+                            description: format!("Update subscriptions and issued commands of policy '{policy_name}'"),
+                            priority: Priority::Medium,
+                        });
+                    }
+                    [ctx, entity, "state_machine"] => {
+                        let decode = |v: &Option<serde_json::Value>| {
+                            v.as_ref().and_then(|v| serde_json::from_value::<StateMachine>(v.clone()).ok())
+                        };
+                        let (old_sm, new_sm) = (decode(&change.before), decode(&change.after));
+                        let file = resolve_path(model, ctx, "entity", entity);
+                        code_actions.push(CodeAction {
+                            action: ActionKind::ModifyFile,
+                            file_path: file.clone(),
+                            description: format!("Update lifecycle of '{entity}': guard each method by its allowed source states"),
+                            priority: Priority::High,
+                        });
+                        let old_transitions = old_sm.as_ref().map(|sm| sm.transitions.as_slice()).unwrap_or_default();
+                        for t in new_sm.iter().flat_map(|sm| &sm.transitions) {
+                            if !old_transitions.contains(t) {
+                                code_actions.push(CodeAction {
+                                    action: ActionKind::AddTest,
+                                    file_path: file.clone(),
+                                    description: format!("Test transition {} → {} via '{}' on '{entity}'", t.from, t.to, t.method),
+                                    priority: Priority::Medium,
+                                });
+                            }
+                        }
+                        let removed: Vec<&str> = old_sm
+                            .iter()
+                            .flat_map(|sm| &sm.states)
+                            .filter(|s| !new_sm.as_ref().is_some_and(|sm| sm.states.contains(s)))
+                            .map(|s| s.as_str())
+                            .collect();
+                        if !removed.is_empty() {
+                            migration_notes.push(format!(
+                                "States removed from '{entity}' lifecycle: {} — migrate rows still in them",
+                                removed.join(", ")
+                            ));
+                        }
+                    }
+                    [ctx, key @ ("commands" | "queries" | "use_cases"), op_name] => {
+                        code_actions.push(CodeAction {
+                            action: ActionKind::ModifyFile,
+                            file_path: operation_file(model, ctx, key, op_name),
+                            description: format!("Update '{op_name}' to match its spec (fields, invariants, emitted events)"),
+                            priority: Priority::Medium,
+                        });
+                    }
+                    [ctx, entity, _, field_name] if change.path.contains(".fields.") => {
+                        let file = resolve_path(model, ctx, "entity", entity);
+                        let type_change = field_versions(change).and_then(|(from, to)| field_change(&from, &to));
+                        code_actions.push(field_type_action(file, entity, field_name, type_change));
+                        migration_notes.push(match type_change {
+                            Some(TypeChange::MadeOptional) => {
+                                format!("Field '{entity}.{field_name}' made optional — drop its NOT NULL constraint")
+                            }
+                            Some(TypeChange::MadeRequired) => {
+                                format!("Field '{entity}.{field_name}' made required — backfill missing values before adding NOT NULL")
+                            }
+                            _ => format!("Field type change on '{entity}.{field_name}' — needs data migration"),
+                        });
+                    }
+                    [ctx, key @ ("events" | "value_objects"), owner, "fields", field_name] => {
+                        let kind = if *key == "events" { "event" } else { "value_object" };
+                        let type_change = field_versions(change).and_then(|(from, to)| field_change(&from, &to));
+                        let file = resolve_path(model, ctx, kind, owner);
+                        code_actions.push(field_type_action(file, owner, field_name, type_change));
+                    }
+                    [ctx, "events", event_name, attr @ ("published" | "channel")] => {
+                        code_actions.push(CodeAction {
+                            action: ActionKind::ModifyFile,
+                            file_path: resolve_path(model, ctx, "event", event_name),
+                            description: format!("Update how event '{event_name}' is published ({attr} changed)"),
+                            priority: Priority::High,
+                        });
+                    }
+                    _ => {}
                 }
             }
             ChangeKind::Moved => {
@@ -715,21 +1402,353 @@ pub fn plan_refactoring(
         Priority::Low => 3,
     });
 
+    let database = &model.tech_stack.database;
+    let migrations = match SqlDialect::from_database(database) {
+        Some(dialect) => plan_migrations(changes, model, dialect),
+        None => {
+            if !migration_notes.is_empty() {
+                migration_notes.push(if database.is_empty() {
+                    "No SQL generated: set tech_stack.database to PostgreSQL or SQLite".to_string()
+                } else {
+                    format!("No SQL generated: tech_stack.database '{database}' is not supported (PostgreSQL, SQLite)")
+                });
+            }
+            vec![]
+        }
+    };
+
     RefactoringPlan {
         model_changes: changes.to_vec(),
         code_actions,
         migration_notes,
+        migrations,
+        deprecations,
+    }
+}
+
+/// The old and new version of a modified field.
+fn field_versions(change: &ModelChange) -> Option<(Field, Field)> {
+    let decode = |v: &Option<serde_json::Value>| v.as_ref().and_then(|v| serde_json::from_value::<Field>(v.clone()).ok());
+    Some((decode(&change.before)?, decode(&change.after)?))
+}
+
+/// The code action for a field whose type changed on `owner`.
+fn field_type_action(file: String, owner: &str, field_name: &str, type_change: Option<TypeChange>) -> CodeAction {
+    let (description, priority) = match type_change {
+        Some(TypeChange::MadeOptional) => (
+            format!("Make field '{field_name}' on '{owner}' optional and handle the missing value at every use"),
+            Priority::High,
+        ),
+        Some(TypeChange::MadeRequired) => (
+            format!("Make field '{field_name}' on '{owner}' required and set it wherever '{owner}' is created"),
+            Priority::Critical,
+        ),
+        _ => (format!("Update field type for '{field_name}' on '{owner}'"), Priority::Critical),
+    };
+    CodeAction {
+        action: ActionKind::ModifyFile,
+        file_path: file,
+        description,
+        priority,
+    }
+}
+
+/// Three stages for a newly deprecated element: mark it and create its replacement,
+/// move every usage over, then delete it.
+fn deprecation_plan(
+    changes: &[ModelChange],
+    model: &DomainModel,
+    ctx: &str,
+    key: &str,
+    name: &str,
+    meta: &Metadata,
+) -> DeprecationPlan {
+    let kind = model
+        .bounded_contexts
+        .iter()
+        .find(|bc| bc.name.eq_ignore_ascii_case(ctx))
+        .and_then(|bc| bc.elements().into_iter().find(|(_, n, _)| n.eq_ignore_ascii_case(name)))
+        .map(|(kind, _, _)| kind)
+        .unwrap_or("entity");
+    let file = element_file(model, ctx, key, name);
+    let replacement = &meta.replaced_by;
+
+    let mut introduce = vec![CodeAction {
+        action: ActionKind::ModifyFile,
+        file_path: file.clone(),
+        description: match meta.deprecation_warning(name) {
+            Some(warning) => format!("Mark '{name}' deprecated in code: {warning}"),
+            None => format!("Mark '{name}' deprecated in code"),
+        },
+        priority: Priority::High,
+    }];
+    if !replacement.is_empty() {
+        let replacement_key = model
+            .bounded_contexts
+            .iter()
+            .find(|bc| bc.name.eq_ignore_ascii_case(ctx))
+            .and_then(|bc| bc.elements().into_iter().find(|(_, n, _)| n.eq_ignore_ascii_case(replacement)))
+            .map(|(kind, _, _)| element_key(kind))
+            .unwrap_or(key);
+        let is_new = changes.iter().any(|c| {
+            matches!(c.kind, ChangeKind::Added) && c.path.eq_ignore_ascii_case(&format!("{ctx}.{replacement_key}.{replacement}"))
+        });
+        if is_new {
+            introduce.push(CodeAction {
+                action: ActionKind::CreateFile,
+                file_path: element_file(model, ctx, replacement_key, replacement),
+                description: format!("Create '{replacement}', the replacement for '{name}', next to it"),
+                priority: Priority::High,
+            });
+        }
+    }
+
+    let migrate = DomainRegistry::new(model)
+        .usages(ctx, name)
+        .into_iter()
+        .filter(|u| !(u.context.eq_ignore_ascii_case(ctx) && u.element.eq_ignore_ascii_case(name)))
+        .map(|u| CodeAction {
+            action: ActionKind::ModifyFile,
+            file_path: element_file(model, &u.context, element_key(u.kind), &u.element),
+            description: if replacement.is_empty() {
+                format!("Stop using '{name}' in {} '{}' ({})", u.kind.replace('_', " "), u.element, u.via)
+            } else {
+                format!("Use '{replacement}' instead of '{name}' in {} '{}' ({})", u.kind.replace('_', " "), u.element, u.via)
+            },
+            priority: Priority::Medium,
+        })
+        .collect();
+
+    let delete = vec![CodeAction {
+        action: ActionKind::DeleteFile,
+        file_path: file,
+        description: if meta.sunset.is_empty() {
+            format!("Delete '{name}' once no usages remain")
+        } else {
+            format!("Delete '{name}' once no usages remain ({})", meta.sunset)
+        },
+        priority: Priority::Low,
+    }];
+
+    DeprecationPlan {
+        context: ctx.to_string(),
+        kind: kind.to_string(),
+        element: name.to_string(),
+        replaced_by: replacement.clone(),
+        sunset: meta.sunset.clone(),
+        stages: vec![
+            PlanStage {
+                name: "Introduce replacement".into(),
+                actions: introduce,
+            },
+            PlanStage {
+                name: "Migrate usages".into(),
+                actions: migrate,
+            },
+            PlanStage {
+                name: "Delete".into(),
+                actions: delete,
+            },
+        ],
+    }
+}
+
+/// Whether a removed element was deprecated before, i.e. its usages were already migrated.
+fn was_deprecated(change: &ModelChange) -> bool {
+    change.before.as_ref().is_some_and(|v| v["status"] == "deprecated")
+}
+
+/// The file an element lives in, by the model key it is listed under.
+fn element_file(model: &DomainModel, ctx: &str, key: &str, name: &str) -> String {
+    match key {
+        "commands" | "queries" | "use_cases" => operation_file(model, ctx, key, name),
+        _ => {
+            let kind = ELEMENT_KINDS.iter().find(|k| element_key(k) == key).unwrap_or(&"entity");
+            resolve_path(model, ctx, kind, name)
+        }
     }
 }
 
-fn resolve_path(pattern: &str, context: &str, layer: &str, name: &str) -> String {
-    if pattern.is_empty() {
-        return format!("src/{}/{}/{}.rs", to_snake(context), layer, to_snake(name));
+/// The adapter a context calls an external system through: the named service or,
+/// without one, a `{System}Client` in the infrastructure layer.
+fn integration_action(model: &DomainModel, change: &ModelChange) -> Option<CodeAction> {
+    let added = matches!(change.kind, ChangeKind::Added);
+    let value = if added { change.after.as_ref() } else { change.before.as_ref() }?;
+    let field = |key: &str| value[key].as_str().unwrap_or("").to_string();
+    let (system, context, adapter) = (field("system"), field("context"), field("adapter"));
+    if context.is_empty() {
+        return None;
     }
-    pattern
-        .replace("{context}", &to_snake(context))
-        .replace("{layer}", layer)
-        .replace("{type}", &to_snake(name))
+    let protocol = match field("protocol") {
+        p if p.is_empty() => String::new(),
+        p => format!(" ({p})"),
+    };
+
+    // An adapter is an infrastructure service unless the model says otherwise
+    let adapter_kind = model
+        .bounded_contexts
+        .iter()
+        .find(|bc| bc.name.eq_ignore_ascii_case(&context))
+        .and_then(|bc| bc.services.iter().find(|s| s.name.eq_ignore_ascii_case(&adapter)))
+        .map_or(&ServiceKind::Infrastructure, |s| &s.kind);
+
+    Some(if adapter.is_empty() {
+        let client = format!("{system}Client");
+        CodeAction {
+            action: if added { ActionKind::CreateFile } else { ActionKind::DeleteFile },
+            file_path: element_files(model, &context, "service", &client, Some(&ServiceKind::Infrastructure)).swap_remove(0).path,
+            description: if added {
+                format!("Create '{client}' for external system '{system}'{protocol}; keep its types out of the domain layer")
+            } else {
+                format!("Remove '{client}' — '{context}' no longer integrates with '{system}'")
+            },
+            priority: Priority::High,
+        }
+    } else {
+        CodeAction {
+            action: ActionKind::ModifyFile,
+            file_path: element_files(model, &context, "service", &adapter, Some(adapter_kind)).swap_remove(0).path,
+            description: if added {
+                format!("Call external system '{system}'{protocol} from adapter '{adapter}'")
+            } else {
+                format!("Remove calls to external system '{system}' from adapter '{adapter}'")
+            },
+            priority: Priority::High,
+        }
+    })
+}
+
+/// Child entities must only be mutated through the root: move their mutators onto it.
+fn aggregate_boundary_action(model: &DomainModel, ctx: &str, agg: &Aggregate) -> CodeAction {
+    let members = if agg.entities.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", agg.entities.join(", "))
+    };
+    CodeAction {
+        action: ActionKind::ModifyFile,
+        file_path: resolve_path(model, ctx, "entity", &agg.root),
+        description: format!(
+            "Enforce aggregate '{}': mutate child entities{} only through root '{}'",
+            agg.name, members, agg.root
+        ),
+        priority: Priority::High,
+    }
+}
+
+/// A field whose type refers to an enumeration.
+struct EnumerationUsage {
+    context: String,
+    /// Kind of `owner`: entity, value object or event
+    kind: &'static str,
+    owner: String,
+    field: String,
+}
+
+/// Entity, value object and event fields of any context whose type mentions `enum_name`.
+fn enumeration_usages(model: &DomainModel, enum_name: &str) -> Vec<EnumerationUsage> {
+    let mut usages = Vec::new();
+    for bc in &model.bounded_contexts {
+        let owners = bc
+            .entities
+            .iter()
+            .map(|e| ("entity", &e.name, &e.fields))
+            .chain(bc.value_objects.iter().map(|v| ("value_object", &v.name, &v.fields)))
+            .chain(bc.events.iter().map(|e| ("event", &e.name, &e.fields)));
+        for (kind, owner, fields) in owners {
+            for field in fields {
+                if type_names(&field.field_type).any(|t| t.eq_ignore_ascii_case(enum_name)) {
+                    usages.push(EnumerationUsage {
+                        context: bc.name.clone(),
+                        kind,
+                        owner: owner.clone(),
+                        field: field.name.clone(),
+                    });
+                }
+            }
+        }
+    }
+    usages
+}
+
+fn usage_suffix(usages: &[EnumerationUsage]) -> String {
+    if usages.is_empty() {
+        return String::new();
+    }
+    let fields: Vec<String> = usages.iter().map(|u| format!("{}.{}", u.owner, u.field)).collect();
+    format!(" (used by {})", fields.join(", "))
+}
+
+/// Commands and queries live in `{Name}Handler` files, use cases in their own file;
+/// all in the layer of their kind.
+fn operation_file(model: &DomainModel, ctx: &str, key: &str, name: &str) -> String {
+    match key {
+        "use_cases" => resolve_path(model, ctx, "use_case", name),
+        "queries" => resolve_path(model, ctx, "query", &format!("{name}Handler")),
+        _ => resolve_path(model, ctx, "command", &format!("{name}Handler")),
+    }
+}
+
+/// The companion actions of an added or removed element at `parts`, e.g. `Billing.entities.Invoice`.
+/// Aggregates have no files of their own; operations are named by their handler.
+fn companions(model: &DomainModel, parts: &[&str], added: bool) -> Vec<CodeAction> {
+    let [ctx, key, name] = parts else {
+        return vec![];
+    };
+    let Some(kind) = ELEMENT_KINDS.iter().find(|k| element_key(k) == *key && **k != "aggregate") else {
+        return vec![];
+    };
+    match *key {
+        "commands" | "queries" => companion_actions(model, ctx, kind, &format!("{name}Handler"), added),
+        _ => companion_actions(model, ctx, kind, name, added),
+    }
+}
+
+/// Actions for the files an added or removed element needs besides its own: creating
+/// or deleting them, (un)registering it in module files, adding or dropping tests.
+fn companion_actions(model: &DomainModel, ctx: &str, kind: &str, name: &str, added: bool) -> Vec<CodeAction> {
+    let label = kind.replace('_', " ");
+    element_files(model, ctx, kind, name, None)
+        .into_iter()
+        .skip(1)
+        .map(|file| {
+            let (action, description, priority) = match (file.role.as_str(), added) {
+                ("module", true) => (ActionKind::UpdateImports, format!("Register {label} '{name}' in its module"), Priority::Medium),
+                ("module", false) => (ActionKind::UpdateImports, format!("Unregister {label} '{name}' from its module"), Priority::Medium),
+                ("test", true) => (ActionKind::AddTest, format!("Add tests for {label} '{name}'"), Priority::Medium),
+                (role, true) => (ActionKind::CreateFile, format!("Create {label} {role} '{name}'"), Priority::High),
+                (role, false) => (ActionKind::DeleteFile, format!("Remove {label} {role} '{name}'"), Priority::High),
+            };
+            CodeAction {
+                action,
+                file_path: file.path,
+                description,
+                priority,
+            }
+        })
+        .collect()
+}
+
+/// Every file of an element, its own first. `service` overrides the kind of a service
+/// the model describes; one it does not describe counts as an application service.
+fn element_files(model: &DomainModel, context: &str, kind: &str, name: &str, service: Option<&ServiceKind>) -> Vec<ElementFile> {
+    let bc = model.bounded_contexts.iter().find(|bc| bc.name.eq_ignore_ascii_case(context));
+    let service = service.or_else(|| {
+        bc.and_then(|bc| bc.services.iter().find(|s| s.name.eq_ignore_ascii_case(name))).map(|s| &s.kind)
+    });
+    let vars = PathVars {
+        context,
+        module_path: bc.map_or("", |bc| &bc.module_path),
+        layer: "",
+        kind,
+        name,
+    };
+    model.conventions.file_structure.element_files(&vars, service)
+}
+
+/// The element's own file, in the layer the conventions map its kind to.
+fn resolve_path(model: &DomainModel, context: &str, kind: &str, name: &str) -> String {
+    element_files(model, context, kind, name, None).swap_remove(0).path
 }
 
 #[cfg(test)]
@@ -756,19 +1775,31 @@ mod tests {
                     }],
                     methods: vec![],
                     invariants: vec![],
+                    state_machine: None,
+                    meta: Metadata::default(),
                 }],
                 value_objects: vec![],
+                enumerations: vec![],
+                aggregates: vec![],
+                commands: vec![],
+                queries: vec![],
+                use_cases: vec![],
+                policies: vec![],
+                glossary: vec![],
                 services: vec![],
                 repositories: vec![],
                 events: vec![],
                 dependencies: vec![],
+                meta: Metadata::default(),
             }],
+            external_systems: vec![],
             rules: vec![],
             tech_stack: TechStack::default(),
             conventions: Conventions {
                 file_structure: FileStructure {
                     pattern: "src/{context}/{layer}/{type}.rs".into(),
                     layers: vec!["domain".into(), "application".into()],
+                    ..Default::default()
                 },
                 ..Default::default()
             },
@@ -793,6 +1824,8 @@ mod tests {
             fields: vec![],
             methods: vec![],
             invariants: vec![],
+            state_machine: None,
+            meta: Metadata::default(),
         });
         let changes = diff_models(&old, &new);
         assert_eq!(changes.len(), 1);
@@ -835,6 +1868,34 @@ mod tests {
         assert!(matches!(changes[0].kind, ChangeKind::Modified));
     }
 
+    #[test]
+    fn test_detect_field_made_optional() {
+        let old = base_model();
+        let mut new = base_model();
+        new.bounded_contexts[0].entities[0].fields[0].field_type = "UserId?".into();
+        let changes = diff_models(&old, &new);
+        assert!(changes[0].description.contains("made optional"));
+        let plan = plan_refactoring(&changes, &new);
+        assert!(plan.migration_notes[0].contains("drop its NOT NULL constraint"));
+
+        // Only the spelling changed
+        let mut respelled = new.clone();
+        respelled.bounded_contexts[0].entities[0].fields[0].field_type = "Option<UserId>".into();
+        assert!(diff_models(&new, &respelled).is_empty());
+    }
+
+    #[test]
+    fn test_detect_field_required_flip() {
+        let old = base_model();
+        let mut new = base_model();
+        new.bounded_contexts[0].entities[0].fields[0].required = false;
+        let changes = diff_models(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].description, "Field 'id' on 'User' made optional: required true → false");
+        let plan = plan_refactoring(&changes, &new);
+        assert!(plan.migration_notes[0].contains("drop its NOT NULL constraint"));
+    }
+
     #[test]
     fn test_detect_new_bounded_context() {
         let old = base_model();
@@ -845,10 +1906,18 @@ mod tests {
             module_path: "src/billing".into(),
             entities: vec![],
             value_objects: vec![],
+            enumerations: vec![],
+            aggregates: vec![],
+            commands: vec![],
+            queries: vec![],
+            use_cases: vec![],
+            policies: vec![],
+            glossary: vec![],
             services: vec![],
             repositories: vec![],
             events: vec![],
             dependencies: vec!["Identity".into()],
+            meta: Metadata::default(),
         });
         let changes = diff_models(&old, &new);
         // New context + new dependency
@@ -875,6 +1944,9 @@ mod tests {
                 description: "".into(),
                 fields: vec![],
                 source: "User".into(),
+                published: false,
+                channel: "".into(),
+                meta: Metadata::default(),
             });
             m
         };
@@ -884,6 +1956,65 @@ mod tests {
             && c.path.contains("UserCreated")));
     }
 
+    #[test]
+    fn test_detect_event_and_value_object_changes() {
+        let old = {
+            let mut m = base_model();
+            m.bounded_contexts[0].events.push(DomainEvent {
+                name: "UserCreated".into(),
+                description: "".into(),
+                fields: vec![Field {
+                    name: "user_id".into(),
+                    field_type: "UserId".into(),
+                    required: true,
+                    description: "".into(),
+                }],
+                source: "User".into(),
+                published: false,
+                channel: "".into(),
+                meta: Metadata::default(),
+            });
+            m.bounded_contexts[0].value_objects.push(ValueObject {
+                name: "Email".into(),
+                description: "".into(),
+                fields: vec![Field {
+                    name: "domain".into(),
+                    field_type: "String".into(),
+                    required: true,
+                    description: "".into(),
+                }],
+                validation_rules: vec![],
+                meta: Metadata::default(),
+            });
+            m
+        };
+        let mut new = old.clone();
+        let event = &mut new.bounded_contexts[0].events[0];
+        event.published = true;
+        event.channel = "identity.users".into();
+        event.fields[0].field_type = "Uuid".into();
+        new.bounded_contexts[0].value_objects[0].fields[0].field_type = "Option<String>".into();
+
+        let changes = diff_models(&old, &new);
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "Identity.events.UserCreated.published",
+                "Identity.events.UserCreated.channel",
+                "Identity.events.UserCreated.fields.user_id",
+                "Identity.value_objects.Email.fields.domain",
+            ]
+        );
+        assert!(changes[3].description.contains("made optional"));
+
+        let plan = plan_refactoring(&changes, &new);
+        assert!(plan.code_actions.iter().any(|a| a.file_path == "src/identity/domain/email.rs"
+            && a.description == "Make field 'domain' on 'Email' optional and handle the missing value at every use"));
+        assert!(plan.code_actions.iter().any(|a| a.description == "Update how event 'UserCreated' is published (channel changed)"));
+        assert!(plan.migration_notes.is_empty());
+    }
+
     #[test]
     fn test_detect_service_kind_change() {
         let old = {
@@ -894,6 +2025,7 @@ mod tests {
                 kind: ServiceKind::Domain,
                 methods: vec![],
                 dependencies: vec![],
+                meta: Metadata::default(),
             });
             m
         };
@@ -913,6 +2045,7 @@ mod tests {
             description: "".into(),
             fields: vec![],
             validation_rules: vec![],
+            meta: Metadata::default(),
         });
         let changes = diff_models(&old, &new);
         assert!(changes.iter().any(|c| matches!(c.kind, ChangeKind::Added)
@@ -927,6 +2060,7 @@ mod tests {
             name: "UserRepository".into(),
             aggregate: "User".into(),
             methods: vec![],
+            meta: Metadata::default(),
         });
         let changes = diff_models(&old, &new);
         assert!(changes.iter().any(|c| matches!(c.kind, ChangeKind::Added)
@@ -942,6 +2076,7 @@ mod tests {
                 description: "Old description".into(),
                 severity: Severity::Warning,
                 scope: "".into(),
+                check: "".into(),
             });
             m
         };
@@ -952,6 +2087,50 @@ mod tests {
             && c.path.contains("RULE-1")));
     }
 
+    #[test]
+    fn test_plan_refactoring_stages_deprecation() {
+        let mut old = base_model();
+        old.bounded_contexts[0].repositories.push(Repository {
+            name: "UserRepository".into(),
+            aggregate: "User".into(),
+            methods: vec![],
+            meta: Metadata::default(),
+        });
+        let mut new = old.clone();
+        let mut account = new.bounded_contexts[0].entities[0].clone();
+        account.name = "Account".into();
+        new.bounded_contexts[0].entities.push(account);
+        let meta = &mut new.bounded_contexts[0].entities[0].meta;
+        meta.status = Status::Deprecated;
+        meta.replaced_by = "Account".into();
+        meta.sunset = "after 2.0".into();
+        new.validate().unwrap();
+
+        let changes = diff_models(&old, &new);
+        assert!(changes.iter().any(|c| c.path == "Identity.entities.User.status"
+            && c.description == "Deprecated entity 'User' in favour of 'Account'"));
+        let plan = plan_refactoring(&changes, &new);
+        let staged = &plan.deprecations[0];
+        assert_eq!(staged.element, "User");
+        let actions: Vec<Vec<&str>> = staged
+            .stages
+            .iter()
+            .map(|s| s.actions.iter().map(|a| a.description.as_str()).collect())
+            .collect();
+        assert_eq!(actions[0][1], "Create 'Account', the replacement for 'User', next to it");
+        assert_eq!(actions[1], vec!["Use 'Account' instead of 'User' in repository 'UserRepository' (aggregate)"]);
+        assert_eq!(staged.stages[1].actions[0].file_path, "src/identity/infrastructure/user_repository.rs");
+        assert_eq!(actions[2], vec!["Delete 'User' once no usages remain (after 2.0)"]);
+
+        // Deleting it later is no longer a critical, unannounced removal
+        let mut done = new.clone();
+        done.bounded_contexts[0].entities.remove(0);
+        done.bounded_contexts[0].repositories[0].aggregate = "Account".into();
+        let plan = plan_refactoring(&diff_models(&new, &done), &done);
+        assert!(plan.code_actions.iter().any(|a| a.description.starts_with("Delete deprecated entity 'User'")
+            && matches!(a.priority, Priority::High)));
+    }
+
     #[test]
     fn test_plan_refactoring_creates_file_for_new_entity() {
         let old = base_model();
@@ -963,14 +2142,259 @@ mod tests {
             fields: vec![],
             methods: vec![],
             invariants: vec![],
+            state_machine: None,
+            meta: Metadata::default(),
         });
         let changes = diff_models(&old, &new);
-        let plan = plan_refactoring(&changes, &new.conventions);
+        let plan = plan_refactoring(&changes, &new);
         assert!(!plan.code_actions.is_empty());
         assert!(plan.code_actions.iter().any(|a| matches!(a.action, ActionKind::CreateFile)
             && a.file_path.contains("role")));
     }
 
+    #[test]
+    fn test_plan_refactoring_creates_handler_for_new_command() {
+        let old = base_model();
+        let mut new = base_model();
+        new.bounded_contexts[0].commands.push(Operation {
+            name: "DeactivateUser".into(),
+            description: "".into(),
+            fields: vec![],
+            aggregate: "User".into(),
+            emits: vec![],
+            invariants: vec![],
+            handler: "".into(),
+            returns: "".into(),
+            steps: vec![],
+            meta: Metadata::default(),
+        });
+        let changes = diff_models(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "Identity.commands.DeactivateUser");
+
+        let plan = plan_refactoring(&changes, &new);
+        assert!(plan.code_actions.iter().any(|a| matches!(a.action, ActionKind::CreateFile)
+            && a.file_path == "src/identity/application/deactivate_user_handler.rs"));
+
+        let mut changed = new.clone();
+        changed.bounded_contexts[0].commands[0].invariants.push("User must be active".into());
+        let changes = diff_models(&new, &changed);
+        assert!(matches!(changes[0].kind, ChangeKind::Modified));
+    }
+
+    #[test]
+    fn test_plan_refactoring_creates_policy() {
+        let old = base_model();
+        let mut new = base_model();
+        new.bounded_contexts[0].policies.push(Policy {
+            name: "WelcomeUser".into(),
+            description: "".into(),
+            kind: PolicyKind::Policy,
+            reacts_to: vec!["UserRegistered".into()],
+            issues: vec![],
+            meta: Metadata::default(),
+        });
+        let changes = diff_models(&old, &new);
+        assert_eq!(changes[0].path, "Identity.policies.WelcomeUser");
+        let plan = plan_refactoring(&changes, &new);
+        assert_eq!(plan.code_actions[0].file_path, "src/identity/application/welcome_user.rs");
+        assert!(plan.code_actions[0].description.contains("subscribed to UserRegistered"));
+    }
+
+    #[test]
+    fn test_plan_refactoring_external_integration() {
+        let stripe = |adapter: &str| ExternalSystem {
+            name: "Stripe".into(),
+            description: "".into(),
+            protocol: "REST".into(),
+            integrations: vec![Integration {
+                context: "Identity".into(),
+                adapter: adapter.into(),
+            }],
+        };
+        let old = base_model();
+        let mut new = base_model();
+        new.external_systems.push(stripe(""));
+        let changes = diff_models(&old, &new);
+        let paths: Vec<_> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["external_systems.Stripe", "external_systems.Stripe.integrations.Identity"]);
+        let plan = plan_refactoring(&changes, &new);
+        assert_eq!(plan.code_actions.len(), 1);
+        assert_eq!(plan.code_actions[0].file_path, "src/identity/infrastructure/stripe_client.rs");
+
+        // Moving the call behind an adapter replaces the integration
+        let mut adapted = new.clone();
+        adapted.external_systems[0] = stripe("PaymentGateway");
+        let changes = diff_models(&new, &adapted);
+        let plan = plan_refactoring(&changes, &adapted);
+        assert!(matches!(plan.code_actions[0].action, ActionKind::ModifyFile));
+        assert!(plan.code_actions[0].description.contains("from adapter 'PaymentGateway'"));
+        assert!(matches!(plan.code_actions[1].action, ActionKind::DeleteFile));
+    }
+
+    #[test]
+    fn test_plan_refactoring_follows_layer_mapping() {
+        let old = base_model();
+        let mut new = base_model();
+        new.conventions.file_structure.service_layers.insert("infrastructure".into(), "adapters".into());
+        new.bounded_contexts[0].services.push(Service {
+            name: "MailSender".into(),
+            description: "".into(),
+            kind: ServiceKind::Infrastructure,
+            methods: vec![],
+            dependencies: vec![],
+            meta: Metadata::default(),
+        });
+        new.bounded_contexts[0].repositories.push(Repository {
+            name: "UserRepository".into(),
+            aggregate: "User".into(),
+            methods: vec![],
+            meta: Metadata::default(),
+        });
+        let plan = plan_refactoring(&diff_models(&old, &new), &new);
+        let files: Vec<_> = plan.code_actions.iter().map(|a| (a.file_path.as_str(), a.description.as_str())).collect();
+        assert_eq!(
+            files,
+            vec![
+                ("src/identity/adapters/mail_sender.rs", "Create service 'MailSender'"),
+                ("src/identity/infrastructure/user_repository.rs", "Create repository implementation 'UserRepository'"),
+                ("src/identity/domain/user_repository.rs", "Create repository trait 'UserRepository'"),
+            ]
+        );
+
+        // Trait and implementation in one layer share a file
+        let mut removed = old.clone();
+        removed.conventions.file_structure.repository_layers.interface = "infrastructure".into();
+        let plan = plan_refactoring(&diff_models(&new, &removed), &removed);
+        let files: Vec<_> = plan.code_actions.iter().map(|a| (a.file_path.as_str(), a.description.as_str())).collect();
+        assert_eq!(files, vec![("src/identity/infrastructure/user_repository.rs", "Remove repository 'UserRepository'")]);
+    }
+
+    #[test]
+    fn test_plan_refactoring_creates_artifacts() {
+        let old = base_model();
+        let mut new = base_model();
+        new.conventions.file_structure.artifacts.insert(
+            "event".into(),
+            vec![
+                ArtifactPattern {
+                    role: "source".into(),
+                    pattern: "{module_path}/events/{type}.rs".into(),
+                    layer: "".into(),
+                },
+                ArtifactPattern {
+                    role: "module".into(),
+                    pattern: "{module_path}/events/mod.rs".into(),
+                    layer: "".into(),
+                },
+                ArtifactPattern {
+                    role: "test".into(),
+                    pattern: "tests/{context}/{type}_test.rs".into(),
+                    layer: "".into(),
+                },
+            ],
+        );
+        new.bounded_contexts[0].events.push(DomainEvent {
+            name: "UserRenamed".into(),
+            description: "".into(),
+            fields: vec![],
+            source: "User".into(),
+            published: false,
+            channel: "".into(),
+            meta: Metadata::default(),
+        });
+        let plan = plan_refactoring(&diff_models(&old, &new), &new);
+        let actions: Vec<_> = plan.code_actions.iter().map(|a| (a.file_path.as_str(), a.description.as_str())).collect();
+        assert_eq!(
+            actions,
+            vec![
+                ("src/identity/events/user_renamed.rs", "Create domain event 'UserRenamed'"),
+                ("src/identity/events/mod.rs", "Register event 'UserRenamed' in its module"),
+                ("tests/identity/user_renamed_test.rs", "Add tests for event 'UserRenamed'"),
+            ]
+        );
+        assert!(matches!(plan.code_actions[1].action, ActionKind::UpdateImports));
+
+        let plan = plan_refactoring(&diff_models(&new, &old), &new);
+        let removed: Vec<_> = plan.code_actions.iter().map(|a| a.description.as_str()).collect();
+        assert_eq!(removed, vec!["Remove event test 'UserRenamed'", "Unregister event 'UserRenamed' from its module"]);
+    }
+
+    #[test]
+    fn test_plan_refactoring_state_machine_change() {
+        let lifecycle = |states: &[&str]| StateMachine {
+            field: "".into(),
+            states: states.iter().map(|s| s.to_string()).collect(),
+            initial: "Active".into(),
+            transitions: vec![Transition {
+                from: "Active".into(),
+                to: "Suspended".into(),
+                method: "suspend".into(),
+                event: "".into(),
+            }],
+        };
+        let mut old = base_model();
+        old.bounded_contexts[0].entities[0].state_machine = Some(lifecycle(&["Active", "Suspended", "Archived"]));
+        let mut new = base_model();
+        new.bounded_contexts[0].entities[0].state_machine = Some(lifecycle(&["Active", "Suspended"]));
+        new.bounded_contexts[0].entities[0].state_machine.as_mut().unwrap().transitions.push(Transition {
+            from: "Suspended".into(),
+            to: "Active".into(),
+            method: "reactivate".into(),
+            event: "".into(),
+        });
+
+        let changes = diff_models(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "Identity.User.state_machine");
+
+        let plan = plan_refactoring(&changes, &new);
+        let tests: Vec<_> = plan.code_actions.iter().filter(|a| matches!(a.action, ActionKind::AddTest)).collect();
+        assert_eq!(tests.len(), 1);
+        assert!(tests[0].description.contains("Suspended → Active via 'reactivate'"));
+        assert!(plan.migration_notes[0].contains("States removed from 'User' lifecycle: Archived"));
+    }
+
+    #[test]
+    fn test_plan_refactoring_removed_variant() {
+        let status = |variants: &[&str]| Enumeration {
+            name: "UserStatus".into(),
+            description: "".into(),
+            variants: variants
+                .iter()
+                .map(|v| Variant {
+                    name: v.to_string(),
+                    description: "".into(),
+                })
+                .collect(),
+            meta: Metadata::default(),
+        };
+        let mut old = base_model();
+        old.bounded_contexts[0].enumerations.push(status(&["Active", "Banned"]));
+        old.bounded_contexts[0].entities[0].fields.push(Field {
+            name: "status".into(),
+            field_type: "UserStatus".into(),
+            required: true,
+            description: "".into(),
+        });
+        let mut new = old.clone();
+        new.bounded_contexts[0].enumerations[0] = status(&["Active", "Suspended"]);
+
+        let changes = diff_models(&old, &new);
+        let paths: Vec<_> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["Identity.enumerations.UserStatus.variants.Suspended", "Identity.enumerations.UserStatus.variants.Banned"]
+        );
+
+        let plan = plan_refactoring(&changes, &new);
+        assert!(plan.code_actions.iter().any(|a| a.file_path == "src/identity/domain/user.rs"
+            && a.description.contains("'UserStatus::Banned' on 'User.status'")));
+        assert!(plan.code_actions.iter().any(|a| a.description == "Handle new variant 'Suspended' in exhaustive matches on 'User.status'"));
+        assert!(plan.migration_notes.iter().any(|n| n.starts_with("Removed variant 'Banned' from 'UserStatus' — migrate stored 'Banned' values")
+            && n.ends_with("(used by User.status)")));
+    }
+
     #[test]
     fn test_plan_refactoring_field_migration_note() {
         let old = base_model();
@@ -982,7 +2406,68 @@ mod tests {
             description: "".into(),
         });
         let changes = diff_models(&old, &new);
-        let plan = plan_refactoring(&changes, &new.conventions);
+        let plan = plan_refactoring(&changes, &new);
         assert!(plan.migration_notes.iter().any(|n| n.contains("ALTER TABLE")));
+        assert!(plan.migrations.is_empty());
+    }
+
+    #[test]
+    fn test_plan_refactoring_field_named_status() {
+        let mut old = base_model();
+        old.bounded_contexts[0].entities[0].fields.push(Field {
+            name: "status".into(),
+            field_type: "String".into(),
+            required: true,
+            description: "".into(),
+        });
+        let mut new = old.clone();
+        new.bounded_contexts[0].entities[0].fields.last_mut().unwrap().field_type = "i32".into();
+
+        let changes = diff_models(&old, &new);
+        assert_eq!(changes[0].path, "Identity.User.fields.status");
+        let plan = plan_refactoring(&changes, &new);
+        assert!(plan.code_actions.iter().any(|a| a.description == "Update field type for 'status' on 'User'"));
+        assert!(plan.migration_notes[0].contains("Field type change on 'User.status'"));
+        assert!(plan.deprecations.is_empty());
+    }
+
+    #[test]
+    fn test_aggregate_boundary_change() {
+        let mut old = base_model();
+        old.bounded_contexts[0].aggregates.push(Aggregate {
+            name: "Account".into(),
+            description: "".into(),
+            root: "User".into(),
+            entities: vec![],
+            value_objects: vec![],
+            meta: Metadata::default(),
+        });
+        let mut new = old.clone();
+        new.bounded_contexts[0].aggregates[0].entities.push("Session".into());
+
+        let changes = diff_models(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(changes[0].kind, ChangeKind::Modified));
+        assert_eq!(changes[0].path, "Identity.aggregates.Account");
+
+        let plan = plan_refactoring(&changes, &new);
+        assert!(plan.code_actions[0].description.contains("(Session) only through root 'User'"));
+    }
+
+    #[test]
+    fn test_plan_refactoring_emits_sql_for_configured_database() {
+        let old = base_model();
+        let mut new = base_model();
+        new.tech_stack.database = "PostgreSQL".into();
+        new.bounded_contexts[0].entities[0].fields.push(Field {
+            name: "avatar".into(),
+            field_type: "String".into(),
+            required: false,
+            description: "".into(),
+        });
+        let changes = diff_models(&old, &new);
+        let plan = plan_refactoring(&changes, &new);
+        assert_eq!(plan.migrations.len(), 1);
+        assert!(plan.migrations[0].up.contains("ADD COLUMN avatar TEXT"));
     }
 }
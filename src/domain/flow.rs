@@ -0,0 +1,248 @@
+use std::collections::{HashSet, VecDeque};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::model::*;
+use super::registry::{DomainRegistry, LookupError};
+
+/// An ordered emit → react chain starting from a command or event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventFlow {
+    pub start: String,
+    pub steps: Vec<FlowStep>,
+    /// Reactions that cross a context boundary not declared in `dependencies`
+    pub violations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlowStep {
+    pub kind: StepKind,
+    pub name: String,
+    pub context: String,
+    /// The step that led here (`None` for the start)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub violation: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepKind {
+    Command,
+    Event,
+    Policy,
+}
+
+/// Walk the flow breadth-first: a command emits events, an event triggers every
+/// policy that reacts to it, a policy issues commands. Each element appears once
+/// per context, so cycles terminate and homonyms in other contexts are still traced.
+///
+/// A policy depends on the context of every event it reacts to and every command
+/// it issues; crossings missing from the policy context's `dependencies` are
+/// reported on the step and collected in `violations`.
+pub fn trace(model: &DomainModel, start: &str) -> Result<EventFlow> {
+    let first = match DomainRegistry::new(model).resolve(start, &["command", "event"]) {
+        Ok((bc, "command", name)) => (StepKind::Command, name.to_string(), bc.name.clone()),
+        Ok((bc, _, name)) => (StepKind::Event, name.to_string(), bc.name.clone()),
+        Err(LookupError::NotFound) => anyhow::bail!("'{start}' is neither a command nor an event of the model"),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut steps = Vec::new();
+    let mut violations = Vec::new();
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([(first, None::<String>, None::<String>)]);
+
+    while let Some(((kind, name, context), after, violation)) = queue.pop_front() {
+        if !seen.insert((kind, name.to_ascii_lowercase(), context.to_ascii_lowercase())) {
+            continue;
+        }
+        if let Some(v) = &violation {
+            violations.push(v.clone());
+        }
+
+        match kind {
+            StepKind::Command => {
+                if let Some((bc, cmd)) = model.find_command(&name, &context) {
+                    for event in &cmd.emits {
+                        queue.push_back(((StepKind::Event, event.clone(), bc.name.clone()), Some(name.clone()), None));
+                    }
+                }
+            }
+            StepKind::Event => {
+                for bc in &model.bounded_contexts {
+                    for policy in bc.policies.iter().filter(|p| p.reacts_to.iter().any(|e| e.eq_ignore_ascii_case(&name))) {
+                        let violation = crossing(bc, &context, &format!(
+                            "Policy '{}' in '{}' reacts to '{}' from '{}'",
+                            policy.name, bc.name, name, context
+                        ));
+                        queue.push_back(((StepKind::Policy, policy.name.clone(), bc.name.clone()), Some(name.clone()), violation));
+                    }
+                }
+            }
+            StepKind::Policy => {
+                let policy_ctx = model.bounded_contexts.iter().find(|bc| bc.name == context);
+                let policy = policy_ctx.and_then(|bc| bc.policies.iter().find(|p| p.name == name));
+                if let (Some(bc), Some(policy)) = (policy_ctx, policy) {
+                    for command in &policy.issues {
+                        let Some((target, cmd)) = model.find_command(command, &bc.name) else {
+                            continue;
+                        };
+                        let violation = crossing(bc, &target.name, &format!(
+                            "Policy '{}' in '{}' issues '{}' in '{}'",
+                            policy.name, bc.name, cmd.name, target.name
+                        ));
+                        queue.push_back(((StepKind::Command, cmd.name.clone(), target.name.clone()), Some(name.clone()), violation));
+                    }
+                }
+            }
+        }
+
+        steps.push(FlowStep { kind, name, context, after, violation });
+    }
+
+    Ok(EventFlow {
+        start: start.to_string(),
+        steps,
+        violations,
+    })
+}
+
+/// `None` when `from` may reach `to` (same context or a declared dependency).
+fn crossing(from: &BoundedContext, to: &str, what: &str) -> Option<String> {
+    if from.name.eq_ignore_ascii_case(to) || from.dependencies.iter().any(|d| d.eq_ignore_ascii_case(to)) {
+        None
+    } else {
+        Some(format!("{what}, but '{}' does not depend on '{}'", from.name, to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::fixtures::context;
+
+    fn command(name: &str, emits: &[&str]) -> Operation {
+        Operation {
+            name: name.into(),
+            description: "".into(),
+            fields: vec![],
+            aggregate: "".into(),
+            emits: emits.iter().map(|e| e.to_string()).collect(),
+            invariants: vec![],
+            handler: "".into(),
+            returns: "".into(),
+            steps: vec![],
+            meta: Metadata::default(),
+        }
+    }
+
+    fn event(name: &str) -> DomainEvent {
+        DomainEvent {
+            name: name.into(),
+            description: "".into(),
+            fields: vec![],
+            source: "".into(),
+            published: true,
+            channel: "".into(),
+            meta: Metadata::default(),
+        }
+    }
+
+    fn policy(name: &str, reacts_to: &str, issues: &str) -> Policy {
+        Policy {
+            name: name.into(),
+            description: "".into(),
+            kind: PolicyKind::Policy,
+            reacts_to: vec![reacts_to.into()],
+            issues: vec![issues.into()],
+            meta: Metadata::default(),
+        }
+    }
+
+    /// Identity: SuspendUser → UserSuspended
+    /// Billing:  CancelOnSuspension reacts to UserSuspended → CancelSubscription → SubscriptionCancelled
+    fn model(billing_deps: &[&str]) -> DomainModel {
+        let mut identity = context("Identity", &[]);
+        identity.commands.push(command("SuspendUser", &["UserSuspended"]));
+        identity.events.push(event("UserSuspended"));
+
+        let mut billing = context("Billing", billing_deps);
+        billing.commands.push(command("CancelSubscription", &["SubscriptionCancelled"]));
+        billing.events.push(event("SubscriptionCancelled"));
+        billing.policies.push(policy("CancelOnSuspension", "UserSuspended", "CancelSubscription"));
+
+        let mut m = DomainModel::empty("/tmp/shop");
+        m.bounded_contexts = vec![identity, billing];
+        m
+    }
+
+    #[test]
+    fn test_trace_from_command_across_contexts() {
+        let flow = trace(&model(&["Identity"]), "SuspendUser").unwrap();
+        let names: Vec<_> = flow.steps.iter().map(|s| (s.kind, s.name.as_str(), s.context.as_str())).collect();
+        assert_eq!(
+            names,
+            vec![
+                (StepKind::Command, "SuspendUser", "Identity"),
+                (StepKind::Event, "UserSuspended", "Identity"),
+                (StepKind::Policy, "CancelOnSuspension", "Billing"),
+                (StepKind::Command, "CancelSubscription", "Billing"),
+                (StepKind::Event, "SubscriptionCancelled", "Billing"),
+            ]
+        );
+        assert_eq!(flow.steps[2].after.as_deref(), Some("UserSuspended"));
+        assert!(flow.violations.is_empty());
+    }
+
+    #[test]
+    fn test_trace_flags_undeclared_dependency() {
+        let flow = trace(&model(&[]), "UserSuspended").unwrap();
+        assert_eq!(flow.steps[0].kind, StepKind::Event);
+        assert_eq!(flow.violations.len(), 1);
+        assert!(flow.steps[1].violation.as_deref().unwrap().contains("'Billing' does not depend on 'Identity'"));
+    }
+
+    #[test]
+    fn test_trace_terminates_on_cycles() {
+        let mut m = model(&["Identity"]);
+        m.bounded_contexts[0].dependencies.push("Billing".into());
+        m.bounded_contexts[0].policies.push(policy("Resuspend", "SubscriptionCancelled", "SuspendUser"));
+        let flow = trace(&m, "SuspendUser").unwrap();
+        assert_eq!(flow.steps.len(), 6);
+        assert_eq!(flow.steps[5].name, "Resuspend");
+    }
+
+    #[test]
+    fn test_trace_keeps_homonyms_of_other_contexts() {
+        let mut m = model(&["Identity"]);
+        let mut shipping = context("Shipping", &["Identity"]);
+        shipping.commands.push(command("CancelSubscription", &[]));
+        shipping.policies.push(policy("CancelOnSuspension", "UserSuspended", "CancelSubscription"));
+        m.bounded_contexts.push(shipping);
+
+        let flow = trace(&m, "SuspendUser").unwrap();
+        let homonyms: Vec<_> = flow
+            .steps
+            .iter()
+            .filter(|s| s.name == "CancelOnSuspension" || s.name == "CancelSubscription")
+            .map(|s| (s.name.as_str(), s.context.as_str()))
+            .collect();
+        assert_eq!(
+            homonyms,
+            vec![
+                ("CancelOnSuspension", "Billing"),
+                ("CancelOnSuspension", "Shipping"),
+                ("CancelSubscription", "Billing"),
+                ("CancelSubscription", "Shipping"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trace_unknown_start() {
+        assert!(trace(&model(&[]), "Nope").is_err());
+    }
+}
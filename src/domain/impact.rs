@@ -0,0 +1,308 @@
+use std::collections::{HashSet, VecDeque};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use super::model::*;
+use super::registry::{split_qualified, DomainRegistry, LookupError};
+use super::search;
+
+/// Everything that may have to change along with an element.
+#[derive(Debug, Clone, Serialize)]
+pub struct Impact {
+    pub target: String,
+    pub kind: &'static str,
+    pub context: String,
+    /// Affected elements grouped by context, nearest context first
+    pub contexts: Vec<ContextImpact>,
+    /// Rules scoped to the target, its context or its layer
+    pub rules: Vec<ArchitecturalRule>,
+    pub total: usize,
+}
+
+impl Impact {
+    /// Plain-text report for the command line, one line per affected element.
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "Impact of {} '{}' in '{}': {} element(s)\n",
+            self.kind.replace('_', " "),
+            self.target,
+            self.context,
+            self.total
+        );
+        for group in &self.contexts {
+            out.push_str(&format!("\n{}\n", group.context));
+            for a in &group.affected {
+                out.push_str(&format!("  [{}] {} {} — {}\n", a.distance, a.kind.replace('_', " "), a.name, a.reason));
+            }
+        }
+        if !self.rules.is_empty() {
+            out.push_str("\nRules\n");
+            for rule in &self.rules {
+                out.push_str(&format!("  {} — {}\n", rule.id, rule.description));
+            }
+        }
+        out
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextImpact {
+    pub context: String,
+    pub affected: Vec<Affected>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Affected {
+    pub kind: &'static str,
+    pub name: String,
+    /// 1 for elements referring to the target, 2 for elements referring to those, …
+    pub distance: usize,
+    /// What it refers to and how, e.g. "uses 'User' via aggregate"
+    pub reason: String,
+}
+
+/// Walk the reverse-reference graph breadth-first from `name` (qualified as
+/// `Billing::Account` when several contexts define it), up to `max_distance`
+/// hops. An entity also reaches everything typed with its id (`UserId`), and every
+/// service of a context that depends on the target's context is affected one hop
+/// further out than the target's direct users.
+pub fn analyze(model: &DomainModel, name: &str, max_distance: usize) -> Result<Impact> {
+    let registry = DomainRegistry::new(model);
+    let (bc, kind, target) = match registry.resolve(name, &[]) {
+        Ok(found) => found,
+        Err(LookupError::NotFound) => {
+            let all = model.bounded_contexts.iter().flat_map(|bc| bc.elements()).map(|(_, n, _)| n);
+            let suggestions = search::suggest(split_qualified(name).1, all);
+            if suggestions.is_empty() {
+                anyhow::bail!("'{name}' is not an element of the model");
+            }
+            anyhow::bail!("'{name}' is not an element of the model. Did you mean '{}'?", suggestions.join("' or '"));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut affected: Vec<(String, Affected)> = Vec::new();
+    let mut seen = HashSet::from([(bc.name.to_lowercase(), target.to_lowercase())]);
+    let mut queue = VecDeque::from([(bc.name.clone(), target.to_string(), 0)]);
+    if kind == "entity" {
+        queue.push_back((bc.name.clone(), format!("{target}Id"), 0));
+    }
+
+    while let Some((context, current, distance)) = queue.pop_front() {
+        if distance >= max_distance {
+            continue;
+        }
+        for usage in registry.usages(&context, &current) {
+            if !seen.insert((usage.context.to_lowercase(), usage.element.to_lowercase())) {
+                continue;
+            }
+            queue.push_back((usage.context.clone(), usage.element.clone(), distance + 1));
+            affected.push((
+                usage.context,
+                Affected {
+                    kind: usage.kind,
+                    name: usage.element,
+                    distance: distance + 1,
+                    reason: format!("uses '{current}' via {}", usage.via),
+                },
+            ));
+        }
+    }
+
+    if max_distance >= 2 {
+        for other in &model.bounded_contexts {
+            if !other.dependencies.iter().any(|d| d.eq_ignore_ascii_case(&bc.name)) {
+                continue;
+            }
+            for svc in &other.services {
+                if seen.insert((other.name.to_lowercase(), svc.name.to_lowercase())) {
+                    affected.push((
+                        other.name.clone(),
+                        Affected {
+                            kind: "service",
+                            name: svc.name.clone(),
+                            distance: 2,
+                            reason: format!("its context '{}' depends on '{}'", other.name, bc.name),
+                        },
+                    ));
+                }
+            }
+        }
+    }
+
+    let total = affected.len();
+    let mut contexts: Vec<ContextImpact> = Vec::new();
+    for (context, item) in affected {
+        match contexts.iter_mut().find(|c| c.context == context) {
+            Some(group) => group.affected.push(item),
+            None => contexts.push(ContextImpact {
+                context,
+                affected: vec![item],
+            }),
+        }
+    }
+    for group in &mut contexts {
+        group.affected.sort_by(|a, b| a.distance.cmp(&b.distance).then_with(|| a.name.cmp(&b.name)));
+    }
+    let nearest = |g: &ContextImpact| g.affected.first().map_or(usize::MAX, |a| a.distance);
+    contexts.sort_by(|a, b| {
+        nearest(a)
+            .cmp(&nearest(b))
+            .then_with(|| (a.context != bc.name).cmp(&(b.context != bc.name)))
+            .then_with(|| a.context.cmp(&b.context))
+    });
+
+    let layer = model.conventions.file_structure.layer_of(bc, kind, target);
+    let rules = model
+        .rules
+        .iter()
+        .filter(|r| [target, bc.name.as_str(), layer].iter().any(|s| r.scope.eq_ignore_ascii_case(s)))
+        .cloned()
+        .collect();
+
+    Ok(Impact {
+        target: target.to_string(),
+        kind,
+        context: bc.name.clone(),
+        contexts,
+        rules,
+        total,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::fixtures::context;
+
+    fn field(name: &str, ty: &str) -> Field {
+        Field {
+            name: name.into(),
+            field_type: ty.into(),
+            required: true,
+            description: "".into(),
+        }
+    }
+
+    fn shop() -> DomainModel {
+        let mut m = DomainModel::empty("/tmp/shop");
+        let mut identity = context("Identity", &[]);
+        identity.entities.push(Entity {
+            name: "User".into(),
+            description: "".into(),
+            aggregate_root: true,
+            fields: vec![field("id", "UserId")],
+            methods: vec![],
+            invariants: vec![],
+            state_machine: None,
+            meta: Metadata::default(),
+        });
+        identity.repositories.push(Repository {
+            name: "UserRepository".into(),
+            aggregate: "User".into(),
+            methods: vec![],
+            meta: Metadata::default(),
+        });
+        identity.events.push(DomainEvent {
+            name: "UserRegistered".into(),
+            description: "".into(),
+            fields: vec![],
+            source: "User".into(),
+            published: true,
+            channel: "".into(),
+            meta: Metadata::default(),
+        });
+        let mut billing = context("Billing", &["Identity"]);
+        billing.policies.push(Policy {
+            name: "WelcomeOffer".into(),
+            description: "".into(),
+            kind: PolicyKind::Policy,
+            reacts_to: vec!["UserRegistered".into()],
+            issues: vec![],
+            meta: Metadata::default(),
+        });
+        billing.services.push(Service {
+            name: "InvoiceService".into(),
+            description: "".into(),
+            kind: ServiceKind::Domain,
+            methods: vec![],
+            dependencies: vec![],
+            meta: Metadata::default(),
+        });
+        billing.events.push(DomainEvent {
+            name: "InvoicePaid".into(),
+            description: "".into(),
+            fields: vec![field("payer", "UserId")],
+            source: "".into(),
+            published: false,
+            channel: "".into(),
+            meta: Metadata::default(),
+        });
+        m.bounded_contexts = vec![identity, billing];
+        m.rules.push(ArchitecturalRule {
+            id: "DDD-001".into(),
+            description: "".into(),
+            severity: Severity::Error,
+            scope: "domain".into(),
+            check: "".into(),
+        });
+        m
+    }
+
+    #[test]
+    fn test_impact_ranked_by_distance_and_grouped_by_context() {
+        let impact = analyze(&shop(), "user", 3).unwrap();
+        assert_eq!(impact.target, "User");
+        let groups: Vec<_> = impact.contexts.iter().map(|g| g.context.as_str()).collect();
+        assert_eq!(groups, vec!["Identity", "Billing"]);
+
+        let identity: Vec<_> = impact.contexts[0].affected.iter().map(|a| (a.distance, a.name.as_str())).collect();
+        assert_eq!(identity, vec![(1, "UserRegistered"), (1, "UserRepository")]);
+        let billing: Vec<_> = impact.contexts[1].affected.iter().map(|a| (a.distance, a.name.as_str(), a.reason.as_str())).collect();
+        assert_eq!(
+            billing,
+            vec![
+                (1, "InvoicePaid", "uses 'UserId' via field 'payer'"),
+                (2, "InvoiceService", "its context 'Billing' depends on 'Identity'"),
+                (2, "WelcomeOffer", "uses 'UserRegistered' via reacts to"),
+            ]
+        );
+        assert_eq!(impact.rules[0].id, "DDD-001");
+        assert_eq!(impact.total, 5);
+    }
+
+    #[test]
+    fn test_impact_of_homonym_stays_in_its_context() {
+        let mut m = shop();
+        for (i, prefix) in [(0, "Identity"), (1, "Billing")] {
+            m.bounded_contexts[i].entities.push(Entity {
+                name: "Account".into(),
+                description: "".into(),
+                aggregate_root: true,
+                fields: vec![],
+                methods: vec![],
+                invariants: vec![],
+                state_machine: None,
+                meta: Metadata::default(),
+            });
+            m.bounded_contexts[i].repositories.push(Repository {
+                name: format!("{prefix}AccountRepository"),
+                aggregate: "Account".into(),
+                methods: vec![],
+                meta: Metadata::default(),
+            });
+        }
+        let impact = analyze(&m, "Billing::Account", 1).unwrap();
+        let names: Vec<_> = impact.contexts.iter().flat_map(|g| &g.affected).map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["BillingAccountRepository"]);
+    }
+
+    #[test]
+    fn test_impact_depth_and_unknown_target() {
+        let impact = analyze(&shop(), "User", 1).unwrap();
+        assert_eq!(impact.total, 3);
+        let err = analyze(&shop(), "Users", 3).unwrap_err().to_string();
+        assert!(err.contains("Did you mean 'User'"), "{err}");
+    }
+}
@@ -0,0 +1,714 @@
+use serde::{Deserialize, Serialize};
+
+use super::diff::{ChangeKind, ModelChange};
+use super::model::*;
+use super::to_snake;
+use super::types::{classify_change, TypeChange, TypeRef};
+
+/// SQL dialects migration skeletons can be generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SqlDialect {
+    Postgres,
+    Sqlite,
+}
+
+impl SqlDialect {
+    /// Pick the dialect from `tech_stack.database`. Returns `None` for unsupported databases.
+    pub fn from_database(database: &str) -> Option<Self> {
+        let db = database.to_ascii_lowercase();
+        if db.contains("postgres") {
+            Some(Self::Postgres)
+        } else if db.contains("sqlite") {
+            Some(Self::Sqlite)
+        } else {
+            None
+        }
+    }
+}
+
+/// A migration skeleton with forward and rollback SQL.
+/// `TODO` comments mark places that need a human decision (backfills, conversions).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SqlMigration {
+    /// snake_case name, usable as a file name suffix (e.g. `add_last_login_to_users`)
+    pub name: String,
+    pub description: String,
+    pub dialect: SqlDialect,
+    pub up: String,
+    pub down: String,
+}
+
+/// Generate migration skeletons for entity-level changes.
+///
+/// - new aggregate roots (and the roots of new contexts) → `CREATE TABLE`
+/// - removed aggregate roots → `DROP TABLE`
+/// - added / removed entity fields → `ADD COLUMN` / `DROP COLUMN`
+/// - field type changes → type-change template with a data-migration placeholder
+/// - fields whose column gains or loses `NOT NULL` → nullability change
+///
+/// Fields holding a collection of entities get no column: their rows belong in the
+/// child entity's table, created along with the parent's and keyed by a `{parent}_id`
+/// foreign key. `{Root}Id` fields of roots in the same context reference its table.
+/// New tables come first, each after the tables it references.
+pub fn plan_migrations(
+    changes: &[ModelChange],
+    model: &DomainModel,
+    dialect: SqlDialect,
+) -> Vec<SqlMigration> {
+    let mut tables = Vec::new();
+    let mut migrations = Vec::new();
+
+    for change in changes {
+        let parts: Vec<&str> = change.path.split('.').collect();
+        match (&change.kind, parts.as_slice()) {
+            (ChangeKind::Added, ["bounded_contexts", ctx_name]) => {
+                let Some(bc) = model
+                    .bounded_contexts
+                    .iter()
+                    .find(|bc| bc.name.eq_ignore_ascii_case(ctx_name))
+                else {
+                    continue;
+                };
+                for entity in bc.entities.iter().filter(|e| e.aggregate_root) {
+                    create_tables(entity, None, model, &bc.name, dialect, &mut tables);
+                }
+            }
+            (ChangeKind::Added, [ctx, "entities", _]) => {
+                if let Some(entity) = decode::<Entity>(&change.after) {
+                    if entity.aggregate_root {
+                        create_tables(&entity, None, model, ctx, dialect, &mut tables);
+                    }
+                }
+            }
+            (ChangeKind::Removed, [ctx, "entities", _]) => {
+                if let Some(entity) = decode::<Entity>(&change.before) {
+                    if entity.aggregate_root {
+                        let table = table_name(&entity.name);
+                        let recreate = create_table(&entity, None, model, ctx, dialect).migration;
+                        migrations.push(SqlMigration {
+                            name: format!("drop_{table}"),
+                            description: format!("Drop table for removed aggregate '{}'", entity.name),
+                            dialect,
+                            up: format!("-- TODO: archive data before dropping if needed\nDROP TABLE {table};\n"),
+                            down: recreate.up,
+                        });
+                    }
+                }
+            }
+            (ChangeKind::Added, [ctx, entity, "fields", _]) => {
+                let Some(field) = decode::<Field>(&change.after) else {
+                    continue;
+                };
+                match column(entity, &field, model, ctx, dialect) {
+                    Column::Sql(sql_type) => migrations.push(add_column(entity, &field, &sql_type, model, ctx, dialect)),
+                    Column::Child { entity: child, context, .. } => {
+                        if let Some(child) = find_entity(model, &context, &child) {
+                            let parent = Parent::new(entity, model, ctx, dialect);
+                            create_tables(child, Some(&parent), model, &context, dialect, &mut tables);
+                        }
+                    }
+                }
+            }
+            (ChangeKind::Removed, [ctx, entity, "fields", _]) => {
+                if let Some(field) = decode::<Field>(&change.before) {
+                    let Column::Sql(sql_type) = column(entity, &field, model, ctx, dialect) else {
+                        continue;
+                    };
+                    let table = table_name(entity);
+                    let column = to_snake(&field.name);
+                    let restore = add_column(entity, &field, &sql_type, model, ctx, dialect);
+                    migrations.push(SqlMigration {
+                        name: format!("drop_{column}_from_{table}"),
+                        description: format!("Drop column '{column}' from '{table}'"),
+                        dialect,
+                        up: format!("ALTER TABLE {table} DROP COLUMN {column};\n"),
+                        down: restore.up,
+                    });
+                }
+            }
+            (ChangeKind::Modified, [_, entity, "fields", field]) => {
+                let (Some(from), Some(to)) = (decode::<Field>(&change.before), decode::<Field>(&change.after)) else {
+                    continue;
+                };
+                if classify_change(&from.field_type, &to.field_type) == Some(TypeChange::Changed) {
+                    migrations.push(change_column_type(entity, field, &from.field_type, &to.field_type, dialect));
+                }
+                if is_not_null(&from) != is_not_null(&to) {
+                    migrations.push(change_nullability(entity, field, is_not_null(&to), dialect));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut ordered = order_tables(tables);
+    ordered.extend(migrations);
+    ordered
+}
+
+/// A `CREATE TABLE` migration and the tables its foreign keys reference.
+struct Table {
+    name: String,
+    references: Vec<String>,
+    migration: SqlMigration,
+}
+
+/// The entity owning a child table.
+struct Parent<'a> {
+    entity: &'a str,
+    /// Type of the `{parent}_id` column: the parent's `id` column, a UUID if it has none
+    key_type: String,
+}
+
+impl<'a> Parent<'a> {
+    fn new(entity: &'a str, model: &DomainModel, ctx: &str, dialect: SqlDialect) -> Self {
+        let key_type = find_entity(model, ctx, entity)
+            .and_then(|e| e.fields.iter().find(|f| f.name == "id"))
+            .map(|id| column_type(&id.field_type, dialect))
+            .unwrap_or_else(|| column_type("Uuid", dialect));
+        Parent { entity, key_type }
+    }
+}
+
+/// The table of `entity` followed by the tables of the entity collections it holds.
+fn create_tables(
+    entity: &Entity,
+    parent: Option<&Parent>,
+    model: &DomainModel,
+    ctx: &str,
+    dialect: SqlDialect,
+    tables: &mut Vec<Table>,
+) {
+    let table = create_table(entity, parent, model, ctx, dialect);
+    if tables.iter().any(|t| t.name == table.name) {
+        return;
+    }
+    tables.push(table);
+    for field in &entity.fields {
+        if let Column::Child { entity: child, context, .. } = column(&entity.name, field, model, ctx, dialect) {
+            if let Some(child) = find_entity(model, &context, &child) {
+                let parent = Parent::new(&entity.name, model, ctx, dialect);
+                create_tables(child, Some(&parent), model, &context, dialect, tables);
+            }
+        }
+    }
+}
+
+fn create_table(entity: &Entity, parent: Option<&Parent>, model: &DomainModel, ctx: &str, dialect: SqlDialect) -> Table {
+    let table = table_name(&entity.name);
+    let mut columns = Vec::new();
+    let mut references = Vec::new();
+    let mut children = Vec::new();
+    for f in &entity.fields {
+        match column(&entity.name, f, model, ctx, dialect) {
+            Column::Child { note, .. } => children.push(format!("    -- {note}")),
+            Column::Sql(sql_type) => {
+                let mut col = format!("    {} {}", to_snake(&f.name), sql_type);
+                if f.name == "id" {
+                    col.push_str(" PRIMARY KEY");
+                } else if is_not_null(f) {
+                    col.push_str(" NOT NULL");
+                }
+                if let Some(referenced) = referenced_table(f, model, ctx) {
+                    col.push_str(&format!(" REFERENCES {referenced}(id)"));
+                    references.push(referenced);
+                }
+                columns.push(col);
+            }
+        }
+    }
+    if let Some(parent) = parent {
+        let parent_table = table_name(parent.entity);
+        columns.push(format!(
+            "    {}_id {} NOT NULL REFERENCES {parent_table}(id)",
+            to_snake(parent.entity),
+            parent.key_type
+        ));
+        references.push(parent_table);
+    }
+    let mut body = if columns.is_empty() {
+        "    -- TODO: define columns".to_string()
+    } else {
+        columns.join(",\n")
+    };
+    for child in children {
+        body.push('\n');
+        body.push_str(&child);
+    }
+
+    let description = match parent {
+        Some(parent) => format!("Create table for '{}' entries of '{}'", entity.name, parent.entity),
+        None => format!("Create table for aggregate root '{}'", entity.name),
+    };
+    Table {
+        name: table.clone(),
+        references,
+        migration: SqlMigration {
+            name: format!("create_{table}"),
+            description,
+            dialect,
+            up: format!("CREATE TABLE {table} (\n{body}\n);\n"),
+            down: format!("DROP TABLE {table};\n"),
+        },
+    }
+}
+
+/// New tables, each after the new tables it references. Tables in a reference cycle
+/// keep their order; one of their foreign keys has to be added afterwards.
+fn order_tables(mut pending: Vec<Table>) -> Vec<SqlMigration> {
+    let mut ordered: Vec<Table> = Vec::new();
+    while !pending.is_empty() {
+        let ready = pending
+            .iter()
+            .position(|t| {
+                t.references
+                    .iter()
+                    .all(|r| *r == t.name || !pending.iter().any(|p| p.name == *r))
+            })
+            .unwrap_or(0);
+        ordered.push(pending.remove(ready));
+    }
+    ordered.into_iter().map(|t| t.migration).collect()
+}
+
+fn add_column(entity: &str, field: &Field, sql_type: &str, model: &DomainModel, ctx: &str, dialect: SqlDialect) -> SqlMigration {
+    let table = table_name(entity);
+    let sql_type = format!("{sql_type}{}", foreign_key(field, model, ctx));
+    let column = to_snake(&field.name);
+
+    let up = match (is_not_null(field), dialect) {
+        (false, _) => format!("ALTER TABLE {table} ADD COLUMN {column} {sql_type};\n"),
+        // Existing rows need a value before the constraint can be enforced
+        (true, SqlDialect::Postgres) => format!(
+            "ALTER TABLE {table} ADD COLUMN {column} {sql_type};\n\
+             -- TODO: backfill existing rows\n\
+             UPDATE {table} SET {column} = /* TODO: value */ NULL WHERE {column} IS NULL;\n\
+             ALTER TABLE {table} ALTER COLUMN {column} SET NOT NULL;\n"
+        ),
+        (true, SqlDialect::Sqlite) => format!(
+            "-- SQLite requires a default to add a NOT NULL column to existing rows\n\
+             ALTER TABLE {table} ADD COLUMN {column} {sql_type} NOT NULL DEFAULT /* TODO: default */ '';\n"
+        ),
+    };
+
+    SqlMigration {
+        name: format!("add_{column}_to_{table}"),
+        description: format!("Add column '{column}' to '{table}'"),
+        dialect,
+        up,
+        down: format!("ALTER TABLE {table} DROP COLUMN {column};\n"),
+    }
+}
+
+fn change_column_type(entity: &str, field: &str, from: &str, to: &str, dialect: SqlDialect) -> SqlMigration {
+    let table = table_name(entity);
+    let column = to_snake(field);
+    let new_type = column_type(to, dialect);
+    let old_type = column_type(from, dialect);
+
+    let (up, down) = match dialect {
+        SqlDialect::Postgres => (
+            format!(
+                "-- {from} → {to}\n\
+                 -- TODO: verify the conversion expression preserves existing data\n\
+                 ALTER TABLE {table} ALTER COLUMN {column} TYPE {new_type} USING {column}::{new_type};\n"
+            ),
+            format!("ALTER TABLE {table} ALTER COLUMN {column} TYPE {old_type} USING {column}::{old_type};\n"),
+        ),
+        // SQLite cannot alter a column type in place: copy through a new column
+        SqlDialect::Sqlite => (
+            format!(
+                "-- {from} → {to}\n\
+                 ALTER TABLE {table} ADD COLUMN {column}__new {new_type};\n\
+                 -- TODO: convert existing data\n\
+                 UPDATE {table} SET {column}__new = /* TODO: convert */ {column};\n\
+                 ALTER TABLE {table} DROP COLUMN {column};\n\
+                 ALTER TABLE {table} RENAME COLUMN {column}__new TO {column};\n"
+            ),
+            format!(
+                "ALTER TABLE {table} ADD COLUMN {column}__old {old_type};\n\
+                 UPDATE {table} SET {column}__old = /* TODO: convert */ {column};\n\
+                 ALTER TABLE {table} DROP COLUMN {column};\n\
+                 ALTER TABLE {table} RENAME COLUMN {column}__old TO {column};\n"
+            ),
+        ),
+    };
+
+    SqlMigration {
+        name: format!("change_{column}_type_on_{table}"),
+        description: format!("Change type of '{table}.{column}' from {from} to {to}"),
+        dialect,
+        up,
+        down,
+    }
+}
+
+/// Drop or add `NOT NULL` for a field whose column changes nullability.
+fn change_nullability(entity: &str, field: &str, not_null: bool, dialect: SqlDialect) -> SqlMigration {
+    let table = table_name(entity);
+    let column = to_snake(field);
+    let (set, drop) = (
+        format!(
+            "-- TODO: backfill rows where {column} is missing\n\
+             UPDATE {table} SET {column} = /* TODO: value */ NULL WHERE {column} IS NULL;\n\
+             ALTER TABLE {table} ALTER COLUMN {column} SET NOT NULL;\n"
+        ),
+        format!("ALTER TABLE {table} ALTER COLUMN {column} DROP NOT NULL;\n"),
+    );
+    let (up, down) = match dialect {
+        SqlDialect::Postgres if not_null => (set, drop),
+        SqlDialect::Postgres => (drop, set),
+        // SQLite cannot change a column constraint in place
+        SqlDialect::Sqlite => {
+            let rebuild = |with: &str| {
+                format!(
+                    "-- TODO: SQLite cannot alter NOT NULL in place — rebuild {table} with {column} {with}:\n\
+                     -- create {table}__new, copy the rows, drop {table}, rename {table}__new to {table}\n"
+                )
+            };
+            if not_null {
+                (rebuild("NOT NULL"), rebuild("nullable"))
+            } else {
+                (rebuild("nullable"), rebuild("NOT NULL"))
+            }
+        }
+    };
+
+    SqlMigration {
+        name: format!("make_{column}_{}_on_{table}", if not_null { "required" } else { "optional" }),
+        description: format!(
+            "{} '{table}.{column}'",
+            if not_null { "Add NOT NULL to" } else { "Drop NOT NULL from" }
+        ),
+        dialect,
+        up,
+        down,
+    }
+}
+
+fn is_not_null(field: &Field) -> bool {
+    field.required && !field.type_ref().is_ok_and(|t| t.is_optional())
+}
+
+/// What a field becomes in its entity's table.
+enum Column {
+    Sql(String),
+    /// A collection of entities: no column, the children have their own table
+    Child { entity: String, context: String, note: String },
+}
+
+fn column(entity: &str, field: &Field, model: &DomainModel, ctx: &str, dialect: SqlDialect) -> Column {
+    let Ok(ty) = field.type_ref() else {
+        return Column::Sql(format!("TEXT /* {} */", field.field_type));
+    };
+    match ty.references(model, ctx).into_iter().find(|r| r.many && r.kind == "entity") {
+        Some(child) => Column::Child {
+            note: format!(
+                "{} ({}) is stored in {} with a {}_id foreign key",
+                to_snake(&field.name),
+                ty,
+                table_name(&child.name),
+                to_snake(entity)
+            ),
+            entity: child.name,
+            context: child.context,
+        },
+        None => Column::Sql(sql_type(&ty, dialect)),
+    }
+}
+
+/// ` REFERENCES roots(id)` for a `{Root}Id` field naming an aggregate root of the same context.
+fn foreign_key(field: &Field, model: &DomainModel, ctx: &str) -> String {
+    referenced_table(field, model, ctx)
+        .map(|table| format!(" REFERENCES {table}(id)"))
+        .unwrap_or_default()
+}
+
+/// The table a `{Root}Id` field refers to, if it names an aggregate root of the same context.
+fn referenced_table(field: &Field, model: &DomainModel, ctx: &str) -> Option<String> {
+    let bc = model.bounded_contexts.iter().find(|bc| bc.name.eq_ignore_ascii_case(ctx))?;
+    let ty = field.type_ref().ok()?;
+    if field.name == "id" {
+        return None;
+    }
+    match ty.required() {
+        TypeRef::Named { name, args } if args.is_empty() => name
+            .strip_suffix("Id")
+            .filter(|root| bc.is_aggregate_root(root))
+            .map(table_name),
+        _ => None,
+    }
+}
+
+fn find_entity<'m>(model: &'m DomainModel, ctx: &str, name: &str) -> Option<&'m Entity> {
+    model
+        .bounded_contexts
+        .iter()
+        .find(|bc| bc.name.eq_ignore_ascii_case(ctx))
+        .and_then(|bc| bc.entities.iter().find(|e| e.name == name))
+}
+
+/// Map a model field type to a column type.
+/// Unknown domain types fall back to TEXT with the original type as a comment.
+fn column_type(field_type: &str, dialect: SqlDialect) -> String {
+    match TypeRef::parse(field_type) {
+        Ok(ty) => sql_type(&ty, dialect),
+        Err(_) => format!("TEXT /* {} */", field_type.trim()),
+    }
+}
+
+fn sql_type(ty: &TypeRef, dialect: SqlDialect) -> String {
+    use SqlDialect::*;
+
+    match ty {
+        TypeRef::Optional { inner } => return sql_type(inner, dialect),
+        TypeRef::Collection { item, .. } if item.to_string() == "u8" => {
+            return match dialect {
+                Postgres => "BYTEA",
+                Sqlite => "BLOB",
+            }
+            .into();
+        }
+        TypeRef::Collection { item, .. } => {
+            return match dialect {
+                Postgres => format!("{}[]", sql_type(item, dialect)),
+                Sqlite => format!("TEXT /* JSON array of {item} */"),
+            };
+        }
+        TypeRef::Map { .. } => {
+            return match dialect {
+                Postgres => "JSONB".into(),
+                Sqlite => format!("TEXT /* JSON object {ty} */"),
+            };
+        }
+        _ => {}
+    }
+
+    let rendered = ty.to_string();
+    let mapped = match (rendered.as_str(), dialect) {
+        ("Uuid", Postgres) => "UUID",
+        ("String" | "str" | "&str", _) => "TEXT",
+        ("i16" | "u8" | "i8", Postgres) => "SMALLINT",
+        ("i32" | "u16", Postgres) => "INTEGER",
+        ("i64" | "u32" | "u64" | "usize", Postgres) => "BIGINT",
+        ("i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "usize", Sqlite) => "INTEGER",
+        ("bool", Postgres) => "BOOLEAN",
+        ("bool", Sqlite) => "INTEGER",
+        ("f32", Postgres) => "REAL",
+        ("f64", Postgres) => "DOUBLE PRECISION",
+        ("f32" | "f64", Sqlite) => "REAL",
+        ("Decimal", _) => "NUMERIC",
+        ("DateTime<Utc>", Postgres) => "TIMESTAMPTZ",
+        ("NaiveDateTime", Postgres) => "TIMESTAMP",
+        ("NaiveDate", Postgres) => "DATE",
+        ("NaiveTime", Postgres) => "TIME",
+        ("serde_json::Value" | "Value", Postgres) => "JSONB",
+        // SQLite stores dates, UUIDs and JSON as text
+        ("Uuid" | "DateTime<Utc>" | "NaiveDateTime" | "NaiveDate" | "NaiveTime", Sqlite) => "TEXT",
+        ("serde_json::Value" | "Value", Sqlite) => "TEXT",
+        // Typed identifiers such as UserId wrap a UUID
+        (t, Postgres) if t.ends_with("Id") => "UUID",
+        (t, Sqlite) if t.ends_with("Id") => "TEXT",
+        (other, _) => return format!("TEXT /* {other} */"),
+    };
+    mapped.to_string()
+}
+
+/// Entity name → conventional plural snake_case table name (`Category` → `categories`).
+fn table_name(entity: &str) -> String {
+    let snake = to_snake(entity);
+    if let Some(stem) = snake.strip_suffix('y').filter(|s| !s.ends_with(['a', 'e', 'o', 'u'])) {
+        format!("{stem}ies")
+    } else if snake.ends_with('s') || snake.ends_with('x') || snake.ends_with("ch") || snake.ends_with("sh") {
+        format!("{snake}es")
+    } else {
+        format!("{snake}s")
+    }
+}
+
+fn decode<T: serde::de::DeserializeOwned>(value: &Option<serde_json::Value>) -> Option<T> {
+    value.as_ref().and_then(|v| serde_json::from_value(v.clone()).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::diff::diff_models;
+
+    fn model() -> DomainModel {
+        let mut m = DomainModel::empty("/tmp/proj");
+        m.bounded_contexts.push(BoundedContext {
+            name: "Identity".into(),
+            description: "".into(),
+            module_path: "src/identity".into(),
+            entities: vec![Entity {
+                name: "User".into(),
+                description: "".into(),
+                aggregate_root: true,
+                fields: vec![
+                    Field {
+                        name: "id".into(),
+                        field_type: "UserId".into(),
+                        required: true,
+                        description: "".into(),
+                    },
+                    Field {
+                        name: "age".into(),
+                        field_type: "i32".into(),
+                        required: false,
+                        description: "".into(),
+                    },
+                ],
+                methods: vec![],
+                invariants: vec![],
+                state_machine: None,
+                meta: Metadata::default(),
+            }],
+            value_objects: vec![],
+            enumerations: vec![],
+            aggregates: vec![],
+            commands: vec![],
+            queries: vec![],
+            use_cases: vec![],
+            policies: vec![],
+            glossary: vec![],
+            services: vec![],
+            repositories: vec![],
+            events: vec![],
+            dependencies: vec![],
+            meta: Metadata::default(),
+        });
+        m
+    }
+
+    #[test]
+    fn test_dialect_from_database() {
+        assert_eq!(SqlDialect::from_database("PostgreSQL"), Some(SqlDialect::Postgres));
+        assert_eq!(SqlDialect::from_database("sqlite3"), Some(SqlDialect::Sqlite));
+        assert_eq!(SqlDialect::from_database("MongoDB"), None);
+    }
+
+    #[test]
+    fn test_new_context_creates_tables_for_roots() {
+        let old = DomainModel::empty("/tmp/proj");
+        let new = model();
+        let migrations = plan_migrations(&diff_models(&old, &new), &new, SqlDialect::Postgres);
+        assert_eq!(migrations.len(), 1);
+        assert_eq!(migrations[0].name, "create_users");
+        assert!(migrations[0].up.contains("id UUID PRIMARY KEY"));
+        assert!(migrations[0].up.contains("age INTEGER,") || migrations[0].up.contains("age INTEGER\n"));
+        assert_eq!(migrations[0].down, "DROP TABLE users;\n");
+    }
+
+    #[test]
+    fn test_required_field_added_with_backfill() {
+        let old = model();
+        let mut new = model();
+        new.bounded_contexts[0].entities[0].fields.push(Field {
+            name: "last_login".into(),
+            field_type: "DateTime<Utc>".into(),
+            required: true,
+            description: "".into(),
+        });
+        let migrations = plan_migrations(&diff_models(&old, &new), &new, SqlDialect::Postgres);
+        assert_eq!(migrations[0].name, "add_last_login_to_users");
+        assert!(migrations[0].up.contains("ADD COLUMN last_login TIMESTAMPTZ;"));
+        assert!(migrations[0].up.contains("SET NOT NULL"));
+        assert_eq!(migrations[0].down, "ALTER TABLE users DROP COLUMN last_login;\n");
+    }
+
+    #[test]
+    fn test_type_change_templates_per_dialect() {
+        let old = model();
+        let mut new = model();
+        new.bounded_contexts[0].entities[0].fields[1].field_type = "i64".into();
+        let changes = diff_models(&old, &new);
+
+        let pg = plan_migrations(&changes, &new, SqlDialect::Postgres);
+        assert!(pg[0].up.contains("ALTER COLUMN age TYPE BIGINT USING age::BIGINT"));
+
+        let lite = plan_migrations(&changes, &new, SqlDialect::Sqlite);
+        assert!(lite[0].up.contains("RENAME COLUMN age__new TO age"));
+        assert!(lite[0].up.contains("TODO"));
+    }
+
+    #[test]
+    fn test_made_optional_drops_not_null() {
+        let mut old = model();
+        old.bounded_contexts[0].entities[0].fields[1].required = true;
+        let mut new = old.clone();
+        new.bounded_contexts[0].entities[0].fields[1].field_type = "Option<i32>".into();
+        new.bounded_contexts[0].entities[0].fields[1].required = false;
+        let changes = diff_models(&old, &new);
+
+        let pg = plan_migrations(&changes, &new, SqlDialect::Postgres);
+        assert_eq!(pg[0].name, "make_age_optional_on_users");
+        assert_eq!(pg[0].up, "ALTER TABLE users ALTER COLUMN age DROP NOT NULL;\n");
+        assert!(pg[0].down.contains("SET NOT NULL"));
+
+        let lite = plan_migrations(&changes, &new, SqlDialect::Sqlite);
+        assert!(lite[0].up.contains("rebuild users"));
+    }
+
+    #[test]
+    fn test_required_flip_changes_not_null() {
+        let old = model();
+        let mut new = model();
+        new.bounded_contexts[0].entities[0].fields[1].required = true;
+
+        let made_required = plan_migrations(&diff_models(&old, &new), &new, SqlDialect::Postgres);
+        assert_eq!(made_required.len(), 1);
+        assert_eq!(made_required[0].name, "make_age_required_on_users");
+        assert!(made_required[0].up.contains("SET NOT NULL"));
+
+        let made_optional = plan_migrations(&diff_models(&new, &old), &old, SqlDialect::Postgres);
+        assert_eq!(made_optional[0].up, "ALTER TABLE users ALTER COLUMN age DROP NOT NULL;\n");
+    }
+
+    #[test]
+    fn test_entity_collections_and_root_ids() {
+        let old = DomainModel::empty("/tmp/proj");
+        let mut new = model();
+        let ctx = &mut new.bounded_contexts[0];
+        let mut session = ctx.entities[0].clone();
+        session.name = "Session".into();
+        session.fields = vec![
+            Field {
+                name: "user_id".into(),
+                field_type: "UserId".into(),
+                required: true,
+                description: "".into(),
+            },
+            Field {
+                name: "devices".into(),
+                field_type: "Vec<Device>".into(),
+                required: true,
+                description: "".into(),
+            },
+        ];
+        let mut device = session.clone();
+        device.name = "Device".into();
+        device.aggregate_root = false;
+        device.fields.clear();
+        // Listed before the users table it references
+        ctx.entities.insert(0, session);
+        ctx.entities.push(device);
+
+        let migrations = plan_migrations(&diff_models(&old, &new), &new, SqlDialect::Postgres);
+        let names: Vec<&str> = migrations.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["create_users", "create_sessions", "create_devices"]);
+        let sessions = &migrations[1];
+        assert!(sessions.up.contains("user_id UUID NOT NULL REFERENCES users(id)\n"));
+        assert!(sessions.up.contains("-- devices (Vec<Device>) is stored in devices with a session_id foreign key"));
+        assert!(migrations[2].up.contains("session_id UUID NOT NULL REFERENCES sessions(id)\n"));
+    }
+
+    #[test]
+    fn test_table_name_pluralization() {
+        assert_eq!(table_name("Category"), "categories");
+        assert_eq!(table_name("Address"), "addresses");
+        assert_eq!(table_name("Subscription"), "subscriptions");
+        assert_eq!(table_name("Survey"), "surveys");
+    }
+}
@@ -1,6 +1,30 @@
 pub mod diff;
+pub mod flow;
+pub mod impact;
+pub mod migration;
 pub mod model;
+pub mod naming;
 pub mod registry;
+pub mod relevance;
+pub mod rules;
+pub mod search;
+pub mod types;
+pub mod validation;
+
+/// Fixtures shared by the unit tests of several modules.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::model::BoundedContext;
+
+    /// An otherwise empty bounded context depending on `dependencies`.
+    pub fn context(name: &str, dependencies: &[&str]) -> BoundedContext {
+        BoundedContext {
+            name: name.into(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+}
 
 /// Convert PascalCase / camelCase to snake_case.
 pub fn to_snake(s: &str) -> String {
@@ -9,7 +33,7 @@ pub fn to_snake(s: &str) -> String {
     for (i, &ch) in chars.iter().enumerate() {
         if ch.is_uppercase() && i > 0 {
             let prev_lower = chars[i - 1].is_lowercase();
-            let next_lower = chars.get(i + 1).map_or(false, |c| c.is_lowercase());
+            let next_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
             if prev_lower || next_lower {
                 result.push('_');
             }
@@ -19,9 +43,24 @@ pub fn to_snake(s: &str) -> String {
     result
 }
 
+/// Convert snake_case / kebab-case / space separated words to PascalCase.
+/// Segments that are already PascalCase are kept as-is.
+pub fn to_pascal(s: &str) -> String {
+    s.split(['_', '-', ' ', '.', '/'])
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::to_snake;
+    use super::{to_pascal, to_snake};
 
     #[test]
     fn test_to_snake_simple() {
@@ -39,4 +78,11 @@ mod tests {
     fn test_to_snake_already_lower() {
         assert_eq!(to_snake("already_snake"), "already_snake");
     }
+
+    #[test]
+    fn test_to_pascal() {
+        assert_eq!(to_pascal("line_item"), "LineItem");
+        assert_eq!(to_pascal("billing-accounts"), "BillingAccounts");
+        assert_eq!(to_pascal("Invoice"), "Invoice");
+    }
 }
@@ -1,7 +1,10 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
 
+use super::types::TypeRef;
+
 // ─── Top-Level Domain Model ────────────────────────────────────────────────
 
 /// The root of the domain model configuration.
@@ -16,6 +19,9 @@ pub struct DomainModel {
     /// Bounded contexts (DDD)
     #[serde(default)]
     pub bounded_contexts: Vec<BoundedContext>,
+    /// Systems outside the model that contexts integrate with (payment providers, mail relays, ERPs)
+    #[serde(default)]
+    pub external_systems: Vec<ExternalSystem>,
     /// Cross-cutting architectural rules
     #[serde(default)]
     pub rules: Vec<ArchitecturalRule>,
@@ -38,6 +44,7 @@ impl DomainModel {
             name,
             description: String::new(),
             bounded_contexts: vec![],
+            external_systems: vec![],
             rules: vec![],
             tech_stack: TechStack::default(),
             conventions: Conventions::default(),
@@ -55,7 +62,8 @@ impl DomainModel {
         Ok(model)
     }
 
-    fn validate(&self) -> Result<()> {
+    /// Check structural integrity. Called on load and before the model is stored.
+    pub fn validate(&self) -> Result<()> {
         if self.name.is_empty() {
             anyhow::bail!("Domain model must have a name");
         }
@@ -70,15 +78,433 @@ impl DomainModel {
                         bc.name
                     );
                 }
+                if let Some(sm) = &entity.state_machine {
+                    validate_state_machine(bc, entity, sm)?;
+                }
+            }
+            for enumeration in &bc.enumerations {
+                for (i, variant) in enumeration.variants.iter().enumerate() {
+                    if enumeration.variants[..i].iter().any(|v| v.name.eq_ignore_ascii_case(&variant.name)) {
+                        anyhow::bail!(
+                            "Enumeration '{}' in '{}' lists variant '{}' twice",
+                            enumeration.name, bc.name, variant.name
+                        );
+                    }
+                }
+            }
+            validate_deprecations(bc)?;
+            validate_glossary(bc)?;
+            validate_aggregates(bc)?;
+            validate_operations(bc)?;
+            self.validate_policies(bc)?;
+        }
+        self.validate_external_systems()?;
+        self.conventions.file_structure.validate_layer_mapping()?;
+        self.conventions.file_structure.validate_artifacts()?;
+        for (kind, rule) in self.conventions.naming.rules() {
+            if let Err(e) = super::naming::compile(&rule.pattern) {
+                anyhow::bail!("Naming convention for {kind} has an invalid pattern: {e}");
+            }
+        }
+        for rule in self.rules.iter().filter(|r| !r.check.is_empty()) {
+            if let Err(e) = super::rules::parse(&rule.check) {
+                anyhow::bail!("Rule '{}' has an invalid check: {e}", rule.id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Each integration names an existing context and, if given, an adapter service of it.
+    fn validate_external_systems(&self) -> Result<()> {
+        for system in &self.external_systems {
+            if system.name.is_empty() {
+                anyhow::bail!("External system must have a name");
+            }
+            if self.bounded_contexts.iter().any(|bc| bc.name.eq_ignore_ascii_case(&system.name)) {
+                anyhow::bail!("External system '{}' has the name of a bounded context", system.name);
+            }
+            for integration in &system.integrations {
+                let Some(bc) = self
+                    .bounded_contexts
+                    .iter()
+                    .find(|bc| bc.name.eq_ignore_ascii_case(&integration.context))
+                else {
+                    anyhow::bail!(
+                        "External system '{}' integrates with unknown bounded context '{}'",
+                        system.name, integration.context
+                    );
+                };
+                if !integration.adapter.is_empty()
+                    && !bc.services.iter().any(|s| s.name.eq_ignore_ascii_case(&integration.adapter))
+                {
+                    anyhow::bail!(
+                        "Adapter '{}' for external system '{}' is not a service of '{}'",
+                        integration.adapter, system.name, bc.name
+                    );
+                }
             }
         }
         Ok(())
     }
+
+    /// The model with only the contexts and elements the filter matches.
+    pub fn filtered(&self, filter: &MetadataFilter) -> DomainModel {
+        let mut model = self.clone();
+        model.bounded_contexts = self.bounded_contexts.iter().filter_map(|bc| bc.filtered(filter)).collect();
+        model
+    }
+
+    /// Deprecated contexts and elements as (context, kind, name, metadata); a deprecated
+    /// context is listed once with kind `bounded_context`.
+    pub fn deprecated(&self) -> Vec<(&str, &'static str, &str, &Metadata)> {
+        let mut found = Vec::new();
+        for bc in &self.bounded_contexts {
+            if bc.meta.status == Status::Deprecated {
+                found.push((bc.name.as_str(), "bounded_context", bc.name.as_str(), &bc.meta));
+            }
+            for (kind, name, meta) in bc.elements() {
+                if meta.status == Status::Deprecated {
+                    found.push((bc.name.as_str(), kind, name, meta));
+                }
+            }
+        }
+        found
+    }
+
+    pub fn find_external_system(&self, name: &str) -> Option<&ExternalSystem> {
+        self.external_systems.iter().find(|s| s.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Policies may react to events and issue commands of any context; whether
+    /// that crossing is allowed is reported by `trace_event_flow`, not rejected here.
+    fn validate_policies(&self, bc: &BoundedContext) -> Result<()> {
+        for policy in &bc.policies {
+            for event in &policy.reacts_to {
+                if self.find_event(event).is_none() {
+                    anyhow::bail!("Policy '{}' in '{}' reacts to unknown event '{}'", policy.name, bc.name, event);
+                }
+            }
+            for command in &policy.issues {
+                if self.find_command(command, &bc.name).is_none() {
+                    anyhow::bail!("Policy '{}' in '{}' issues unknown command '{}'", policy.name, bc.name, command);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Find an event in any context.
+    pub fn find_event(&self, name: &str) -> Option<(&BoundedContext, &DomainEvent)> {
+        self.bounded_contexts.iter().find_map(|bc| {
+            bc.events
+                .iter()
+                .find(|e| e.name.eq_ignore_ascii_case(name))
+                .map(|e| (bc, e))
+        })
+    }
+
+    /// Find a command, looking in `context` first and then in every other context.
+    pub fn find_command(&self, name: &str, context: &str) -> Option<(&BoundedContext, &Operation)> {
+        let local = self
+            .bounded_contexts
+            .iter()
+            .filter(|bc| bc.name.eq_ignore_ascii_case(context));
+        local.chain(self.bounded_contexts.iter()).find_map(|bc| {
+            bc.commands
+                .iter()
+                .find(|c| c.name.eq_ignore_ascii_case(name))
+                .map(|c| (bc, c))
+        })
+    }
+}
+
+fn validate_state_machine(bc: &BoundedContext, entity: &Entity, sm: &StateMachine) -> Result<()> {
+    let is_state = |s: &str| sm.states.iter().any(|st| st.eq_ignore_ascii_case(s));
+
+    if !sm.field.is_empty() {
+        let Some(field) = entity.fields.iter().find(|f| f.name.eq_ignore_ascii_case(&sm.field)) else {
+            anyhow::bail!("State machine of '{}' uses unknown field '{}'", entity.name, sm.field);
+        };
+        // A state field typed as an enumeration must use its variants as states
+        if let Some(en) = type_names(&field.field_type).find_map(|t| bc.find_enumeration(t)) {
+            if let Some(state) = sm.states.iter().find(|s| !en.variants.iter().any(|v| v.name.eq_ignore_ascii_case(s))) {
+                anyhow::bail!(
+                    "State '{}' of '{}' is not a variant of '{}', the type of '{}'",
+                    state, entity.name, en.name, field.name
+                );
+            }
+        }
+    }
+    if !is_state(&sm.initial) {
+        anyhow::bail!("Initial state '{}' of '{}' is not one of its states", sm.initial, entity.name);
+    }
+    for t in &sm.transitions {
+        for state in [&t.from, &t.to] {
+            if !is_state(state) {
+                anyhow::bail!(
+                    "Transition {} → {} of '{}' uses unknown state '{}'",
+                    t.from, t.to, entity.name, state
+                );
+            }
+        }
+        if !entity.methods.iter().any(|m| m.name.eq_ignore_ascii_case(&t.method)) {
+            anyhow::bail!(
+                "Transition {} → {} of '{}' needs method '{}', which the entity does not have",
+                t.from, t.to, entity.name, t.method
+            );
+        }
+        if !t.event.is_empty() && !bc.events.iter().any(|e| e.name.eq_ignore_ascii_case(&t.event)) {
+            anyhow::bail!(
+                "Transition {} → {} of '{}' emits unknown event '{}'",
+                t.from, t.to, entity.name, t.event
+            );
+        }
+    }
+    Ok(())
+}
+
+/// A replacement is only named for deprecated elements, and names another element of the context.
+fn validate_deprecations(bc: &BoundedContext) -> Result<()> {
+    let elements = bc.elements();
+    for (_, name, meta) in &elements {
+        if meta.replaced_by.is_empty() {
+            continue;
+        }
+        if meta.status != Status::Deprecated {
+            anyhow::bail!(
+                "'{}' in '{}' names replacement '{}' but is not deprecated",
+                name, bc.name, meta.replaced_by
+            );
+        }
+        if meta.replaced_by.eq_ignore_ascii_case(name) {
+            anyhow::bail!("'{}' in '{}' cannot replace itself", name, bc.name);
+        }
+        if !elements.iter().any(|(_, other, _)| other.eq_ignore_ascii_case(&meta.replaced_by)) {
+            anyhow::bail!("'{}' in '{}' is replaced by unknown element '{}'", name, bc.name, meta.replaced_by);
+        }
+    }
+    Ok(())
+}
+
+/// Each word names at most one term, and a term cannot forbid a word the glossary accepts.
+fn validate_glossary(bc: &BoundedContext) -> Result<()> {
+    for (i, term) in bc.glossary.iter().enumerate() {
+        if term.term.is_empty() {
+            anyhow::bail!("Glossary term in bounded context '{}' must have a name", bc.name);
+        }
+        for word in std::iter::once(&term.term).chain(&term.synonyms) {
+            if let Some(other) = bc.glossary[..i].iter().find(|t| t.names(word)) {
+                anyhow::bail!(
+                    "'{}' is defined twice in the '{}' glossary (terms '{}' and '{}')",
+                    word, bc.name, other.term, term.term
+                );
+            }
+        }
+        for alias in &term.forbidden_aliases {
+            if let Some(other) = bc.find_term(alias) {
+                anyhow::bail!(
+                    "'{}' glossary forbids '{}' as an alias of '{}', but it names the term '{}'",
+                    bc.name, alias, term.term, other.term
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_aggregates(bc: &BoundedContext) -> Result<()> {
+    let has_entity = |name: &str| bc.entities.iter().any(|e| e.name.eq_ignore_ascii_case(name));
+
+    for agg in &bc.aggregates {
+        if !has_entity(&agg.root) {
+            anyhow::bail!(
+                "Aggregate '{}' in '{}' has root '{}', which is not an entity of the context",
+                agg.name, bc.name, agg.root
+            );
+        }
+        for member in &agg.entities {
+            if !has_entity(member) {
+                anyhow::bail!("Aggregate '{}' in '{}' lists unknown entity '{}'", agg.name, bc.name, member);
+            }
+            if bc.is_aggregate_root(member) {
+                anyhow::bail!(
+                    "Entity '{}' is an aggregate root and cannot be a member of aggregate '{}'",
+                    member, agg.name
+                );
+            }
+            if let Some(other) = bc
+                .aggregates
+                .iter()
+                .find(|o| o.name != agg.name && o.entities.iter().any(|e| e.eq_ignore_ascii_case(member)))
+            {
+                anyhow::bail!(
+                    "Entity '{}' belongs to both aggregate '{}' and '{}'",
+                    member, agg.name, other.name
+                );
+            }
+        }
+        for member in &agg.value_objects {
+            if !bc.value_objects.iter().any(|v| v.name.eq_ignore_ascii_case(member)) {
+                anyhow::bail!("Aggregate '{}' in '{}' lists unknown value object '{}'", agg.name, bc.name, member);
+            }
+        }
+    }
+
+    for repo in &bc.repositories {
+        if !bc.is_aggregate_root(&repo.aggregate) {
+            let hint = match bc.aggregate_of(&repo.aggregate) {
+                Some((agg, root)) => format!(" — '{}' belongs to aggregate '{}', load it through '{}'", repo.aggregate, agg, root),
+                None => String::new(),
+            };
+            anyhow::bail!(
+                "Repository '{}' in '{}' manages '{}', which is not an aggregate root{}",
+                repo.name, bc.name, repo.aggregate, hint
+            );
+        }
+    }
+    Ok(())
+}
+
+fn validate_operations(bc: &BoundedContext) -> Result<()> {
+    for kind in OperationKind::ALL {
+        for op in bc.operations(kind) {
+            if !op.aggregate.is_empty() && !bc.is_aggregate_root(&op.aggregate) {
+                anyhow::bail!(
+                    "{} '{}' in '{}' targets '{}', which is not an aggregate root",
+                    kind.label(), op.name, bc.name, op.aggregate
+                );
+            }
+            if !op.handler.is_empty() && !bc.services.iter().any(|s| s.name.eq_ignore_ascii_case(&op.handler)) {
+                anyhow::bail!(
+                    "{} '{}' in '{}' is handled by unknown service '{}'",
+                    kind.label(), op.name, bc.name, op.handler
+                );
+            }
+            for event in &op.emits {
+                if !bc.events.iter().any(|e| e.name.eq_ignore_ascii_case(event)) {
+                    anyhow::bail!("{} '{}' in '{}' emits unknown event '{}'", kind.label(), op.name, bc.name, event);
+                }
+            }
+            for step in &op.steps {
+                if !bc.commands.iter().chain(&bc.queries).any(|o| o.name.eq_ignore_ascii_case(step)) {
+                    anyhow::bail!(
+                        "{} '{}' in '{}' runs '{}', which is not a command or query of the context",
+                        kind.label(), op.name, bc.name, step
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// ─── Metadata ──────────────────────────────────────────────────────────────
+
+/// Ownership and lifecycle information shared by contexts and their elements.
+/// Flattened into the element, so `"owners"`, `"tags"`, `"status"` and `"links"`
+/// sit next to its name and are left out when unset.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// Teams or people responsible for the element
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Status::is_active")]
+    pub status: Status,
+    /// Design docs, ADRs, runbooks, dashboards
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<Link>,
+    /// The element to use instead, once deprecated
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub replaced_by: String,
+    /// When or under which condition a deprecated element is deleted, e.g. "after the 2.0 release"
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub sunset: String,
+}
+
+impl Metadata {
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// "'X' is deprecated — use 'Y' instead (sunset: …)", for deprecated elements only.
+    pub fn deprecation_warning(&self, name: &str) -> Option<String> {
+        if self.status != Status::Deprecated {
+            return None;
+        }
+        let mut warning = format!("'{name}' is deprecated");
+        if !self.replaced_by.is_empty() {
+            warning.push_str(&format!(" — use '{}' instead", self.replaced_by));
+        }
+        if !self.sunset.is_empty() {
+            warning.push_str(&format!(" (sunset: {})", self.sunset));
+        }
+        Some(warning)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// Designed but not built yet; code may not exist
+    Proposed,
+    #[default]
+    Active,
+    /// Still present, but new code must not start using it
+    Deprecated,
+}
+
+impl Status {
+    pub fn is_active(&self) -> bool {
+        *self == Status::Active
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Proposed => "proposed",
+            Status::Active => "active",
+            Status::Deprecated => "deprecated",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "proposed" => Some(Status::Proposed),
+            "active" => Some(Status::Active),
+            "deprecated" => Some(Status::Deprecated),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Link {
+    #[serde(default)]
+    pub title: String,
+    pub url: String,
+}
+
+/// Selects elements by tag and/or status; an empty filter matches everything.
+#[derive(Debug, Clone, Default)]
+pub struct MetadataFilter {
+    pub tag: Option<String>,
+    pub status: Option<Status>,
+}
+
+impl MetadataFilter {
+    pub fn is_empty(&self) -> bool {
+        self.tag.is_none() && self.status.is_none()
+    }
+
+    pub fn matches(&self, meta: &Metadata) -> bool {
+        self.tag.as_ref().is_none_or(|t| meta.has_tag(t)) && self.status.is_none_or(|s| meta.status == s)
+    }
 }
 
 // ─── Bounded Context ───────────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BoundedContext {
     pub name: String,
     #[serde(default)]
@@ -90,6 +516,27 @@ pub struct BoundedContext {
     pub entities: Vec<Entity>,
     #[serde(default)]
     pub value_objects: Vec<ValueObject>,
+    /// Closed sets of values (e.g. `UserStatus`) referenced by field types
+    #[serde(default)]
+    pub enumerations: Vec<Enumeration>,
+    /// Consistency boundaries: a root entity plus member entities and value objects
+    #[serde(default)]
+    pub aggregates: Vec<Aggregate>,
+    /// Operations that change state
+    #[serde(default)]
+    pub commands: Vec<Operation>,
+    /// Operations that read state
+    #[serde(default)]
+    pub queries: Vec<Operation>,
+    /// User-facing flows composed of commands and queries
+    #[serde(default)]
+    pub use_cases: Vec<Operation>,
+    /// Reactions to domain events (policies, sagas, process managers)
+    #[serde(default)]
+    pub policies: Vec<Policy>,
+    /// The context's ubiquitous language: what its words mean here
+    #[serde(default)]
+    pub glossary: Vec<Term>,
     #[serde(default)]
     pub services: Vec<Service>,
     #[serde(default)]
@@ -99,6 +546,315 @@ pub struct BoundedContext {
     /// Allowed dependencies to other bounded contexts
     #[serde(default)]
     pub dependencies: Vec<String>,
+    #[serde(flatten)]
+    pub meta: Metadata,
+}
+
+/// Every element kind, in the order `BoundedContext::elements` lists them.
+pub const ELEMENT_KINDS: &[&str] = &[
+    "entity", "value_object", "enumeration", "aggregate", "command", "query", "use_case", "policy", "service",
+    "repository", "event",
+];
+
+impl BoundedContext {
+    /// Whether the entity is an aggregate root — flagged as one, or the root of an aggregate.
+    pub fn is_aggregate_root(&self, entity: &str) -> bool {
+        self.entities
+            .iter()
+            .any(|e| e.name.eq_ignore_ascii_case(entity) && e.aggregate_root)
+            || self.aggregates.iter().any(|a| a.root.eq_ignore_ascii_case(entity))
+    }
+
+    /// The aggregate an entity or value object belongs to, as (aggregate name, root).
+    /// Aggregate roots without an explicit aggregate form one named after themselves.
+    pub fn aggregate_of(&self, element: &str) -> Option<(&str, &str)> {
+        if let Some(agg) = self.aggregates.iter().find(|a| a.contains(element)) {
+            return Some((&agg.name, &agg.root));
+        }
+        self.entities
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(element) && e.aggregate_root)
+            .map(|e| (e.name.as_str(), e.name.as_str()))
+    }
+
+    pub fn operations(&self, kind: OperationKind) -> &Vec<Operation> {
+        match kind {
+            OperationKind::Command => &self.commands,
+            OperationKind::Query => &self.queries,
+            OperationKind::UseCase => &self.use_cases,
+        }
+    }
+
+    pub fn operations_mut(&mut self, kind: OperationKind) -> &mut Vec<Operation> {
+        match kind {
+            OperationKind::Command => &mut self.commands,
+            OperationKind::Query => &mut self.queries,
+            OperationKind::UseCase => &mut self.use_cases,
+        }
+    }
+
+    pub fn find_enumeration(&self, name: &str) -> Option<&Enumeration> {
+        self.enumerations.iter().find(|e| e.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Every element of the context as (kind, name, metadata), in declaration order.
+    pub fn elements(&self) -> Vec<(&'static str, &str, &Metadata)> {
+        fn each<'a, T>(kind: &'static str, items: &'a [T], f: fn(&T) -> (&str, &Metadata)) -> impl Iterator<Item = (&'static str, &'a str, &'a Metadata)> {
+            items.iter().map(move |i| {
+                let (name, meta) = f(i);
+                (kind, name, meta)
+            })
+        }
+        each("entity", &self.entities, |e| (&e.name, &e.meta))
+            .chain(each("value_object", &self.value_objects, |v| (&v.name, &v.meta)))
+            .chain(each("enumeration", &self.enumerations, |e| (&e.name, &e.meta)))
+            .chain(each("aggregate", &self.aggregates, |a| (&a.name, &a.meta)))
+            .chain(each("command", &self.commands, |o| (&o.name, &o.meta)))
+            .chain(each("query", &self.queries, |o| (&o.name, &o.meta)))
+            .chain(each("use_case", &self.use_cases, |o| (&o.name, &o.meta)))
+            .chain(each("policy", &self.policies, |p| (&p.name, &p.meta)))
+            .chain(each("service", &self.services, |s| (&s.name, &s.meta)))
+            .chain(each("repository", &self.repositories, |r| (&r.name, &r.meta)))
+            .chain(each("event", &self.events, |e| (&e.name, &e.meta)))
+            .collect()
+    }
+
+    /// The kind and metadata of the element with the given name.
+    pub fn element_meta_mut(&mut self, name: &str) -> Option<(&'static str, &mut Metadata)> {
+        let is = |n: &str| n.eq_ignore_ascii_case(name);
+        if let Some(e) = self.entities.iter_mut().find(|e| is(&e.name)) {
+            return Some(("entity", &mut e.meta));
+        }
+        if let Some(v) = self.value_objects.iter_mut().find(|v| is(&v.name)) {
+            return Some(("value_object", &mut v.meta));
+        }
+        if let Some(e) = self.enumerations.iter_mut().find(|e| is(&e.name)) {
+            return Some(("enumeration", &mut e.meta));
+        }
+        if let Some(a) = self.aggregates.iter_mut().find(|a| is(&a.name)) {
+            return Some(("aggregate", &mut a.meta));
+        }
+        for (kind, ops) in [("command", &mut self.commands), ("query", &mut self.queries), ("use_case", &mut self.use_cases)] {
+            if let Some(o) = ops.iter_mut().find(|o| is(&o.name)) {
+                return Some((kind, &mut o.meta));
+            }
+        }
+        if let Some(p) = self.policies.iter_mut().find(|p| is(&p.name)) {
+            return Some(("policy", &mut p.meta));
+        }
+        if let Some(s) = self.services.iter_mut().find(|s| is(&s.name)) {
+            return Some(("service", &mut s.meta));
+        }
+        if let Some(r) = self.repositories.iter_mut().find(|r| is(&r.name)) {
+            return Some(("repository", &mut r.meta));
+        }
+        self.events.iter_mut().find(|e| is(&e.name)).map(|e| ("event", &mut e.meta))
+    }
+
+    /// The context narrowed to the elements the filter matches. A context that matches
+    /// itself is kept whole; one where neither it nor any element matches yields `None`.
+    pub fn filtered(&self, filter: &MetadataFilter) -> Option<BoundedContext> {
+        if filter.matches(&self.meta) {
+            return Some(self.clone());
+        }
+        let mut bc = self.clone();
+        bc.entities.retain(|e| filter.matches(&e.meta));
+        bc.value_objects.retain(|v| filter.matches(&v.meta));
+        bc.enumerations.retain(|e| filter.matches(&e.meta));
+        bc.aggregates.retain(|a| filter.matches(&a.meta));
+        bc.commands.retain(|o| filter.matches(&o.meta));
+        bc.queries.retain(|o| filter.matches(&o.meta));
+        bc.use_cases.retain(|o| filter.matches(&o.meta));
+        bc.policies.retain(|p| filter.matches(&p.meta));
+        bc.services.retain(|s| filter.matches(&s.meta));
+        bc.repositories.retain(|r| filter.matches(&r.meta));
+        bc.events.retain(|e| filter.matches(&e.meta));
+        (!bc.elements().is_empty()).then_some(bc)
+    }
+
+    /// Find a command, query or use case by name.
+    /// Find a glossary term by its name or one of its synonyms.
+    pub fn find_term(&self, word: &str) -> Option<&Term> {
+        self.glossary.iter().find(|t| t.names(word))
+    }
+
+    pub fn find_operation(&self, name: &str) -> Option<(OperationKind, &Operation)> {
+        OperationKind::ALL.into_iter().find_map(|kind| {
+            self.operations(kind)
+                .iter()
+                .find(|o| o.name.eq_ignore_ascii_case(name))
+                .map(|o| (kind, o))
+        })
+    }
+}
+
+// ─── Aggregate ─────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Aggregate {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// The root entity; all mutations go through it
+    pub root: String,
+    /// Member (child) entities, by name
+    #[serde(default)]
+    pub entities: Vec<String>,
+    /// Member value objects, by name
+    #[serde(default)]
+    pub value_objects: Vec<String>,
+    #[serde(flatten)]
+    pub meta: Metadata,
+}
+
+impl Aggregate {
+    /// Whether the element is the root or a member of this aggregate.
+    pub fn contains(&self, element: &str) -> bool {
+        self.root.eq_ignore_ascii_case(element)
+            || self.entities.iter().any(|e| e.eq_ignore_ascii_case(element))
+            || self.value_objects.iter().any(|v| v.eq_ignore_ascii_case(element))
+    }
+}
+
+// ─── Commands, Queries & Use Cases ────────────────────────────────────────
+
+/// Something a user or another system triggers. Commands change an aggregate,
+/// queries read it, use cases chain commands and queries into one flow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Operation {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Input fields
+    #[serde(default)]
+    pub fields: Vec<Field>,
+    /// The aggregate root it targets
+    #[serde(default)]
+    pub aggregate: String,
+    /// Domain events emitted on success
+    #[serde(default)]
+    pub emits: Vec<String>,
+    /// Invariants checked before the change is applied
+    #[serde(default)]
+    pub invariants: Vec<String>,
+    /// The service that handles it
+    #[serde(default)]
+    pub handler: String,
+    /// Result type (queries)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub returns: String,
+    /// Commands and queries run in order (use cases)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps: Vec<String>,
+    #[serde(flatten)]
+    pub meta: Metadata,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    Command,
+    Query,
+    UseCase,
+}
+
+impl OperationKind {
+    pub const ALL: [OperationKind; 3] = [Self::Command, Self::Query, Self::UseCase];
+
+    /// Parse the tool argument form: "command", "query" or "use_case".
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().replace(['-', ' '], "_").as_str() {
+            "command" => Some(Self::Command),
+            "query" => Some(Self::Query),
+            "use_case" | "usecase" => Some(Self::UseCase),
+            _ => None,
+        }
+    }
+
+    /// Key of the list in `BoundedContext` (and of diff paths).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Command => "commands",
+            Self::Query => "queries",
+            Self::UseCase => "use_cases",
+        }
+    }
+
+    /// The kind as listed by `BoundedContext::elements`.
+    pub fn element_kind(self) -> &'static str {
+        match self {
+            Self::Command => "command",
+            Self::Query => "query",
+            Self::UseCase => "use_case",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Command => "Command",
+            Self::Query => "Query",
+            Self::UseCase => "Use case",
+        }
+    }
+}
+
+// ─── Policy ────────────────────────────────────────────────────────────────
+
+/// "Whenever <event>, then <command>." Subscribes to domain events — from its own
+/// or other contexts — and issues commands in response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Policy {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub kind: PolicyKind,
+    /// Events it subscribes to, by name
+    #[serde(default)]
+    pub reacts_to: Vec<String>,
+    /// Commands it issues, by name
+    #[serde(default)]
+    pub issues: Vec<String>,
+    #[serde(flatten)]
+    pub meta: Metadata,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyKind {
+    /// Stateless reaction
+    #[default]
+    Policy,
+    /// Long-running process that tracks state across several events
+    Saga,
+}
+
+// ─── Glossary ──────────────────────────────────────────────────────────────
+
+/// A word of the ubiquitous language. The same word may mean different things
+/// in different contexts, so each context keeps its own glossary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Term {
+    pub term: String,
+    #[serde(default)]
+    pub definition: String,
+    /// Other words accepted for the same concept
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub synonyms: Vec<String>,
+    /// Words that must not name this concept in this context (e.g. "Client" for Customer)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forbidden_aliases: Vec<String>,
+}
+
+impl Term {
+    /// Whether `word` is the term itself or one of its synonyms.
+    pub fn names(&self, word: &str) -> bool {
+        self.term.eq_ignore_ascii_case(word) || self.synonyms.iter().any(|s| s.eq_ignore_ascii_case(word))
+    }
+
+    pub fn forbids(&self, word: &str) -> bool {
+        self.forbidden_aliases.iter().any(|a| a.eq_ignore_ascii_case(word))
+    }
 }
 
 // ─── Entity ────────────────────────────────────────────────────────────────
@@ -117,6 +873,64 @@ pub struct Entity {
     pub methods: Vec<Method>,
     #[serde(default)]
     pub invariants: Vec<String>,
+    /// Lifecycle: the states the entity moves through and the methods that move it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_machine: Option<StateMachine>,
+    #[serde(flatten)]
+    pub meta: Metadata,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateMachine {
+    /// The field holding the current state (e.g. "status")
+    #[serde(default)]
+    pub field: String,
+    pub states: Vec<String>,
+    pub initial: String,
+    #[serde(default)]
+    pub transitions: Vec<Transition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transition {
+    pub from: String,
+    pub to: String,
+    /// Entity method that performs (and guards) the transition
+    pub method: String,
+    /// Domain event emitted when it happens
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub event: String,
+}
+
+impl StateMachine {
+    /// States without outgoing transitions.
+    pub fn terminal_states(&self) -> Vec<&str> {
+        self.states
+            .iter()
+            .filter(|s| !self.transitions.iter().any(|t| t.from.eq_ignore_ascii_case(s)))
+            .map(|s| s.as_str())
+            .collect()
+    }
+
+    /// Render as a Mermaid `stateDiagram-v2`. Transitions are labelled
+    /// `method()`, followed by ` / Event` when they emit one.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("stateDiagram-v2\n");
+        if !self.initial.is_empty() {
+            out.push_str(&format!("    [*] --> {}\n", self.initial));
+        }
+        for t in &self.transitions {
+            let mut label = format!("{}()", t.method);
+            if !t.event.is_empty() {
+                label.push_str(&format!(" / {}", t.event));
+            }
+            out.push_str(&format!("    {} --> {} : {}\n", t.from, t.to, label));
+        }
+        for state in self.terminal_states() {
+            out.push_str(&format!("    {state} --> [*]\n"));
+        }
+        out
+    }
 }
 
 // ─── Value Object ──────────────────────────────────────────────────────────
@@ -130,6 +944,35 @@ pub struct ValueObject {
     pub fields: Vec<Field>,
     #[serde(default)]
     pub validation_rules: Vec<String>,
+    #[serde(flatten)]
+    pub meta: Metadata,
+}
+
+// ─── Enumeration ───────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Enumeration {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub variants: Vec<Variant>,
+    #[serde(flatten)]
+    pub meta: Metadata,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Variant {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Type names mentioned in a field type: `Option<Vec<UserStatus>>` → Option, Vec, UserStatus.
+pub fn type_names(field_type: &str) -> impl Iterator<Item = &str> {
+    field_type
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|s| !s.is_empty())
 }
 
 // ─── Service ───────────────────────────────────────────────────────────────
@@ -145,6 +988,8 @@ pub struct Service {
     pub methods: Vec<Method>,
     #[serde(default)]
     pub dependencies: Vec<String>,
+    #[serde(flatten)]
+    pub meta: Metadata,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -156,6 +1001,16 @@ pub enum ServiceKind {
     Infrastructure,
 }
 
+impl ServiceKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServiceKind::Domain => "domain",
+            ServiceKind::Application => "application",
+            ServiceKind::Infrastructure => "infrastructure",
+        }
+    }
+}
+
 // ─── Repository ────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -165,6 +1020,8 @@ pub struct Repository {
     pub aggregate: String,
     #[serde(default)]
     pub methods: Vec<Method>,
+    #[serde(flatten)]
+    pub meta: Metadata,
 }
 
 // ─── Domain Event ──────────────────────────────────────────────────────────
@@ -179,11 +1036,50 @@ pub struct DomainEvent {
     /// Which entity/aggregate emits this event
     #[serde(default)]
     pub source: String,
+    /// Whether the event is published to other contexts via the message broker
+    #[serde(default)]
+    pub published: bool,
+    /// Broker channel / subject the event is published on (e.g. "billing.invoice.paid")
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub channel: String,
+    #[serde(flatten)]
+    pub meta: Metadata,
+}
+
+// ─── External System ───────────────────────────────────────────────────────
+
+/// A system outside the bounded contexts, shown as a boundary node in diagrams.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExternalSystem {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// How it is reached, e.g. "REST", "SMTP", "SOAP", "SFTP"
+    #[serde(default)]
+    pub protocol: String,
+    /// Contexts that talk to it
+    #[serde(default)]
+    pub integrations: Vec<Integration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Integration {
+    pub context: String,
+    /// Service in `context` that wraps the system (e.g. `StripeGateway`)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub adapter: String,
+}
+
+impl ExternalSystem {
+    /// The integration of `context`, if it talks to this system.
+    pub fn integration(&self, context: &str) -> Option<&Integration> {
+        self.integrations.iter().find(|i| i.context.eq_ignore_ascii_case(context))
+    }
 }
 
 // ─── Shared Building Blocks ────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Field {
     pub name: String,
     #[serde(rename = "type")]
@@ -194,6 +1090,13 @@ pub struct Field {
     pub description: String,
 }
 
+impl Field {
+    /// The parsed `field_type`.
+    pub fn type_ref(&self) -> Result<TypeRef> {
+        TypeRef::parse(&self.field_type)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Method {
     pub name: String,
@@ -216,6 +1119,10 @@ pub struct ArchitecturalRule {
     /// The pattern/layer this rule applies to
     #[serde(default)]
     pub scope: String,
+    /// Machine-checkable form, e.g. `entity where aggregate_root must have repository`
+    /// (see `rules::parse`); rules without one are guidance only
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub check: String,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -259,16 +1166,160 @@ pub struct Conventions {
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct NamingConventions {
-    #[serde(default)]
-    pub entities: String,
-    #[serde(default)]
-    pub value_objects: String,
-    #[serde(default)]
-    pub services: String,
-    #[serde(default)]
-    pub repositories: String,
-    #[serde(default)]
-    pub events: String,
+    #[serde(default, skip_serializing_if = "NamingRule::is_empty")]
+    pub entities: NamingRule,
+    #[serde(default, skip_serializing_if = "NamingRule::is_empty")]
+    pub value_objects: NamingRule,
+    #[serde(default, skip_serializing_if = "NamingRule::is_empty")]
+    pub enumerations: NamingRule,
+    #[serde(default, skip_serializing_if = "NamingRule::is_empty")]
+    pub commands: NamingRule,
+    #[serde(default, skip_serializing_if = "NamingRule::is_empty")]
+    pub queries: NamingRule,
+    #[serde(default, skip_serializing_if = "NamingRule::is_empty")]
+    pub services: NamingRule,
+    #[serde(default, skip_serializing_if = "NamingRule::is_empty")]
+    pub repositories: NamingRule,
+    #[serde(default, skip_serializing_if = "NamingRule::is_empty")]
+    pub events: NamingRule,
+}
+
+impl NamingConventions {
+    /// Every convention with the element kind it applies to, as listed by `BoundedContext::elements`.
+    pub fn rules(&self) -> [(&'static str, &NamingRule); 8] {
+        [
+            ("entity", &self.entities),
+            ("value_object", &self.value_objects),
+            ("enumeration", &self.enumerations),
+            ("command", &self.commands),
+            ("query", &self.queries),
+            ("service", &self.services),
+            ("repository", &self.repositories),
+            ("event", &self.events),
+        ]
+    }
+
+    pub fn for_kind(&self, kind: &str) -> Option<&NamingRule> {
+        self.rules().into_iter().find(|(k, _)| *k == kind).map(|(_, rule)| rule)
+    }
+}
+
+/// How the names of one element kind are formed. Written either as plain text,
+/// which is only shown to the reader, or as an object whose parts are enforced:
+///
+/// ```json
+/// { "case": "pascal_case", "suffix": "Repository", "description": "e.g. UserRepository" }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "NamingRuleRepr", into = "NamingRuleRepr")]
+pub struct NamingRule {
+    pub case: Option<CaseStyle>,
+    pub prefix: String,
+    pub suffix: String,
+    /// Regular expression the whole name must match
+    pub pattern: String,
+    pub tense: Option<Tense>,
+    pub description: String,
+}
+
+impl NamingRule {
+    pub fn is_empty(&self) -> bool {
+        *self == NamingRule::default()
+    }
+
+    /// Whether anything besides the description can be checked.
+    pub fn is_checked(&self) -> bool {
+        self.case.is_some() || !self.prefix.is_empty() || !self.suffix.is_empty() || !self.pattern.is_empty() || self.tense.is_some()
+    }
+}
+
+impl From<&str> for NamingRule {
+    fn from(description: &str) -> Self {
+        NamingRule {
+            description: description.to_string(),
+            ..NamingRule::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum NamingRuleRepr {
+    Text(String),
+    Rule {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        case: Option<CaseStyle>,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        prefix: String,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        suffix: String,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        pattern: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tense: Option<Tense>,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        description: String,
+    },
+}
+
+impl From<NamingRuleRepr> for NamingRule {
+    fn from(repr: NamingRuleRepr) -> Self {
+        match repr {
+            NamingRuleRepr::Text(text) => NamingRule::from(text.as_str()),
+            NamingRuleRepr::Rule { case, prefix, suffix, pattern, tense, description } => NamingRule {
+                case,
+                prefix,
+                suffix,
+                pattern,
+                tense,
+                description,
+            },
+        }
+    }
+}
+
+impl From<NamingRule> for NamingRuleRepr {
+    fn from(rule: NamingRule) -> Self {
+        if !rule.is_checked() {
+            return NamingRuleRepr::Text(rule.description);
+        }
+        NamingRuleRepr::Rule {
+            case: rule.case,
+            prefix: rule.prefix,
+            suffix: rule.suffix,
+            pattern: rule.pattern,
+            tense: rule.tense,
+            description: rule.description,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CaseStyle {
+    /// `InvoiceLine`
+    #[serde(rename = "pascal_case")]
+    Pascal,
+    /// `invoiceLine`
+    #[serde(rename = "camel_case")]
+    Camel,
+    /// `invoice_line`
+    #[serde(rename = "snake_case")]
+    Snake,
+    /// `INVOICE_LINE`
+    #[serde(rename = "screaming_snake_case")]
+    ScreamingSnake,
+    /// `invoice-line`
+    #[serde(rename = "kebab_case")]
+    Kebab,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tense {
+    /// The last word is a past participle, e.g. `InvoicePaid`
+    Past,
+    /// The first word is a plain verb, e.g. `PayInvoice`
+    Imperative,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -278,4 +1329,592 @@ pub struct FileStructure {
     pub pattern: String,
     #[serde(default)]
     pub layers: Vec<String>,
+    /// Layer per element kind, e.g. `{"policy": "domain"}`. Unlisted kinds keep the
+    /// defaults: commands, queries, use cases and policies in `application`, the rest in
+    /// `domain`. Services and repositories are placed by the settings below.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub kind_layers: BTreeMap<String, String>,
+    /// Layer per service kind, e.g. `{"infrastructure": "adapters"}`; by default the
+    /// layer of the same name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub service_layers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "RepositoryLayers::is_empty")]
+    pub repository_layers: RepositoryLayers,
+    /// The files an element of a kind needs, replacing the one file `pattern` gives it,
+    /// e.g. `{"entity": [{"role": "source", ...}, {"role": "test", "pattern": "tests/{context}/{type}.rs"}]}`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub artifacts: BTreeMap<String, Vec<ArtifactPattern>>,
+}
+
+/// One file of an element, with the placeholders `{context}`, `{module_path}`, `{layer}`,
+/// `{kind}`, `{type}` (snake_case name), `{Name}` and `{name_kebab}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtifactPattern {
+    /// What the file is for, e.g. `trait`, `implementation`, `test` or `module`
+    pub role: String,
+    pub pattern: String,
+    /// Layer substituted for `{layer}`; the layer of the element's kind by default
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub layer: String,
+}
+
+/// A file an element needs, see `FileStructure::element_files`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ElementFile {
+    pub role: String,
+    pub path: String,
+}
+
+/// The values substituted into a file pattern.
+pub struct PathVars<'a> {
+    pub context: &'a str,
+    /// The context's `module_path`; `src/{context}` if empty
+    pub module_path: &'a str,
+    pub layer: &'a str,
+    pub kind: &'a str,
+    pub name: &'a str,
+}
+
+const PLACEHOLDERS: &[&str] = &["context", "module_path", "layer", "kind", "type", "Name", "name_kebab"];
+
+fn placeholder_list() -> String {
+    PLACEHOLDERS.iter().map(|p| format!("{{{p}}}")).collect::<Vec<_>>().join(", ")
+}
+
+/// Substitute the placeholders of a file pattern.
+pub fn render_path(pattern: &str, vars: &PathVars) -> String {
+    let context = super::to_snake(vars.context);
+    let module_path = match vars.module_path.trim_end_matches('/') {
+        "" => format!("src/{context}"),
+        path => path.to_string(),
+    };
+    let snake = super::to_snake(vars.name);
+    pattern
+        .replace("{module_path}", &module_path)
+        .replace("{context}", &context)
+        .replace("{layer}", vars.layer)
+        .replace("{kind}", vars.kind)
+        .replace("{type}", &snake)
+        .replace("{Name}", vars.name)
+        .replace("{name_kebab}", &snake.replace('_', "-"))
+}
+
+/// Where a repository's trait and its implementation live.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RepositoryLayers {
+    /// `domain` by default
+    #[serde(default, rename = "trait", skip_serializing_if = "String::is_empty")]
+    pub interface: String,
+    /// `infrastructure` by default
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub implementation: String,
+}
+
+impl RepositoryLayers {
+    pub fn is_empty(&self) -> bool {
+        *self == RepositoryLayers::default()
+    }
+}
+
+impl FileStructure {
+    /// The layer an element lives in. A service is placed by its kind, or in
+    /// `application` if `bc` does not define it; a repository by its implementation.
+    pub fn layer_of(&self, bc: &BoundedContext, kind: &str, name: &str) -> &str {
+        let service = bc.services.iter().find(|s| s.name.eq_ignore_ascii_case(name)).map(|s| &s.kind);
+        self.kind_layer(kind, service)
+    }
+
+    /// The layer of an element of `kind`; `service` is the kind of a service, if known.
+    pub fn kind_layer(&self, kind: &str, service: Option<&ServiceKind>) -> &str {
+        match kind {
+            "service" => self.service_layer(service),
+            "repository" => self.repository_layer(false),
+            _ => match self.kind_layers.get(kind) {
+                Some(layer) => layer,
+                None if matches!(kind, "command" | "query" | "use_case" | "policy") => "application",
+                None => "domain",
+            },
+        }
+    }
+
+    /// The layer of a service of `kind`; `None` is a service the model does not describe.
+    pub fn service_layer(&self, kind: Option<&ServiceKind>) -> &str {
+        let kind = kind.map_or("application", |k| k.as_str());
+        self.service_layers.get(kind).map_or(kind, |layer| layer)
+    }
+
+    /// The layer of repository traits, or with `interface` false of their implementations.
+    pub fn repository_layer(&self, interface: bool) -> &str {
+        match (interface, &self.repository_layers) {
+            (true, r) if !r.interface.is_empty() => &r.interface,
+            (true, _) => "domain",
+            (false, r) if !r.implementation.is_empty() => &r.implementation,
+            (false, _) => "infrastructure",
+        }
+    }
+
+    /// `pattern`, or the layout used when none is configured.
+    pub fn pattern_or_default(&self) -> &str {
+        if self.pattern.is_empty() {
+            "src/{context}/{layer}/{type}.rs"
+        } else {
+            &self.pattern
+        }
+    }
+
+    /// Every file an element needs, the element's own first: its `artifacts` if the kind
+    /// has any, else the `pattern` file — for a repository both its implementation and,
+    /// in a layer of its own, its trait.
+    pub fn element_files(&self, vars: &PathVars, service: Option<&ServiceKind>) -> Vec<ElementFile> {
+        let layer = self.kind_layer(vars.kind, service);
+        let render = |pattern: &str, layer: &str| render_path(pattern, &PathVars { layer, ..*vars });
+        if let Some(artifacts) = self.artifacts.get(vars.kind).filter(|a| !a.is_empty()) {
+            return artifacts
+                .iter()
+                .map(|a| ElementFile {
+                    role: a.role.clone(),
+                    path: render(&a.pattern, if a.layer.is_empty() { layer } else { &a.layer }),
+                })
+                .collect();
+        }
+        let source = render(self.pattern_or_default(), layer);
+        let interface = render(self.pattern_or_default(), self.repository_layer(true));
+        if vars.kind != "repository" || interface == source {
+            return vec![ElementFile {
+                role: "source".into(),
+                path: source,
+            }];
+        }
+        vec![
+            ElementFile {
+                role: "implementation".into(),
+                path: source,
+            },
+            ElementFile {
+                role: "trait".into(),
+                path: interface,
+            },
+        ]
+    }
+
+    /// Artifacts must be keyed by a kind, have distinct roles and only use known placeholders.
+    fn validate_artifacts(&self) -> Result<()> {
+        let unknown_placeholder = |pattern: &str| {
+            pattern
+                .split('{')
+                .skip(1)
+                .filter_map(|rest| rest.split_once('}').map(|(p, _)| p.to_string()))
+                .find(|p| !PLACEHOLDERS.contains(&p.as_str()))
+        };
+        if let Some(p) = unknown_placeholder(&self.pattern) {
+            anyhow::bail!("File pattern uses unknown placeholder '{{{p}}}'; use {}", placeholder_list());
+        }
+        for (kind, artifacts) in &self.artifacts {
+            if !ELEMENT_KINDS.contains(&kind.as_str()) {
+                anyhow::bail!("artifacts lists files for unknown kind '{kind}'");
+            }
+            for (i, artifact) in artifacts.iter().enumerate() {
+                if artifact.role.is_empty() || artifact.pattern.is_empty() {
+                    anyhow::bail!("Every {kind} artifact needs a role and a pattern");
+                }
+                if artifacts[..i].iter().any(|a| a.role == artifact.role) {
+                    anyhow::bail!("artifacts lists the {kind} role '{}' twice", artifact.role);
+                }
+                if let Some(p) = unknown_placeholder(&artifact.pattern) {
+                    anyhow::bail!("The {kind} {} pattern uses unknown placeholder '{{{p}}}'; use {}", artifact.role, placeholder_list());
+                }
+                if !artifact.layer.is_empty() && !self.layers.is_empty() && !self.layers.contains(&artifact.layer) {
+                    anyhow::bail!("The {kind} {} artifact is in '{}', which is not one of the layers: {}", artifact.role, artifact.layer, self.layers.join(", "));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Mappings must key a kind the setting applies to and, if `layers` is given, name one of them.
+    fn validate_layer_mapping(&self) -> Result<()> {
+        const SERVICE_KINDS: &[&str] = &["domain", "application", "infrastructure"];
+        let mappable = |kind: &str| ELEMENT_KINDS.contains(&kind) && !matches!(kind, "service" | "repository");
+        if let Some(kind) = self.kind_layers.keys().find(|k| !mappable(k)) {
+            anyhow::bail!("kind_layers maps unknown kind '{kind}'; services and repositories have their own settings");
+        }
+        if let Some(kind) = self.service_layers.keys().find(|k| !SERVICE_KINDS.contains(&k.as_str())) {
+            anyhow::bail!("service_layers maps unknown service kind '{kind}'");
+        }
+        if self.layers.is_empty() {
+            return Ok(());
+        }
+        let repositories = [&self.repository_layers.interface, &self.repository_layers.implementation];
+        let mapped = self.kind_layers.values().chain(self.service_layers.values()).chain(repositories);
+        for layer in mapped.filter(|l| !l.is_empty()) {
+            if !self.layers.contains(layer) {
+                anyhow::bail!("Layer '{layer}' in the layer mapping is not one of the layers: {}", self.layers.join(", "));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(name: &str, root: bool) -> Entity {
+        Entity {
+            name: name.into(),
+            description: "".into(),
+            aggregate_root: root,
+            fields: vec![],
+            methods: vec![],
+            invariants: vec![],
+            state_machine: None,
+            meta: Metadata::default(),
+        }
+    }
+
+    fn billing() -> DomainModel {
+        let mut m = DomainModel::empty("/tmp/shop");
+        m.bounded_contexts.push(BoundedContext {
+            name: "Billing".into(),
+            description: "".into(),
+            module_path: "src/billing".into(),
+            entities: vec![entity("Invoice", true), entity("LineItem", false), entity("Customer", true)],
+            value_objects: vec![],
+            enumerations: vec![],
+            aggregates: vec![Aggregate {
+                name: "Invoicing".into(),
+                description: "".into(),
+                root: "Invoice".into(),
+                entities: vec!["LineItem".into()],
+                value_objects: vec![],
+                meta: Metadata::default(),
+            }],
+            commands: vec![],
+            queries: vec![],
+            use_cases: vec![],
+            policies: vec![],
+            glossary: vec![],
+            services: vec![],
+            repositories: vec![Repository {
+                name: "InvoiceRepository".into(),
+                aggregate: "Invoice".into(),
+                methods: vec![],
+                meta: Metadata::default(),
+            }],
+            events: vec![],
+            dependencies: vec![],
+            meta: Metadata::default(),
+        });
+        m
+    }
+
+    #[test]
+    fn test_aggregate_membership() {
+        let m = billing();
+        let bc = &m.bounded_contexts[0];
+        assert_eq!(bc.aggregate_of("LineItem"), Some(("Invoicing", "Invoice")));
+        assert_eq!(bc.aggregate_of("Invoice"), Some(("Invoicing", "Invoice")));
+        // Implicit single-entity aggregate
+        assert_eq!(bc.aggregate_of("Customer"), Some(("Customer", "Customer")));
+        assert!(bc.is_aggregate_root("invoice"));
+        assert!(!bc.is_aggregate_root("LineItem"));
+        assert!(m.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_repository_for_child_entity() {
+        let mut m = billing();
+        m.bounded_contexts[0].repositories.push(Repository {
+            name: "LineItemRepository".into(),
+            aggregate: "LineItem".into(),
+            methods: vec![],
+            meta: Metadata::default(),
+        });
+        let err = m.validate().unwrap_err().to_string();
+        assert!(err.contains("not an aggregate root"));
+        assert!(err.contains("load it through 'Invoice'"));
+    }
+
+    #[test]
+    fn test_validate_rejects_root_as_member() {
+        let mut m = billing();
+        m.bounded_contexts[0].aggregates[0].entities.push("Customer".into());
+        assert!(m.validate().unwrap_err().to_string().contains("cannot be a member"));
+    }
+
+    #[test]
+    fn test_validate_rejects_command_on_child_entity() {
+        let mut m = billing();
+        m.bounded_contexts[0].commands.push(Operation {
+            name: "RemoveLineItem".into(),
+            description: "".into(),
+            fields: vec![],
+            aggregate: "LineItem".into(),
+            emits: vec![],
+            invariants: vec![],
+            handler: "".into(),
+            returns: "".into(),
+            steps: vec![],
+            meta: Metadata::default(),
+        });
+        let err = m.validate().unwrap_err().to_string();
+        assert!(err.contains("Command 'RemoveLineItem' in 'Billing' targets 'LineItem'"));
+
+        m.bounded_contexts[0].commands[0].aggregate = "Invoice".into();
+        assert!(m.validate().is_ok());
+    }
+
+    fn lifecycle() -> StateMachine {
+        StateMachine {
+            field: "".into(),
+            states: vec!["Draft".into(), "Issued".into(), "Paid".into()],
+            initial: "Draft".into(),
+            transitions: vec![
+                Transition {
+                    from: "Draft".into(),
+                    to: "Issued".into(),
+                    method: "issue".into(),
+                    event: "".into(),
+                },
+                Transition {
+                    from: "Issued".into(),
+                    to: "Paid".into(),
+                    method: "pay".into(),
+                    event: "InvoicePaid".into(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_validate_state_machine_methods() {
+        let mut m = billing();
+        let invoice = &mut m.bounded_contexts[0].entities[0];
+        invoice.methods.push(Method {
+            name: "issue".into(),
+            description: "".into(),
+            parameters: vec![],
+            return_type: "".into(),
+        });
+        invoice.state_machine = Some(lifecycle());
+        let err = m.validate().unwrap_err().to_string();
+        assert!(err.contains("needs method 'pay'"));
+    }
+
+    #[test]
+    fn test_validate_states_against_enumeration() {
+        let mut m = billing();
+        let bc = &mut m.bounded_contexts[0];
+        bc.enumerations.push(Enumeration {
+            name: "InvoiceStatus".into(),
+            description: "".into(),
+            variants: vec![
+                Variant {
+                    name: "Draft".into(),
+                    description: "".into(),
+                },
+                Variant {
+                    name: "Issued".into(),
+                    description: "".into(),
+                },
+            ],
+            meta: Metadata::default(),
+        });
+        let invoice = &mut bc.entities[0];
+        invoice.fields.push(Field {
+            name: "status".into(),
+            field_type: "InvoiceStatus".into(),
+            required: true,
+            description: "".into(),
+        });
+        for method in ["issue", "pay"] {
+            invoice.methods.push(Method {
+                name: method.into(),
+                description: "".into(),
+                parameters: vec![],
+                return_type: "".into(),
+            });
+        }
+        let mut sm = lifecycle();
+        sm.field = "status".into();
+        sm.transitions[1].event.clear();
+        invoice.state_machine = Some(sm);
+        let err = m.validate().unwrap_err().to_string();
+        assert!(err.contains("State 'Paid' of 'Invoice' is not a variant of 'InvoiceStatus'"));
+    }
+
+    #[test]
+    fn test_state_machine_to_mermaid() {
+        assert_eq!(
+            lifecycle().to_mermaid(),
+            "stateDiagram-v2\n    [*] --> Draft\n    Draft --> Issued : issue()\n    Issued --> Paid : pay() / InvoicePaid\n    Paid --> [*]\n"
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_root() {
+        let mut m = billing();
+        m.bounded_contexts[0].aggregates[0].root = "Order".into();
+        assert!(m.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_glossary() {
+        let term = |term: &str, synonyms: &[&str], forbidden: &[&str]| Term {
+            term: term.into(),
+            definition: "".into(),
+            synonyms: synonyms.iter().map(|s| s.to_string()).collect(),
+            forbidden_aliases: forbidden.iter().map(|s| s.to_string()).collect(),
+        };
+        let mut m = billing();
+        m.bounded_contexts[0].glossary = vec![term("Customer", &["Payer"], &["Client", "User"])];
+        assert!(m.validate().is_ok());
+        assert_eq!(m.bounded_contexts[0].find_term("payer").unwrap().term, "Customer");
+
+        m.bounded_contexts[0].glossary.push(term("Payer", &[], &[]));
+        assert!(m.validate().unwrap_err().to_string().contains("defined twice"));
+
+        m.bounded_contexts[0].glossary[1] = term("User", &[], &[]);
+        assert!(m.validate().unwrap_err().to_string().contains("forbids 'User'"));
+    }
+
+    #[test]
+    fn test_validate_external_system_adapter() {
+        let mut m = billing();
+        m.external_systems.push(ExternalSystem {
+            name: "Stripe".into(),
+            description: "".into(),
+            protocol: "REST".into(),
+            integrations: vec![Integration {
+                context: "Billing".into(),
+                adapter: "".into(),
+            }],
+        });
+        assert!(m.validate().is_ok());
+
+        m.external_systems[0].integrations[0].adapter = "StripeGateway".into();
+        assert!(m.validate().unwrap_err().to_string().contains("not a service of 'Billing'"));
+
+        m.external_systems[0].integrations[0] = Integration {
+            context: "Shipping".into(),
+            adapter: "".into(),
+        };
+        assert!(m.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_accepts_unparseable_field_type() {
+        let mut m = billing();
+        m.bounded_contexts[0].entities[0].fields.push(Field {
+            name: "hash".into(),
+            field_type: "[u8; 32]".into(),
+            required: true,
+            description: "".into(),
+        });
+        assert!(m.validate().is_ok());
+    }
+
+    #[test]
+    fn test_metadata_is_flattened_and_filterable() {
+        let json = r#"{"name": "LineItem", "owners": ["team-billing"], "tags": ["legacy"], "status": "deprecated"}"#;
+        let item: Entity = serde_json::from_str(json).unwrap();
+        assert_eq!(item.meta.status, Status::Deprecated);
+        assert!(serde_json::to_string(&entity("Invoice", true)).unwrap().find("status").is_none());
+
+        let mut m = billing();
+        m.bounded_contexts[0].entities[1].meta = item.meta;
+        let filter = MetadataFilter {
+            tag: Some("Legacy".into()),
+            status: None,
+        };
+        let bc = &m.filtered(&filter).bounded_contexts[0];
+        assert_eq!(bc.elements().iter().map(|(_, n, _)| *n).collect::<Vec<_>>(), vec!["LineItem"]);
+        assert_eq!(m.deprecated().iter().map(|(c, k, n, _)| (*c, *k, *n)).collect::<Vec<_>>(), vec![("Billing", "entity", "LineItem")]);
+
+        m.bounded_contexts[0].meta.tags.push("legacy".into());
+        assert_eq!(m.filtered(&filter).bounded_contexts[0].entities.len(), 3);
+        let proposed = MetadataFilter {
+            tag: None,
+            status: Some(Status::Proposed),
+        };
+        assert!(m.filtered(&proposed).bounded_contexts.is_empty());
+    }
+
+    #[test]
+    fn test_naming_conventions_accept_text_or_structure() {
+        let json = r#"{"entities": "PascalCase noun", "events": {"case": "pascal_case", "tense": "past"}}"#;
+        let naming: NamingConventions = serde_json::from_str(json).unwrap();
+        assert_eq!(naming.entities.description, "PascalCase noun");
+        assert!(!naming.entities.is_checked());
+        assert_eq!(naming.for_kind("event").unwrap().tense, Some(Tense::Past));
+        assert_eq!(serde_json::to_value(&naming).unwrap(), serde_json::from_str::<serde_json::Value>(json).unwrap());
+
+        let mut m = billing();
+        m.conventions.naming.services.pattern = "[A-Z](".into();
+        let err = m.validate().unwrap_err().to_string();
+        assert!(err.starts_with("Naming convention for service has an invalid pattern"), "{err}");
+    }
+
+    #[test]
+    fn test_layer_mapping() {
+        let mut m = billing();
+        let files = &mut m.conventions.file_structure;
+        files.layers = vec!["domain".into(), "application".into(), "adapters".into()];
+        files.service_layers.insert("infrastructure".into(), "adapters".into());
+        files.repository_layers.implementation = "adapters".into();
+        assert_eq!(files.service_layer(Some(&ServiceKind::Infrastructure)), "adapters");
+        assert_eq!(files.service_layer(None), "application");
+        assert_eq!(files.kind_layer("repository", None), "adapters");
+        assert_eq!(files.repository_layer(true), "domain");
+        assert_eq!(files.kind_layer("policy", None), "application");
+        assert!(m.validate().is_ok());
+
+        m.conventions.file_structure.kind_layers.insert("event".into(), "infrastructure".into());
+        let err = m.validate().unwrap_err().to_string();
+        assert_eq!(err, "Layer 'infrastructure' in the layer mapping is not one of the layers: domain, application, adapters");
+        m.conventions.file_structure.kind_layers = BTreeMap::from([("service".into(), "domain".into())]);
+        assert!(m.validate().unwrap_err().to_string().contains("unknown kind 'service'"));
+    }
+
+    #[test]
+    fn test_element_kinds_match_elements() {
+        let bc: BoundedContext = serde_json::from_value(serde_json::json!({
+            "name": "All",
+            "entities": [{"name": "A"}],
+            "value_objects": [{"name": "B"}],
+            "enumerations": [{"name": "C"}],
+            "aggregates": [{"name": "D", "root": "A"}],
+            "commands": [{"name": "E"}],
+            "queries": [{"name": "F"}],
+            "use_cases": [{"name": "G"}],
+            "policies": [{"name": "H"}],
+            "services": [{"name": "I"}],
+            "repositories": [{"name": "J", "aggregate": "A"}],
+            "events": [{"name": "K"}]
+        }))
+        .unwrap();
+        let kinds: Vec<&str> = bc.elements().into_iter().map(|(kind, _, _)| kind).collect();
+        assert_eq!(kinds, ELEMENT_KINDS);
+    }
+
+    #[test]
+    fn test_validate_artifacts() {
+        let artifact = |role: &str, pattern: &str| ArtifactPattern {
+            role: role.into(),
+            pattern: pattern.into(),
+            layer: "".into(),
+        };
+        let mut m = billing();
+        m.conventions.file_structure.pattern = "{module_path}/{layer}/{type}.rs".into();
+        m.conventions.file_structure.artifacts.insert(
+            "repository".into(),
+            vec![artifact("implementation", "{module_path}/infrastructure/{type}.rs"), artifact("trait", "{module_path}/domain/{type}.rs")],
+        );
+        assert!(m.validate().is_ok());
+
+        m.conventions.file_structure.artifacts.get_mut("repository").unwrap().push(artifact("trait", "{module_path}/{Name}.rs"));
+        assert_eq!(m.validate().unwrap_err().to_string(), "artifacts lists the repository role 'trait' twice");
+        m.conventions.file_structure.artifacts.get_mut("repository").unwrap()[2] = artifact("test", "tests/{typ}.rs");
+        assert!(m.validate().unwrap_err().to_string().starts_with("The repository test pattern uses unknown placeholder '{typ}'"));
+    }
 }
@@ -0,0 +1,194 @@
+use anyhow::Result;
+use regex_lite::Regex;
+
+use super::model::*;
+use super::{to_pascal, to_snake};
+
+/// Verbs whose past tense does not end in "ed".
+const IRREGULAR_PAST: &[&str] = &[
+    "begun", "bought", "brought", "built", "chosen", "done", "drawn", "given", "held", "kept", "lost", "made",
+    "met", "paid", "put", "read", "sent", "set", "sold", "spent", "taken", "won", "written",
+];
+
+/// A convention's `pattern` anchored to match whole names; `None` if there is none.
+pub fn compile(pattern: &str) -> Result<Option<Regex>> {
+    if pattern.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Regex::new(&format!("^(?:{pattern})$"))?))
+}
+
+/// How `name` breaks `rule`, one phrase per broken part, e.g. "does not end with
+/// 'Repository'"; empty if the name conforms.
+pub fn problems(rule: &NamingRule, name: &str) -> Vec<String> {
+    let mut problems = Vec::new();
+    if let Some(case) = rule.case.filter(|c| !is_case(name, *c)) {
+        problems.push(format!("is not in {}", case_name(case)));
+    }
+    if !name.starts_with(rule.prefix.as_str()) {
+        problems.push(format!("does not start with '{}'", rule.prefix));
+    }
+    if !name.ends_with(rule.suffix.as_str()) {
+        problems.push(format!("does not end with '{}'", rule.suffix));
+    }
+    if let Ok(Some(re)) = compile(&rule.pattern) {
+        if !re.is_match(name) {
+            problems.push(format!("does not match the pattern '{}'", rule.pattern));
+        }
+    }
+    match rule.tense {
+        Some(Tense::Past) if !is_past_tense(name) => {
+            problems.push("is not in the past tense, e.g. 'InvoicePaid'".into());
+        }
+        Some(Tense::Imperative) if !is_imperative(name) => {
+            problems.push("does not start with a verb, e.g. 'PayInvoice'".into());
+        }
+        _ => {}
+    }
+    problems
+}
+
+/// `name` reshaped to follow `rule` as far as that can be done mechanically — case,
+/// prefix and suffix. `None` if nothing would change; tense and pattern are left to
+/// the author.
+pub fn suggest(rule: &NamingRule, name: &str) -> Option<String> {
+    let mut suggestion = match rule.case {
+        Some(case) => recase(name, case),
+        None => name.to_string(),
+    };
+    if !suggestion.starts_with(rule.prefix.as_str()) {
+        suggestion.insert_str(0, &rule.prefix);
+    }
+    if !suggestion.ends_with(rule.suffix.as_str()) {
+        suggestion.push_str(&rule.suffix);
+    }
+    (suggestion != name).then_some(suggestion)
+}
+
+pub fn is_case(name: &str, case: CaseStyle) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    let rest = chars.as_str();
+    match case {
+        CaseStyle::Pascal => first.is_ascii_uppercase() && rest.chars().all(|c| c.is_ascii_alphanumeric()),
+        CaseStyle::Camel => first.is_ascii_lowercase() && rest.chars().all(|c| c.is_ascii_alphanumeric()),
+        CaseStyle::Snake => {
+            first.is_ascii_lowercase() && rest.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        }
+        CaseStyle::ScreamingSnake => {
+            first.is_ascii_uppercase() && rest.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        }
+        CaseStyle::Kebab => {
+            first.is_ascii_lowercase() && rest.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        }
+    }
+}
+
+/// Whether the last word of `name` is a past participle: `InvoicePaid`, `user_registered`.
+pub fn is_past_tense(name: &str) -> bool {
+    words(name).last().is_some_and(|last| last.ends_with("ed") || IRREGULAR_PAST.contains(&last.as_str()))
+}
+
+/// Whether `name` reads as an instruction. Lexical only: the first word must not be a
+/// past participle or end in "ing", so `PayInvoice` passes and `InvoicePaid` and
+/// `PayingInvoice` do not. A noun such as `Invoice` is not caught.
+fn is_imperative(name: &str) -> bool {
+    words(name).first().is_some_and(|first| {
+        !first.ends_with("ed") && !first.ends_with("ing") && !IRREGULAR_PAST.contains(&first.as_str())
+    })
+}
+
+fn case_name(case: CaseStyle) -> &'static str {
+    match case {
+        CaseStyle::Pascal => "PascalCase",
+        CaseStyle::Camel => "camelCase",
+        CaseStyle::Snake => "snake_case",
+        CaseStyle::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+        CaseStyle::Kebab => "kebab-case",
+    }
+}
+
+fn recase(name: &str, case: CaseStyle) -> String {
+    let words = words(name);
+    match case {
+        CaseStyle::Pascal => to_pascal(&words.join("_")),
+        CaseStyle::Camel => {
+            let pascal = to_pascal(&words.join("_"));
+            let mut chars = pascal.chars();
+            chars.next().map(|c| c.to_ascii_lowercase().to_string() + chars.as_str()).unwrap_or_default()
+        }
+        CaseStyle::Snake => words.join("_"),
+        CaseStyle::ScreamingSnake => words.join("_").to_ascii_uppercase(),
+        CaseStyle::Kebab => words.join("-"),
+    }
+}
+
+/// The lowercase words of a name in any casing style, `INVOICE_LINE` included.
+fn words(name: &str) -> Vec<String> {
+    name.split(['_', '-', ' '])
+        .filter(|segment| !segment.is_empty())
+        .flat_map(|segment| {
+            if segment.chars().all(|c| !c.is_ascii_lowercase()) {
+                vec![segment.to_ascii_lowercase()]
+            } else {
+                to_snake(segment).split('_').map(String::from).collect()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repositories() -> NamingRule {
+        NamingRule {
+            case: Some(CaseStyle::Pascal),
+            prefix: "".into(),
+            suffix: "Repository".into(),
+            pattern: "".into(),
+            tense: None,
+            description: "".into(),
+        }
+    }
+
+    #[test]
+    fn test_problems() {
+        assert!(problems(&repositories(), "UserRepository").is_empty());
+        assert_eq!(
+            problems(&repositories(), "user_repo"),
+            vec!["is not in PascalCase", "does not end with 'Repository'"]
+        );
+
+        let events = NamingRule {
+            case: None,
+            prefix: "".into(),
+            suffix: "".into(),
+            pattern: "[A-Z][a-z]+[A-Z][A-Za-z]+".into(),
+            tense: Some(Tense::Past),
+            description: "".into(),
+        };
+        assert!(problems(&events, "InvoicePaid").is_empty());
+        assert_eq!(problems(&events, "UserRegistered"), Vec::<String>::new());
+        assert_eq!(
+            problems(&events, "Refund"),
+            vec!["does not match the pattern '[A-Z][a-z]+[A-Z][A-Za-z]+'", "is not in the past tense, e.g. 'InvoicePaid'"]
+        );
+        assert!(problems(&NamingRule::from("PascalCase noun"), "whatever").is_empty());
+    }
+
+    #[test]
+    fn test_suggest_and_cases() {
+        assert_eq!(suggest(&repositories(), "user_repo").as_deref(), Some("UserRepoRepository"));
+        assert_eq!(suggest(&repositories(), "UserRepository"), None);
+        assert_eq!(recase("InvoiceLine", CaseStyle::Camel), "invoiceLine");
+        assert_eq!(recase("invoice-line", CaseStyle::ScreamingSnake), "INVOICE_LINE");
+        assert_eq!(recase("INVOICE_LINE", CaseStyle::Pascal), "InvoiceLine");
+        assert!(is_case("INVOICE_LINE", CaseStyle::ScreamingSnake));
+        assert!(!is_case("Invoice_Line", CaseStyle::Pascal));
+        assert!(is_imperative("PayInvoice"));
+        assert!(!is_imperative("PaidInvoice"));
+    }
+}
@@ -1,5 +1,74 @@
+use serde::Serialize;
+
 use super::model::*;
 
+/// A place in the model that refers to an element by name.
+#[derive(Debug, Clone, Serialize)]
+pub struct Usage {
+    pub context: String,
+    /// The referring element
+    pub element: String,
+    pub kind: &'static str,
+    /// How it refers to the target, e.g. "field 'owner'" or "emits"
+    pub via: String,
+}
+
+/// Why a name did not resolve to exactly one element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LookupError {
+    NotFound,
+    /// The name exists in several contexts; the qualified candidates, e.g. `Billing::Account`
+    Ambiguous(Vec<String>),
+}
+
+impl std::fmt::Display for LookupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LookupError::NotFound => write!(f, "not found"),
+            LookupError::Ambiguous(candidates) => {
+                let name = candidates[0].split_once("::").map_or(candidates[0].as_str(), |(_, n)| n);
+                write!(
+                    f,
+                    "'{name}' is ambiguous: it is defined in several contexts. Use a qualified name: {}",
+                    candidates.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LookupError {}
+
+/// Split `Billing::Account` into its context and element name; an unqualified name has no context.
+pub fn split_qualified(name: &str) -> (Option<&str>, &str) {
+    match name.split_once("::") {
+        Some((context, name)) => (Some(context.trim()), name.trim()),
+        None => (None, name),
+    }
+}
+
+/// Which elements `DomainRegistry::list_elements` returns; the default selects all of them.
+#[derive(Debug, Clone, Default)]
+pub struct ElementQuery {
+    /// An element kind as listed by `BoundedContext::elements`, e.g. `value_object`
+    pub kind: Option<String>,
+    pub context: Option<String>,
+    pub aggregate_roots_only: bool,
+    pub meta: MetadataFilter,
+}
+
+/// One line of an element listing.
+#[derive(Debug, Clone, Serialize)]
+pub struct ElementSummary<'a> {
+    pub context: &'a str,
+    pub kind: &'static str,
+    pub name: &'a str,
+    pub status: Status,
+    /// Only set for entities
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_root: Option<bool>,
+}
+
 /// Provides query access into the domain model for MCP tool handlers.
 pub struct DomainRegistry<'a> {
     model: &'a DomainModel,
@@ -17,22 +86,246 @@ impl<'a> DomainRegistry<'a> {
             .find(|bc| bc.name.eq_ignore_ascii_case(name))
     }
 
-    pub fn find_entity(&self, name: &str) -> Option<(&BoundedContext, &Entity)> {
-        for bc in &self.model.bounded_contexts {
-            if let Some(entity) = bc.entities.iter().find(|e| e.name.eq_ignore_ascii_case(name)) {
-                return Some((bc, entity));
+    /// Resolve an element name, optionally qualified with its context (`Billing::Account`),
+    /// among the elements of the given kinds; an empty `kinds` accepts every kind. An
+    /// unqualified name defined in several contexts is ambiguous. Within one context the
+    /// first kind listed by `BoundedContext::elements` wins, so an entity shadows the
+    /// aggregate it roots.
+    pub fn resolve(&self, name: &str, kinds: &[&str]) -> Result<(&'a BoundedContext, &'static str, &'a str), LookupError> {
+        let (context, name) = split_qualified(name);
+        let mut matches = self
+            .model
+            .bounded_contexts
+            .iter()
+            .filter(|bc| context.is_none_or(|c| bc.name.eq_ignore_ascii_case(c)))
+            .filter_map(|bc| {
+                bc.elements()
+                    .into_iter()
+                    .find(|(kind, n, _)| (kinds.is_empty() || kinds.contains(kind)) && n.eq_ignore_ascii_case(name))
+                    .map(|(kind, n, _)| (bc, kind, n))
+            });
+        let first = matches.next().ok_or(LookupError::NotFound)?;
+        let others: Vec<_> = matches.collect();
+        if others.is_empty() {
+            return Ok(first);
+        }
+        let candidates = std::iter::once(first)
+            .chain(others)
+            .map(|(bc, _, n)| format!("{}::{}", bc.name, n))
+            .collect();
+        Err(LookupError::Ambiguous(candidates))
+    }
+
+    pub fn find_entity(&self, name: &str) -> Result<(&BoundedContext, &Entity), LookupError> {
+        let (bc, _, name) = self.resolve(name, &["entity"])?;
+        bc.entities.iter().find(|e| e.name == name).map(|e| (bc, e)).ok_or(LookupError::NotFound)
+    }
+
+    pub fn find_service(&self, name: &str) -> Result<(&BoundedContext, &Service), LookupError> {
+        let (bc, _, name) = self.resolve(name, &["service"])?;
+        bc.services.iter().find(|s| s.name == name).map(|s| (bc, s)).ok_or(LookupError::NotFound)
+    }
+
+    pub fn find_value_object(&self, name: &str) -> Result<(&BoundedContext, &ValueObject), LookupError> {
+        let (bc, _, name) = self.resolve(name, &["value_object"])?;
+        bc.value_objects.iter().find(|v| v.name == name).map(|v| (bc, v)).ok_or(LookupError::NotFound)
+    }
+
+    pub fn find_repository(&self, name: &str) -> Result<(&BoundedContext, &Repository), LookupError> {
+        let (bc, _, name) = self.resolve(name, &["repository"])?;
+        bc.repositories.iter().find(|r| r.name == name).map(|r| (bc, r)).ok_or(LookupError::NotFound)
+    }
+
+    pub fn find_event(&self, name: &str) -> Result<(&BoundedContext, &DomainEvent), LookupError> {
+        let (bc, _, name) = self.resolve(name, &["event"])?;
+        bc.events.iter().find(|e| e.name == name).map(|e| (bc, e)).ok_or(LookupError::NotFound)
+    }
+
+    /// Elements matching `query`, in declaration order.
+    pub fn list_elements(&self, query: &ElementQuery) -> Vec<ElementSummary<'_>> {
+        let wanted = |filter: &Option<String>, value: &str| filter.as_ref().is_none_or(|f| f.eq_ignore_ascii_case(value));
+        let mut found = Vec::new();
+        for bc in self.model.bounded_contexts.iter().filter(|bc| wanted(&query.context, &bc.name)) {
+            for (kind, name, meta) in bc.elements() {
+                if !wanted(&query.kind, kind) || !query.meta.matches(meta) {
+                    continue;
+                }
+                let aggregate_root = (kind == "entity")
+                    .then(|| bc.entities.iter().any(|e| e.name == name && e.aggregate_root));
+                if query.aggregate_roots_only && aggregate_root != Some(true) {
+                    continue;
+                }
+                found.push(ElementSummary {
+                    context: &bc.name,
+                    kind,
+                    name,
+                    status: meta.status,
+                    aggregate_root,
+                });
             }
         }
-        None
+        found
+    }
+
+    /// Find an enumeration, preferring `context` over the other contexts.
+    pub fn find_enumeration(&self, name: &str, context: &str) -> Option<(&BoundedContext, &Enumeration)> {
+        let local = self
+            .model
+            .bounded_contexts
+            .iter()
+            .filter(|bc| bc.name.eq_ignore_ascii_case(context));
+        local
+            .chain(self.model.bounded_contexts.iter())
+            .find_map(|bc| bc.find_enumeration(name).map(|e| (bc, e)))
     }
 
-    pub fn find_service(&self, name: &str) -> Option<(&BoundedContext, &Service)> {
+    /// Enumerations referenced by the given field types, each listed once.
+    pub fn referenced_enumerations<'f>(
+        &self,
+        context: &str,
+        fields: impl IntoIterator<Item = &'f Field>,
+    ) -> Vec<(&BoundedContext, &Enumeration)> {
+        let mut found: Vec<(&BoundedContext, &Enumeration)> = Vec::new();
+        for field in fields {
+            for name in type_names(&field.field_type) {
+                if let Some((bc, e)) = self.find_enumeration(name, context) {
+                    if !found.iter().any(|(_, f)| std::ptr::eq(*f, e)) {
+                        found.push((bc, e));
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    pub fn find_operation(&self, name: &str) -> Result<(&BoundedContext, OperationKind, &Operation), LookupError> {
+        let (bc, _, name) = self.resolve(name, &["command", "query", "use_case"])?;
+        bc.find_operation(name).map(|(kind, op)| (bc, kind, op)).ok_or(LookupError::NotFound)
+    }
+
+    /// Every element that refers to `context`'s `name`: through field, parameter or
+    /// return types, aggregate membership, handlers, emitted events, steps,
+    /// subscriptions, service dependencies, repositories, event sources and external
+    /// system adapters. References resolve like `TypeRef::references`: in the
+    /// referring context first, then in the others, so a homonym in another context
+    /// is not a usage.
+    pub fn usages(&self, context: &str, name: &str) -> Vec<Usage> {
+        let scope = Scope::new(self.model);
+        // Set per referring context below
+        let from = std::cell::Cell::new("");
+        let is = |s: &str| scope.refers_to(from.get(), s, context, name);
+        let mentions = |ty: &str| type_names(ty).any(is);
+        let mut usages = Vec::new();
+        let mut push = |context: &str, element: &str, kind: &'static str, via: String| {
+            usages.push(Usage {
+                context: context.to_string(),
+                element: element.to_string(),
+                kind,
+                via,
+            });
+        };
+        let method_via = |m: &Method| {
+            (m.parameters.iter().any(|p| mentions(&p.field_type)) || mentions(&m.return_type))
+                .then(|| format!("method '{}'", m.name))
+        };
+
         for bc in &self.model.bounded_contexts {
-            if let Some(svc) = bc.services.iter().find(|s| s.name.eq_ignore_ascii_case(name)) {
-                return Some((bc, svc));
+            let ctx = bc.name.as_str();
+            from.set(ctx);
+            let typed = bc
+                .entities
+                .iter()
+                .map(|e| ("entity", &e.name, &e.fields))
+                .chain(bc.value_objects.iter().map(|v| ("value_object", &v.name, &v.fields)))
+                .chain(bc.events.iter().map(|e| ("event", &e.name, &e.fields)));
+            for (kind, owner, fields) in typed {
+                for field in fields.iter().filter(|f| mentions(&f.field_type)) {
+                    push(ctx, owner, kind, format!("field '{}'", field.name));
+                }
+            }
+            for entity in &bc.entities {
+                for via in entity.methods.iter().filter_map(method_via) {
+                    push(ctx, &entity.name, "entity", via);
+                }
+                let transitions = entity.state_machine.iter().flat_map(|sm| &sm.transitions);
+                if transitions.clone().any(|t| is(&t.event)) {
+                    push(ctx, &entity.name, "entity", "lifecycle transition".into());
+                }
+            }
+            for agg in &bc.aggregates {
+                if is(&agg.root) {
+                    push(ctx, &agg.name, "aggregate", "root".into());
+                }
+                if agg.entities.iter().chain(&agg.value_objects).any(|m| is(m)) {
+                    push(ctx, &agg.name, "aggregate", "member".into());
+                }
+            }
+            for kind in OperationKind::ALL {
+                let label = kind.element_kind();
+                for op in bc.operations(kind) {
+                    for field in op.fields.iter().filter(|f| mentions(&f.field_type)) {
+                        push(ctx, &op.name, label, format!("field '{}'", field.name));
+                    }
+                    let refs = [
+                        (is(&op.aggregate), "aggregate"),
+                        (is(&op.handler), "handler"),
+                        (mentions(&op.returns), "returns"),
+                        (op.emits.iter().any(|e| is(e)), "emits"),
+                        (op.steps.iter().any(|s| is(s)), "step"),
+                    ];
+                    for (_, via) in refs.into_iter().filter(|(hit, _)| *hit) {
+                        push(ctx, &op.name, label, via.into());
+                    }
+                }
+            }
+            for policy in &bc.policies {
+                if policy.reacts_to.iter().any(|e| is(e)) {
+                    push(ctx, &policy.name, "policy", "reacts to".into());
+                }
+                if policy.issues.iter().any(|c| is(c)) {
+                    push(ctx, &policy.name, "policy", "issues".into());
+                }
+            }
+            for svc in &bc.services {
+                if svc.dependencies.iter().any(|d| is(d)) {
+                    push(ctx, &svc.name, "service", "dependency".into());
+                }
+                for via in svc.methods.iter().filter_map(method_via) {
+                    push(ctx, &svc.name, "service", via);
+                }
+            }
+            for repo in &bc.repositories {
+                if is(&repo.aggregate) {
+                    push(ctx, &repo.name, "repository", "aggregate".into());
+                }
+                for via in repo.methods.iter().filter_map(method_via) {
+                    push(ctx, &repo.name, "repository", via);
+                }
+            }
+            for event in bc.events.iter().filter(|e| is(&e.source)) {
+                push(ctx, &event.name, "event", "source".into());
             }
         }
-        None
+        for system in &self.model.external_systems {
+            for integration in &system.integrations {
+                from.set(&integration.context);
+                if is(&integration.adapter) {
+                    push(&integration.context, &system.name, "external_system", "adapter".into());
+                }
+            }
+        }
+        usages
+    }
+
+    /// Names of every element of the given kinds (as listed by `BoundedContext::elements`).
+    pub fn element_names(&self, kinds: &[&str]) -> Vec<&str> {
+        self.model
+            .bounded_contexts
+            .iter()
+            .flat_map(|bc| bc.elements())
+            .filter(|(kind, _, _)| kinds.contains(kind))
+            .map(|(_, name, _)| name)
+            .collect()
     }
 
     pub fn context_names(&self) -> Vec<&str> {
@@ -46,39 +339,87 @@ impl<'a> DomainRegistry<'a> {
     /// Produce a structured JSON summary for Copilot context injection.
     /// Compact and machine-readable — no prose, just data.
     pub fn architecture_summary(&self) -> String {
+        self.render_overview(&vec![Detail::Full; self.model.bounded_contexts.len()]).to_string()
+    }
+
+    /// The summary at the requested detail, fitted to a token budget.
+    ///
+    /// The focus context and its direct dependencies start at full detail, every
+    /// other context at `options.detail`. While the estimate exceeds `max_tokens`,
+    /// the other contexts are lowered one level at a time, last declared first; the
+    /// focus and its dependencies keep full detail. Rules, conventions and deprecations
+    /// are always kept too, so when the budget still cannot be met the summary says so
+    /// in `over_budget`.
+    pub fn overview(&self, options: &OverviewOptions) -> anyhow::Result<String> {
+        let contexts = &self.model.bounded_contexts;
+        let focus = match options.focus.as_deref().filter(|f| !f.is_empty()) {
+            Some(name) => Some(self.find_context(name).ok_or_else(|| {
+                anyhow::anyhow!("Bounded context '{name}' not found. Available: {}", self.context_names().join(", "))
+            })?),
+            None => None,
+        };
+        let relevance = |bc: &BoundedContext| match focus {
+            Some(f) if f.name == bc.name => 2,
+            Some(f) if f.dependencies.iter().any(|d| d.eq_ignore_ascii_case(&bc.name)) => 1,
+            _ => 0,
+        };
+
+        let mut levels: Vec<Detail> = contexts
+            .iter()
+            .map(|bc| if relevance(bc) > 0 { Detail::Full } else { options.detail })
+            .collect();
+        let mut rendered = self.render_overview(&levels).to_string();
+        let Some(budget) = options.max_tokens else {
+            return Ok(rendered);
+        };
+
+        let order: Vec<usize> = (0..contexts.len()).rev().filter(|&i| relevance(&contexts[i]) == 0).collect();
+        for floor in [Detail::Names, Detail::Contexts] {
+            for &i in &order {
+                if estimate_tokens(&rendered) <= budget {
+                    return Ok(rendered);
+                }
+                if levels[i] > floor {
+                    levels[i] = floor;
+                    rendered = self.render_overview(&levels).to_string();
+                }
+            }
+        }
+        let estimated = estimate_tokens(&rendered);
+        if estimated <= budget {
+            return Ok(rendered);
+        }
+        let mut overview = self.render_overview(&levels);
+        overview["over_budget"] = serde_json::json!({
+            "max_tokens": budget,
+            "estimated_tokens": estimated,
+            "reason": match focus {
+                Some(f) => format!("'{}' and its dependencies are kept in full; every other context is already at the lowest detail", f.name),
+                None => "every context is already at the lowest detail".to_string(),
+            },
+        });
+        Ok(overview.to_string())
+    }
+
+    fn render_overview(&self, levels: &[Detail]) -> serde_json::Value {
         use serde_json::json;
 
-        let contexts: Vec<_> = self.model.bounded_contexts.iter().map(|bc| {
+        let contexts: Vec<_> = self.model.bounded_contexts.iter().zip(levels).map(|(bc, &level)| {
+            context_summary(bc, level)
+        }).collect();
+
+        let external_systems: Vec<_> = self.model.external_systems.iter().map(|s| {
             json!({
-                "name": bc.name,
-                "module": bc.module_path,
-                "entities": bc.entities.iter().map(|e| {
-                    json!({
-                        "name": e.name,
-                        "aggregate_root": e.aggregate_root,
-                        "fields": e.fields.iter().map(|f| {
-                            format!("{}: {}{}", f.name, f.field_type, if f.required { " (required)" } else { "" })
-                        }).collect::<Vec<_>>(),
-                        "methods": e.methods.iter().map(|m| {
-                            format!("{}({}) -> {}", m.name,
-                                m.parameters.iter().map(|p| format!("{}: {}", p.name, p.field_type)).collect::<Vec<_>>().join(", "),
-                                m.return_type)
-                        }).collect::<Vec<_>>(),
-                        "invariants": e.invariants,
-                    })
-                }).collect::<Vec<_>>(),
-                "value_objects": bc.value_objects.iter().map(|v| &v.name).collect::<Vec<_>>(),
-                "services": bc.services.iter().map(|s| {
-                    json!({ "name": s.name, "kind": format!("{:?}", s.kind) })
-                }).collect::<Vec<_>>(),
-                "events": bc.events.iter().map(|e| &e.name).collect::<Vec<_>>(),
-                "repositories": bc.repositories.iter().map(|r| {
-                    json!({ "name": r.name, "aggregate": r.aggregate })
-                }).collect::<Vec<_>>(),
-                "depends_on": bc.dependencies,
+                "name": s.name,
+                "protocol": s.protocol,
+                "integrations": s.integrations,
             })
         }).collect();
 
+        let deprecated: Vec<_> = self.model.deprecated().into_iter().map(|(ctx, kind, name, meta)| {
+            json!({ "bounded_context": ctx, "kind": kind, "name": name, "replaced_by": meta.replaced_by })
+        }).collect();
+
         let rules: Vec<_> = self.model.rules.iter().map(|r| {
             json!({ "id": r.id, "severity": format!("{:?}", r.severity), "rule": r.description })
         }).collect();
@@ -92,6 +433,8 @@ impl<'a> DomainRegistry<'a> {
                 "messaging": self.model.tech_stack.messaging,
             },
             "bounded_contexts": contexts,
+            "external_systems": external_systems,
+            "deprecated": deprecated,
             "rules": rules,
             "conventions": {
                 "file_pattern": self.model.conventions.file_structure.pattern,
@@ -108,6 +451,179 @@ impl<'a> DomainRegistry<'a> {
             }
         });
 
-        serde_json::to_string(&overview).unwrap()
+        overview
+    }
+}
+
+/// How much of each bounded context the overview shows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Detail {
+    /// Name, module, owners, tags, dependencies and an element count
+    Contexts,
+    /// Plus the names of its elements
+    Names,
+    /// Plus fields, methods, invariants, aggregate members and policy wiring
+    #[default]
+    Full,
+}
+
+impl Detail {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Detail::Contexts => "contexts",
+            Detail::Names => "names",
+            Detail::Full => "full",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "contexts" => Some(Detail::Contexts),
+            "names" => Some(Detail::Names),
+            "full" => Some(Detail::Full),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OverviewOptions {
+    pub detail: Detail,
+    pub max_tokens: Option<usize>,
+    /// Context kept at full detail together with its direct dependencies
+    pub focus: Option<String>,
+}
+
+/// Element names per context, for resolving references by name.
+struct Scope<'m> {
+    contexts: Vec<(&'m str, Vec<(&'static str, &'m str)>)>,
+}
+
+impl<'m> Scope<'m> {
+    fn new(model: &'m DomainModel) -> Self {
+        let contexts = model
+            .bounded_contexts
+            .iter()
+            .map(|bc| (bc.name.as_str(), bc.elements().into_iter().map(|(kind, n, _)| (kind, n)).collect()))
+            .collect();
+        Scope { contexts }
     }
+
+    /// Whether `reference`, written in context `from`, names `context`'s `name`.
+    /// `Billing::Account` names its context; otherwise `from` is searched first, then
+    /// the other contexts in declaration order. `{Entity}Id` resolves like its entity.
+    fn refers_to(&self, from: &str, reference: &str, context: &str, name: &str) -> bool {
+        let (qualifier, reference) = split_qualified(reference);
+        if !reference.eq_ignore_ascii_case(name) {
+            return false;
+        }
+        if let Some(qualifier) = qualifier {
+            return qualifier.eq_ignore_ascii_case(context);
+        }
+        self.resolve(from, reference).is_some_and(|found| found.eq_ignore_ascii_case(context))
+    }
+
+    fn resolve(&self, from: &str, reference: &str) -> Option<&'m str> {
+        let in_order = || {
+            let local = self.contexts.iter().filter(|(c, _)| c.eq_ignore_ascii_case(from));
+            local.chain(self.contexts.iter())
+        };
+        let defines = |names: &[(&str, &str)], wanted: &str, kind: Option<&str>| {
+            names.iter().any(|(k, n)| n.eq_ignore_ascii_case(wanted) && kind.is_none_or(|kind| *k == kind))
+        };
+        in_order()
+            .find(|(_, names)| defines(names, reference, None))
+            .or_else(|| {
+                let stem = reference.strip_suffix("Id").filter(|s| !s.is_empty())?;
+                in_order().find(|(_, names)| defines(names, stem, Some("entity")))
+            })
+            .map(|(c, _)| *c)
+    }
+}
+
+/// Rough token count of a JSON text: four characters per token.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.len().div_ceil(4)
+}
+
+fn context_summary(bc: &BoundedContext, level: Detail) -> serde_json::Value {
+    use serde_json::json;
+
+    if level == Detail::Contexts {
+        return json!({
+            "name": bc.name,
+            "module": bc.module_path,
+            "owners": bc.meta.owners,
+            "tags": bc.meta.tags,
+            "depends_on": bc.dependencies,
+            "elements": bc.elements().len(),
+            "detail": level.as_str(),
+        });
+    }
+    if level == Detail::Names {
+        let names = |kind: &str| {
+            bc.elements().into_iter().filter(|(k, _, _)| *k == kind).map(|(_, n, _)| n).collect::<Vec<_>>()
+        };
+        return json!({
+            "name": bc.name,
+            "module": bc.module_path,
+            "owners": bc.meta.owners,
+            "tags": bc.meta.tags,
+            "entities": names("entity"),
+            "value_objects": names("value_object"),
+            "enumerations": names("enumeration"),
+            "aggregates": names("aggregate"),
+            "services": names("service"),
+            "events": names("event"),
+            "commands": names("command"),
+            "queries": names("query"),
+            "use_cases": names("use_case"),
+            "policies": names("policy"),
+            "repositories": names("repository"),
+            "depends_on": bc.dependencies,
+            "detail": level.as_str(),
+        });
+    }
+    json!({
+        "name": bc.name,
+        "module": bc.module_path,
+        "owners": bc.meta.owners,
+        "tags": bc.meta.tags,
+        "entities": bc.entities.iter().map(|e| {
+            json!({
+                "name": e.name,
+                "aggregate_root": e.aggregate_root,
+                "fields": e.fields.iter().map(|f| {
+                    format!("{}: {}{}", f.name, f.field_type, if f.required { " (required)" } else { "" })
+                }).collect::<Vec<_>>(),
+                "methods": e.methods.iter().map(|m| {
+                    format!("{}({}) -> {}", m.name,
+                        m.parameters.iter().map(|p| format!("{}: {}", p.name, p.field_type)).collect::<Vec<_>>().join(", "),
+                        m.return_type)
+                }).collect::<Vec<_>>(),
+                "invariants": e.invariants,
+            })
+        }).collect::<Vec<_>>(),
+        "value_objects": bc.value_objects.iter().map(|v| &v.name).collect::<Vec<_>>(),
+        "enumerations": bc.enumerations.iter().map(|e| {
+            json!({ "name": e.name, "variants": e.variants.iter().map(|v| &v.name).collect::<Vec<_>>() })
+        }).collect::<Vec<_>>(),
+        "aggregates": bc.aggregates.iter().map(|a| {
+            json!({ "name": a.name, "root": a.root, "entities": a.entities, "value_objects": a.value_objects })
+        }).collect::<Vec<_>>(),
+        "services": bc.services.iter().map(|s| {
+            json!({ "name": s.name, "kind": format!("{:?}", s.kind) })
+        }).collect::<Vec<_>>(),
+        "events": bc.events.iter().map(|e| &e.name).collect::<Vec<_>>(),
+        "commands": bc.commands.iter().map(|c| &c.name).collect::<Vec<_>>(),
+        "queries": bc.queries.iter().map(|q| &q.name).collect::<Vec<_>>(),
+        "use_cases": bc.use_cases.iter().map(|u| &u.name).collect::<Vec<_>>(),
+        "policies": bc.policies.iter().map(|p| {
+            json!({ "name": p.name, "reacts_to": p.reacts_to, "issues": p.issues })
+        }).collect::<Vec<_>>(),
+        "repositories": bc.repositories.iter().map(|r| {
+            json!({ "name": r.name, "aggregate": r.aggregate })
+        }).collect::<Vec<_>>(),
+        "depends_on": bc.dependencies,
+    })
 }
@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::model::*;
+use super::registry::DomainRegistry;
+use super::search;
+
+/// The slice of the model that matters for a task.
+#[derive(Debug, Clone, Serialize)]
+pub struct Relevance {
+    pub task: String,
+    /// Contexts holding relevant elements, most relevant first
+    pub contexts: Vec<RelevantContext>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub external_systems: Vec<String>,
+    /// Rules mentioning the task, scoped to a relevant context, element or layer, or to `all`
+    pub rules: Vec<ArchitecturalRule>,
+    /// File layout, error handling, testing and the naming conventions of the relevant kinds
+    pub conventions: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RelevantContext {
+    pub name: String,
+    pub score: f64,
+    pub depends_on: Vec<String>,
+    pub elements: Vec<RelevantElement>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RelevantElement {
+    pub kind: &'static str,
+    pub name: String,
+    pub score: f64,
+    /// Why it was picked, e.g. "matches name, field 'amount'" or "uses 'Payment' via source"
+    pub reason: String,
+}
+
+/// Words that describe the work rather than the domain.
+const TASK_WORDS: &[&str] = &[
+    "add", "allow", "api", "build", "change", "code", "endpoint", "feature", "fix", "handle", "implement", "make",
+    "need", "new", "should", "support", "want", "we", "when",
+];
+
+/// Share of a matched element's score passed to the elements it references or is referenced by.
+const NEIGHBOUR: f64 = 0.5;
+
+/// Elements scoring below this share of the best one are noise, e.g. a typo match in a description.
+const CUTOFF: f64 = 0.25;
+
+/// Rank the model against a free-form task description, locally and deterministically.
+///
+/// Elements are scored lexically on every task word that matches their name, members
+/// or prose; a matching glossary term counts for the element of the same name. The
+/// best matches then pass half their score one hop along references in both
+/// directions — the events an entity sources, the aggregate a command targets — so
+/// "refund a payment" also surfaces `PaymentRefunded`. The top `limit` elements
+/// scoring at least a quarter of the best are kept and grouped by context.
+pub fn relevant(model: &DomainModel, task: &str, limit: usize) -> Relevance {
+    let terms: Vec<String> = search::words(task).into_iter().filter(|w| !TASK_WORDS.contains(&w.as_str())).collect();
+    let hits = search::rank_any(model, &terms);
+
+    let mut elements: HashMap<(String, String), RelevantElement> = HashMap::new();
+    let mut context_scores: HashMap<String, f64> = HashMap::new();
+    let mut external_systems = Vec::new();
+    let mut matched_rules = Vec::new();
+    for hit in hits {
+        let reason = format!("matches {}", hit.matched.join(", "));
+        match hit.kind {
+            "bounded_context" => *context_scores.entry(hit.name).or_default() += hit.score,
+            "external_system" => external_systems.push(hit.name),
+            "rule" => matched_rules.push(hit.name),
+            "term" => {
+                let Some(bc) = model.bounded_contexts.iter().find(|bc| bc.name == hit.context) else {
+                    continue;
+                };
+                match bc.elements().into_iter().find(|(_, n, _)| n.eq_ignore_ascii_case(&hit.name)) {
+                    Some((kind, name, _)) => {
+                        let reason = format!("glossary term '{}' {reason}", hit.name);
+                        offer(&mut elements, &bc.name, kind, name, hit.score, reason)
+                    }
+                    None => *context_scores.entry(hit.context).or_default() += hit.score,
+                }
+            }
+            kind => offer(&mut elements, &hit.context, kind, &hit.name, hit.score, reason),
+        }
+    }
+
+    let registry = DomainRegistry::new(model);
+    let mut seeds: Vec<(String, RelevantElement)> =
+        elements.iter().map(|((context, _), e)| (context.clone(), e.clone())).collect();
+    seeds.sort_by(|(ca, a), (cb, b)| {
+        b.score.total_cmp(&a.score).then_with(|| a.name.cmp(&b.name)).then_with(|| ca.cmp(cb))
+    });
+    seeds.truncate(limit);
+    for (context, seed) in &seeds {
+        let score = seed.score * NEIGHBOUR;
+        for usage in registry.usages(context, &seed.name) {
+            let reason = format!("uses '{}' via {}", seed.name, usage.via);
+            offer(&mut elements, &usage.context, usage.kind, &usage.element, score, reason);
+        }
+        for bc in &model.bounded_contexts {
+            for (kind, name, _) in bc.elements() {
+                let used = registry.usages(&bc.name, name).into_iter().find(|u| u.context == *context && u.element == seed.name);
+                if let Some(usage) = used {
+                    let reason = format!("used by '{}' via {}", seed.name, usage.via);
+                    offer(&mut elements, &bc.name, kind, name, score, reason);
+                }
+            }
+        }
+    }
+
+    let mut ranked: Vec<((String, String), RelevantElement)> = elements.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.score.total_cmp(&a.1.score).then_with(|| a.1.name.cmp(&b.1.name)));
+    let floor = ranked.first().map_or(0.0, |(_, e)| e.score * CUTOFF);
+    ranked.retain(|(_, e)| e.score >= floor);
+    ranked.truncate(limit);
+
+    let mut contexts: Vec<RelevantContext> = Vec::new();
+    for ((context, _), element) in ranked {
+        let Some(bc) = model.bounded_contexts.iter().find(|bc| bc.name == context) else {
+            continue;
+        };
+        match contexts.iter_mut().find(|c| c.name == context) {
+            Some(group) => group.elements.push(element),
+            None => contexts.push(RelevantContext {
+                name: context,
+                score: 0.0,
+                depends_on: bc.dependencies.clone(),
+                elements: vec![element],
+            }),
+        }
+    }
+    for (name, score) in context_scores {
+        match contexts.iter_mut().find(|c| c.name == name) {
+            Some(group) => group.score += score,
+            None => {
+                if let Some(bc) = model.bounded_contexts.iter().find(|bc| bc.name == name) {
+                    contexts.push(RelevantContext {
+                        name,
+                        score,
+                        depends_on: bc.dependencies.clone(),
+                        elements: vec![],
+                    });
+                }
+            }
+        }
+    }
+    for group in &mut contexts {
+        group.score = round(group.score + group.elements.iter().map(|e| e.score).sum::<f64>());
+    }
+    contexts.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+
+    let mut scopes: Vec<&str> = vec!["all"];
+    let mut kinds: Vec<&str> = Vec::new();
+    for group in &contexts {
+        let bc = model.bounded_contexts.iter().find(|bc| bc.name == group.name).unwrap();
+        scopes.push(&bc.name);
+        for e in &group.elements {
+            scopes.push(&e.name);
+            scopes.push(model.conventions.file_structure.layer_of(bc, e.kind, &e.name));
+            kinds.push(e.kind);
+        }
+    }
+    let rules = model
+        .rules
+        .iter()
+        .filter(|r| matched_rules.contains(&r.id) || scopes.iter().any(|s| r.scope.eq_ignore_ascii_case(s)))
+        .cloned()
+        .collect();
+
+    Relevance {
+        task: task.to_string(),
+        contexts,
+        external_systems,
+        rules,
+        conventions: conventions(&model.conventions, &kinds),
+    }
+}
+
+/// Record `name` as relevant, keeping its best score and the reason for it.
+fn offer(
+    elements: &mut HashMap<(String, String), RelevantElement>,
+    context: &str,
+    kind: &'static str,
+    name: &str,
+    score: f64,
+    reason: String,
+) {
+    let score = round(score);
+    let entry = elements
+        .entry((context.to_string(), name.to_string()))
+        .or_insert_with(|| RelevantElement {
+            kind,
+            name: name.to_string(),
+            score: 0.0,
+            reason: String::new(),
+        });
+    if score > entry.score {
+        entry.score = score;
+        entry.reason = reason;
+    }
+}
+
+fn conventions(conventions: &Conventions, kinds: &[&str]) -> Value {
+    let naming = &conventions.naming;
+    let mut by_kind = serde_json::Map::new();
+    for (kind, key, rule) in [
+        ("entity", "entities", &naming.entities),
+        ("value_object", "value_objects", &naming.value_objects),
+        ("service", "services", &naming.services),
+        ("repository", "repositories", &naming.repositories),
+        ("event", "events", &naming.events),
+    ] {
+        if kinds.contains(&kind) && !rule.is_empty() {
+            by_kind.insert(key.into(), json!(rule));
+        }
+    }
+    json!({
+        "file_pattern": conventions.file_structure.pattern,
+        "layers": conventions.file_structure.layers,
+        "naming": by_kind,
+        "error_handling": conventions.error_handling,
+        "testing": conventions.testing,
+    })
+}
+
+fn round(score: f64) -> f64 {
+    (score * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::fixtures::context;
+
+    fn entity(name: &str, description: &str) -> Entity {
+        Entity {
+            name: name.into(),
+            description: description.into(),
+            aggregate_root: true,
+            fields: vec![],
+            methods: vec![],
+            invariants: vec![],
+            state_machine: None,
+            meta: Metadata::default(),
+        }
+    }
+
+    fn event(name: &str, source: &str) -> DomainEvent {
+        DomainEvent {
+            name: name.into(),
+            description: "".into(),
+            fields: vec![],
+            source: source.into(),
+            published: true,
+            channel: "".into(),
+            meta: Metadata::default(),
+        }
+    }
+
+    fn rule(id: &str, scope: &str) -> ArchitecturalRule {
+        ArchitecturalRule {
+            id: id.into(),
+            description: "".into(),
+            severity: Severity::Error,
+            scope: scope.into(),
+            check: "".into(),
+        }
+    }
+
+    fn shop() -> DomainModel {
+        let mut identity = context("Identity", &[]);
+        identity.entities.push(entity("User", "A registered customer"));
+        let mut billing = context("Billing", &["Identity"]);
+        billing.entities.push(entity("Payment", "Money received for an invoice"));
+        billing.entities.push(entity("Invoice", "What a customer owes"));
+        billing.events.push(event("PaymentRefunded", "Payment"));
+        billing.glossary.push(Term {
+            term: "Payment".into(),
+            definition: "".into(),
+            synonyms: vec!["charge".into()],
+            forbidden_aliases: vec![],
+        });
+
+        let mut m = DomainModel::empty("/tmp/shop");
+        m.bounded_contexts = vec![identity, billing];
+        m.rules = vec![rule("DDD-001", "domain"), rule("GEN-001", "all"), rule("TEST-001", "testing")];
+        m.conventions.naming.entities = "PascalCase noun".into();
+        m.conventions.naming.services = "PascalCase + Service".into();
+        m
+    }
+
+    #[test]
+    fn test_relevant_matches_and_expands_over_references() {
+        let relevance = relevant(&shop(), "add a refund endpoint for a charge", 10);
+        assert_eq!(relevance.contexts.len(), 1);
+        let billing = &relevance.contexts[0];
+        assert_eq!(billing.name, "Billing");
+        let names: Vec<_> = billing.elements.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["Payment", "PaymentRefunded"]);
+        assert!(billing.elements[0].reason.starts_with("glossary term 'Payment'"), "{}", billing.elements[0].reason);
+
+        let rules: Vec<_> = relevance.rules.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(rules, vec!["DDD-001", "GEN-001"]);
+        assert_eq!(relevance.conventions["naming"], json!({"entities": "PascalCase noun"}));
+    }
+
+    #[test]
+    fn test_relevant_neighbours_rank_below_matches_and_respect_limit() {
+        let relevance = relevant(&shop(), "invoice payment", 2);
+        let elements: Vec<_> = relevance.contexts.iter().flat_map(|c| &c.elements).collect();
+        assert_eq!(elements.len(), 2);
+        assert!(elements.iter().all(|e| e.reason.starts_with("matches") || e.reason.starts_with("glossary")));
+
+        let nothing = relevant(&shop(), "add an endpoint", 10);
+        assert!(nothing.contexts.is_empty());
+        assert_eq!(nothing.rules[0].id, "GEN-001");
+    }
+}
@@ -0,0 +1,536 @@
+use std::fmt;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::model::*;
+use super::registry::DomainRegistry;
+use super::naming;
+
+/// A rule's `check`, parsed:
+///
+/// ```text
+/// check     := selector "must" ["not"] predicate
+/// selector  := kind ["where" condition ("and" condition)*]
+///            | "layer" name ("or" name)*
+/// condition := "aggregate_root" | ("kind" | "context" | "status" | "tag") "=" value
+/// predicate := "depend on" selector | "have" kind
+///            | "be named" ("past_tense" | "pascal_case" | "suffix" word | "prefix" word)
+/// ```
+///
+/// `kind` in a condition is a service kind (domain, application, infrastructure).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleCheck {
+    pub subject: Selector,
+    pub negated: bool,
+    pub predicate: Predicate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selector {
+    Elements { kind: String, conditions: Vec<Condition> },
+    Layers(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    AggregateRoot,
+    ServiceKind(String),
+    Context(String),
+    Status(Status),
+    Tag(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    DependOn(Selector),
+    Have(String),
+    BeNamed(Naming),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Naming {
+    PastTense,
+    PascalCase,
+    Suffix(String),
+    Prefix(String),
+}
+
+/// A code-level dependency, e.g. a `use` or `import` from one file of the workspace to another.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Import {
+    pub from: String,
+    pub to: String,
+}
+
+/// An element, layer or file breaking a checked rule.
+#[derive(Debug, Clone, Serialize)]
+pub struct Violation {
+    pub rule: String,
+    pub severity: Severity,
+    /// `Context::Element`, a layer or a file path
+    pub subject: String,
+    pub message: String,
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Selector::Layers(layers) => write!(f, "layer {}", layers.join(" or ")),
+            Selector::Elements { kind, conditions } => {
+                write!(f, "{kind}")?;
+                for (i, c) in conditions.iter().enumerate() {
+                    f.write_str(if i == 0 { " where " } else { " and " })?;
+                    match c {
+                        Condition::AggregateRoot => write!(f, "aggregate_root")?,
+                        Condition::ServiceKind(k) => write!(f, "kind = {k}")?,
+                        Condition::Context(c) => write!(f, "context = {c}")?,
+                        Condition::Status(s) => write!(f, "status = {}", s.as_str())?,
+                        Condition::Tag(t) => write!(f, "tag = {t}")?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for Naming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Naming::PastTense => write!(f, "in the past tense"),
+            Naming::PascalCase => write!(f, "in PascalCase"),
+            Naming::Suffix(s) => write!(f, "with the suffix '{s}'"),
+            Naming::Prefix(p) => write!(f, "with the prefix '{p}'"),
+        }
+    }
+}
+
+/// Parse a rule's `check`, e.g. `entity where aggregate_root must have repository`.
+pub fn parse(text: &str) -> Result<RuleCheck> {
+    let spaced = text.replace('=', " = ");
+    let mut p = Parser {
+        tokens: spaced.split_whitespace().collect(),
+        pos: 0,
+    };
+    let subject = p.selector()?;
+    p.expect("must")?;
+    let negated = p.eat("not");
+    let predicate = match p.next()? {
+        w if w.eq_ignore_ascii_case("depend") => {
+            p.expect("on")?;
+            let target = p.selector()?;
+            if matches!(subject, Selector::Layers(_)) != matches!(target, Selector::Layers(_)) {
+                anyhow::bail!("a layer can only depend on layers, and an element on elements");
+            }
+            Predicate::DependOn(target)
+        }
+        w if w.eq_ignore_ascii_case("have") => Predicate::Have(p.kind()?),
+        w if w.eq_ignore_ascii_case("be") => {
+            p.expect("named")?;
+            Predicate::BeNamed(match p.next()?.to_lowercase().as_str() {
+                "past_tense" => Naming::PastTense,
+                "pascal_case" => Naming::PascalCase,
+                "suffix" => Naming::Suffix(p.next()?.trim_matches('"').to_string()),
+                "prefix" => Naming::Prefix(p.next()?.trim_matches('"').to_string()),
+                other => anyhow::bail!("unknown naming '{other}'; use past_tense, pascal_case, suffix <word> or prefix <word>"),
+            })
+        }
+        other => anyhow::bail!("expected 'depend on', 'have' or 'be named' after 'must', found '{other}'"),
+    };
+    if matches!(subject, Selector::Layers(_)) && !matches!(predicate, Predicate::DependOn(_)) {
+        anyhow::bail!("layers can only be checked with 'depend on'");
+    }
+    if let Some(extra) = p.tokens.get(p.pos) {
+        anyhow::bail!("unexpected '{extra}' at the end");
+    }
+    Ok(RuleCheck {
+        subject,
+        negated,
+        predicate,
+    })
+}
+
+struct Parser<'t> {
+    tokens: Vec<&'t str>,
+    pos: usize,
+}
+
+impl<'t> Parser<'t> {
+    fn next(&mut self) -> Result<&'t str> {
+        let token = self.tokens.get(self.pos).copied().ok_or_else(|| anyhow::anyhow!("unexpected end"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, word: &str) -> bool {
+        let found = self.tokens.get(self.pos).is_some_and(|t| t.eq_ignore_ascii_case(word));
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, word: &str) -> Result<()> {
+        match self.next() {
+            Ok(t) if t.eq_ignore_ascii_case(word) => Ok(()),
+            Ok(t) => anyhow::bail!("expected '{word}', found '{t}'"),
+            Err(_) => anyhow::bail!("expected '{word}' but the check ends"),
+        }
+    }
+
+    fn kind(&mut self) -> Result<String> {
+        let kind = self.next()?.to_lowercase();
+        if !ELEMENT_KINDS.contains(&kind.as_str()) {
+            anyhow::bail!("unknown element kind '{kind}'; use one of: {}", ELEMENT_KINDS.join(", "));
+        }
+        Ok(kind)
+    }
+
+    fn selector(&mut self) -> Result<Selector> {
+        if self.eat("layer") {
+            let mut layers = vec![self.next()?.to_lowercase()];
+            while self.eat("or") {
+                layers.push(self.next()?.to_lowercase());
+            }
+            return Ok(Selector::Layers(layers));
+        }
+        let kind = self.kind()?;
+        let mut conditions = Vec::new();
+        if self.eat("where") {
+            loop {
+                conditions.push(self.condition()?);
+                if !self.eat("and") {
+                    break;
+                }
+            }
+        }
+        Ok(Selector::Elements { kind, conditions })
+    }
+
+    fn condition(&mut self) -> Result<Condition> {
+        let key = self.next()?.to_lowercase();
+        if key == "aggregate_root" {
+            return Ok(Condition::AggregateRoot);
+        }
+        self.expect("=")?;
+        let value = self.next()?.to_string();
+        Ok(match key.as_str() {
+            "kind" => Condition::ServiceKind(value.to_lowercase()),
+            "context" => Condition::Context(value),
+            "status" => Condition::Status(
+                Status::parse(&value).ok_or_else(|| anyhow::anyhow!("unknown status '{value}'"))?,
+            ),
+            "tag" => Condition::Tag(value),
+            other => anyhow::bail!("unknown condition '{other}'; use aggregate_root, kind, context, status or tag"),
+        })
+    }
+}
+
+/// An element of the model as seen by the evaluator.
+#[derive(Clone, Copy)]
+struct Item<'m> {
+    bc: &'m BoundedContext,
+    kind: &'static str,
+    name: &'m str,
+    meta: &'m Metadata,
+    layer: &'m str,
+}
+
+impl<'m> Item<'m> {
+    fn label(&self) -> String {
+        format!("{}::{}", self.bc.name, self.name)
+    }
+
+    fn matches(&self, selector: &Selector) -> bool {
+        match selector {
+            Selector::Layers(layers) => layers.iter().any(|l| l == self.layer),
+            Selector::Elements { kind, conditions } => {
+                kind == self.kind && conditions.iter().all(|c| self.satisfies(c))
+            }
+        }
+    }
+
+    fn satisfies(&self, condition: &Condition) -> bool {
+        match condition {
+            Condition::AggregateRoot => self.kind == "entity" && self.bc.is_aggregate_root(self.name),
+            Condition::ServiceKind(k) => self
+                .bc
+                .services
+                .iter()
+                .find(|s| s.name == self.name)
+                .is_some_and(|s| s.kind.as_str() == k),
+            Condition::Context(c) => self.bc.name.eq_ignore_ascii_case(c),
+            Condition::Status(s) => self.meta.status == *s,
+            Condition::Tag(t) => self.meta.has_tag(t),
+        }
+    }
+}
+
+/// Evaluate every rule that has a `check`. Element dependencies are the references
+/// between model elements; layer dependencies also include `imports`, whose files are
+/// placed in the layer named by one of their path segments. A `check` that does not
+/// parse is reported as an error against its rule.
+pub fn evaluate(model: &DomainModel, imports: &[Import]) -> Vec<Violation> {
+    let files = &model.conventions.file_structure;
+    let items: Vec<Item> = model
+        .bounded_contexts
+        .iter()
+        .flat_map(|bc| {
+            bc.elements().into_iter().map(move |(kind, name, meta)| Item {
+                bc,
+                kind,
+                name,
+                meta,
+                layer: files.layer_of(bc, kind, name),
+            })
+        })
+        .collect();
+    let registry = DomainRegistry::new(model);
+    // (from, to, via): `from` refers to `to`
+    let mut edges: Vec<(usize, usize, String)> = Vec::new();
+    let mut users: Vec<Vec<usize>> = vec![Vec::new(); items.len()];
+    for (to, item) in items.iter().enumerate() {
+        for usage in registry.usages(&item.bc.name, item.name) {
+            let from = items.iter().position(|i| i.bc.name == usage.context && i.name == usage.element);
+            if let Some(from) = from.filter(|&f| f != to) {
+                users[to].push(from);
+                edges.push((from, to, usage.via));
+            }
+        }
+    }
+
+    let mut violations = Vec::new();
+    for rule in model.rules.iter().filter(|r| !r.check.is_empty()) {
+        let check = match parse(&rule.check) {
+            Ok(check) => check,
+            Err(e) => {
+                violations.push(Violation {
+                    rule: rule.id.clone(),
+                    severity: Severity::Error,
+                    subject: rule.id.clone(),
+                    message: format!("check '{}' cannot be parsed: {e}", rule.check),
+                });
+                continue;
+            }
+        };
+        let mut violate = |subject: String, message: String| {
+            violations.push(Violation {
+                rule: rule.id.clone(),
+                severity: rule.severity.clone(),
+                subject,
+                message,
+            })
+        };
+
+        if let (Selector::Layers(from), Predicate::DependOn(Selector::Layers(to))) = (&check.subject, &check.predicate) {
+            let crossing = |a: &str, b: &str| from.iter().any(|l| l == a) && to.iter().any(|l| l == b);
+            let mut found = false;
+            for (a, b, via) in &edges {
+                let (a, b) = (&items[*a], &items[*b]);
+                if crossing(a.layer, b.layer) {
+                    found = true;
+                    if check.negated {
+                        violate(
+                            a.label(),
+                            format!("'{}' ({}) depends on '{}' ({}) via {via}", a.name, a.layer, b.label(), b.layer),
+                        );
+                    }
+                }
+            }
+            let layers = &model.conventions.file_structure.layers;
+            for import in imports {
+                let (Some(a), Some(b)) = (file_layer(&import.from, layers), file_layer(&import.to, layers)) else {
+                    continue;
+                };
+                if crossing(&a, &b) {
+                    found = true;
+                    if check.negated {
+                        violate(import.from.clone(), format!("'{}' ({a}) imports '{}' ({b})", import.from, import.to));
+                    }
+                }
+            }
+            if !check.negated && !found {
+                violate(check.subject.to_string(), format!("nothing in {} depends on {}", check.subject, to.join(" or ")));
+            }
+            continue;
+        }
+
+        for (index, item) in items.iter().enumerate().filter(|(_, i)| i.matches(&check.subject)) {
+            let (holds, message) = match &check.predicate {
+                Predicate::DependOn(target) => {
+                    let dependency = edges.iter().find(|(a, b, _)| *a == index && items[*b].matches(target));
+                    match dependency {
+                        Some((_, b, via)) => (true, format!("'{}' depends on '{}' via {via}", item.name, items[*b].label())),
+                        None => (false, format!("'{}' does not depend on any {target}", item.name)),
+                    }
+                }
+                Predicate::Have(kind) => match users[index].iter().find(|&&u| items[u].kind == kind) {
+                    Some(&u) => (true, format!("'{}' has the {} '{}'", item.name, kind.replace('_', " "), items[u].name)),
+                    None => (false, format!("'{}' has no {}", item.name, kind.replace('_', " "))),
+                },
+                Predicate::BeNamed(naming) => {
+                    let holds = is_named(item.name, naming);
+                    let verb = if holds { "is" } else { "is not" };
+                    (holds, format!("'{}' {verb} named {naming}", item.name))
+                }
+            };
+            if holds == check.negated {
+                violate(item.label(), message);
+            }
+        }
+    }
+    violations
+}
+
+fn is_named(name: &str, naming: &Naming) -> bool {
+    match naming {
+        Naming::PastTense => naming::is_past_tense(name),
+        Naming::PascalCase => naming::is_case(name, CaseStyle::Pascal),
+        Naming::Suffix(s) => name.ends_with(s.as_str()),
+        Naming::Prefix(p) => name.starts_with(p.as_str()),
+    }
+}
+
+/// The layer a file belongs to: the first path segment naming one of the configured
+/// `layers` or domain, application or infrastructure.
+fn file_layer(path: &str, layers: &[String]) -> Option<String> {
+    const STANDARD: &[&str] = &["domain", "application", "infrastructure"];
+    path.split(['/', '\\']).find_map(|segment| {
+        let segment = segment.to_lowercase();
+        let known = STANDARD.contains(&segment.as_str()) || layers.iter().any(|l| l.eq_ignore_ascii_case(&segment));
+        known.then_some(segment)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::fixtures::context;
+
+    fn service(name: &str, kind: ServiceKind, dependencies: &[&str]) -> Service {
+        Service {
+            name: name.into(),
+            description: "".into(),
+            kind,
+            methods: vec![],
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+            meta: Metadata::default(),
+        }
+    }
+
+    fn rule(id: &str, severity: Severity, check: &str) -> ArchitecturalRule {
+        ArchitecturalRule {
+            id: id.into(),
+            description: "".into(),
+            severity,
+            scope: "".into(),
+            check: check.into(),
+        }
+    }
+
+    fn shop() -> DomainModel {
+        let mut billing = context("Billing", &[]);
+        for name in ["Invoice", "Payment"] {
+            billing.entities.push(Entity {
+                name: name.into(),
+                description: "".into(),
+                aggregate_root: true,
+                fields: vec![],
+                methods: vec![],
+                invariants: vec![],
+                state_machine: None,
+                meta: Metadata::default(),
+            });
+        }
+        billing.repositories.push(Repository {
+            name: "InvoiceRepository".into(),
+            aggregate: "Invoice".into(),
+            methods: vec![],
+            meta: Metadata::default(),
+        });
+        for name in ["InvoicePaid", "PaymentRefund", "InvoiceSent"] {
+            billing.events.push(DomainEvent {
+                name: name.into(),
+                description: "".into(),
+                fields: vec![],
+                source: "".into(),
+                published: false,
+                channel: "".into(),
+                meta: Metadata::default(),
+            });
+        }
+        billing.services.push(service("Pricing", ServiceKind::Domain, &["StripeGateway"]));
+        billing.services.push(service("StripeGateway", ServiceKind::Infrastructure, &[]));
+        let mut m = DomainModel::empty("/tmp/shop");
+        m.bounded_contexts.push(billing);
+        m
+    }
+
+    #[test]
+    fn test_parse_checks() {
+        let check = parse("service where kind=domain and context = Billing must not depend on service where kind = infrastructure").unwrap();
+        assert!(check.negated);
+        assert_eq!(
+            check.subject,
+            Selector::Elements {
+                kind: "service".into(),
+                conditions: vec![Condition::ServiceKind("domain".into()), Condition::Context("Billing".into())],
+            }
+        );
+        assert_eq!(check.predicate.clone(), Predicate::DependOn(parse("service where kind = infrastructure must have event").unwrap().subject));
+        assert_eq!(parse("layer domain must not depend on layer infrastructure or application").unwrap().subject.to_string(), "layer domain");
+
+        let err = |text: &str| parse(text).unwrap_err().to_string();
+        assert!(err("services must have repository").contains("unknown element kind 'services'"));
+        assert!(err("event must be named future").contains("unknown naming"));
+        assert!(err("layer domain must have repository").contains("only be checked with 'depend on'"));
+        assert!(err("entity must have repository now").contains("unexpected 'now'"));
+    }
+
+    #[test]
+    fn test_evaluate_model_rules() {
+        let mut m = shop();
+        m.rules = vec![
+            rule("DDD-010", Severity::Error, "service where kind = domain must not depend on service where kind = infrastructure"),
+            rule("DDD-011", Severity::Warning, "entity where aggregate_root must have repository"),
+            rule("NAME-001", Severity::Warning, "event must be named past_tense"),
+            rule("FREE-001", Severity::Error, ""),
+            rule("BAD-001", Severity::Info, "events must be named past_tense"),
+        ];
+        let found: Vec<_> = evaluate(&m, &[]).into_iter().map(|v| (v.rule, v.subject, v.message)).collect();
+        assert_eq!(
+            found,
+            vec![
+                ("DDD-010".into(), "Billing::Pricing".into(), "'Pricing' depends on 'Billing::StripeGateway' via dependency".into()),
+                ("DDD-011".into(), "Billing::Payment".into(), "'Payment' has no repository".into()),
+                ("NAME-001".into(), "Billing::PaymentRefund".into(), "'PaymentRefund' is not named in the past tense".into()),
+                (
+                    "BAD-001".into(),
+                    "BAD-001".into(),
+                    "check 'events must be named past_tense' cannot be parsed: unknown element kind 'events'; use one of: \
+                     entity, value_object, enumeration, aggregate, command, query, use_case, policy, service, repository, event"
+                        .into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_evaluate_layers_with_imports() {
+        let mut m = shop();
+        m.rules = vec![rule("LAYER-001", Severity::Error, "layer domain must not depend on layer infrastructure")];
+        let imports = vec![
+            Import {
+                from: "src/billing/domain/invoice.rs".into(),
+                to: "src/billing/infrastructure/stripe.rs".into(),
+            },
+            Import {
+                from: "src/billing/application/pay.rs".into(),
+                to: "src/billing/infrastructure/stripe.rs".into(),
+            },
+        ];
+        let subjects: Vec<_> = evaluate(&m, &imports).into_iter().map(|v| v.subject).collect();
+        assert_eq!(subjects, vec!["Billing::Pricing", "src/billing/domain/invoice.rs"]);
+    }
+}
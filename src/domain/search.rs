@@ -0,0 +1,417 @@
+use serde::Serialize;
+
+use super::model::*;
+use super::to_snake;
+
+/// A ranked search result.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub kind: &'static str,
+    /// Owning bounded context; empty for contexts, external systems and rules
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub context: String,
+    pub name: String,
+    pub score: f64,
+    /// Where the query matched, e.g. `name`, `field 'plan'`, `invariant`
+    pub matched: Vec<String>,
+}
+
+/// A searchable element: labelled, weighted sections of normalized words.
+struct Document {
+    kind: &'static str,
+    context: String,
+    name: String,
+    sections: Vec<(String, f64, Vec<String>)>,
+}
+
+impl Document {
+    fn new(kind: &'static str, context: &str, name: &str) -> Self {
+        let mut doc = Document {
+            kind,
+            context: context.to_string(),
+            name: name.to_string(),
+            sections: Vec::new(),
+        };
+        doc.add("name", NAME, name);
+        doc
+    }
+
+    fn add(&mut self, label: impl Into<String>, weight: f64, text: &str) -> &mut Self {
+        let words = words(text);
+        if !words.is_empty() {
+            self.sections.push((label.into(), weight, words));
+        }
+        self
+    }
+
+    fn fields(&mut self, fields: &[Field]) -> &mut Self {
+        for f in fields {
+            self.add(format!("field '{}'", f.name), MEMBER, &format!("{} {}", f.name, f.field_type));
+            self.add(format!("field '{}'", f.name), TEXT, &f.description);
+        }
+        self
+    }
+
+    fn methods(&mut self, methods: &[Method]) -> &mut Self {
+        for m in methods {
+            self.add(format!("method '{}'", m.name), MEMBER, &m.name);
+            self.add(format!("method '{}'", m.name), TEXT, &m.description);
+        }
+        self
+    }
+
+    fn texts(&mut self, label: &str, texts: &[String]) -> &mut Self {
+        for text in texts {
+            self.add(label, TEXT, text);
+        }
+        self
+    }
+
+    /// The section where `term` matches best, with its weighted score.
+    fn best_match(&self, term: &str) -> Option<(&String, f64)> {
+        self.sections
+            .iter()
+            .filter_map(|(label, weight, words)| {
+                let quality = words.iter().map(|w| word_match(term, w)).fold(0.0, f64::max);
+                (quality > 0.0).then_some((label, weight * quality))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+const NAME: f64 = 5.0;
+const ALIAS: f64 = 4.0;
+const MEMBER: f64 = 2.0;
+const TEXT: f64 = 1.0;
+
+/// Search names, descriptions, fields, methods, invariants, glossary terms and rules.
+/// Every query word must match somewhere — exactly, as a prefix or with a typo — and
+/// hits are ranked by where it matched: names first, then members, then prose.
+pub fn search(model: &DomainModel, query: &str, limit: usize) -> Vec<SearchHit> {
+    let terms = words(query);
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hits: Vec<SearchHit> = documents(model)
+        .into_iter()
+        .filter_map(|doc| {
+            let mut score = 0.0;
+            let mut matched: Vec<String> = Vec::new();
+            for term in &terms {
+                let best = doc.best_match(term)?;
+                score += best.1;
+                if !matched.contains(best.0) {
+                    matched.push(best.0.clone());
+                }
+            }
+            // The query spells the whole name, e.g. "subscription plan" for SubscriptionPlan
+            if words(&doc.name) == terms {
+                score += NAME;
+            }
+            Some(SearchHit {
+                kind: doc.kind,
+                context: doc.context,
+                name: doc.name,
+                score: (score * 100.0).round() / 100.0,
+                matched,
+            })
+        })
+        .collect();
+
+    hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+    hits.truncate(limit);
+    hits
+}
+
+/// Every document matching at least one of `terms`, best first. Unlike `search`, a
+/// term that matches nothing is ignored, which suits free-form task descriptions.
+pub(super) fn rank_any(model: &DomainModel, terms: &[String]) -> Vec<SearchHit> {
+    let mut hits: Vec<SearchHit> = documents(model)
+        .into_iter()
+        .filter_map(|doc| {
+            let mut score = 0.0;
+            let mut matched: Vec<String> = Vec::new();
+            for best in terms.iter().filter_map(|term| doc.best_match(term)) {
+                score += best.1;
+                if !matched.contains(best.0) {
+                    matched.push(best.0.clone());
+                }
+            }
+            (score > 0.0).then(|| SearchHit {
+                kind: doc.kind,
+                context: doc.context,
+                name: doc.name,
+                score: (score * 100.0).round() / 100.0,
+                matched,
+            })
+        })
+        .collect();
+    hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+    hits
+}
+
+/// Up to three candidates that look like what `name` was meant to be: the same words
+/// up to plurals and typos, or a name that contains all of its words or vice versa.
+pub fn suggest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let wanted = words(name);
+    if wanted.is_empty() {
+        return Vec::new();
+    }
+    let joined = wanted.concat();
+
+    let mut ranked: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let have = words(candidate);
+            let distance = levenshtein(&joined, &have.concat());
+            if distance <= typo_budget(joined.len()).max(1) {
+                return Some((distance, candidate));
+            }
+            let overlap = |a: &[String], b: &[String]| a.iter().all(|w| b.contains(w));
+            (overlap(&wanted, &have) || overlap(&have, &wanted)).then_some((distance, candidate))
+        })
+        .collect();
+    ranked.sort();
+    ranked.dedup_by(|a, b| a.1.eq_ignore_ascii_case(b.1));
+    ranked.into_iter().take(3).map(|(_, c)| c.to_string()).collect()
+}
+
+fn documents(model: &DomainModel) -> Vec<Document> {
+    let mut docs = Vec::new();
+    for bc in &model.bounded_contexts {
+        let ctx = bc.name.as_str();
+        let mut doc = Document::new("bounded_context", "", ctx);
+        doc.add("description", TEXT, &bc.description).texts("tag", &bc.meta.tags);
+        docs.push(doc);
+
+        for e in &bc.entities {
+            let mut doc = Document::new("entity", ctx, &e.name);
+            doc.add("description", TEXT, &e.description)
+                .fields(&e.fields)
+                .methods(&e.methods)
+                .texts("invariant", &e.invariants)
+                .texts("tag", &e.meta.tags);
+            docs.push(doc);
+        }
+        for v in &bc.value_objects {
+            let mut doc = Document::new("value_object", ctx, &v.name);
+            doc.add("description", TEXT, &v.description)
+                .fields(&v.fields)
+                .texts("validation rule", &v.validation_rules)
+                .texts("tag", &v.meta.tags);
+            docs.push(doc);
+        }
+        for en in &bc.enumerations {
+            let mut doc = Document::new("enumeration", ctx, &en.name);
+            doc.add("description", TEXT, &en.description);
+            for variant in &en.variants {
+                doc.add(format!("variant '{}'", variant.name), MEMBER, &variant.name);
+            }
+            docs.push(doc);
+        }
+        for agg in &bc.aggregates {
+            let mut doc = Document::new("aggregate", ctx, &agg.name);
+            doc.add("description", TEXT, &agg.description);
+            docs.push(doc);
+        }
+        for kind in OperationKind::ALL {
+            for op in bc.operations(kind) {
+                let mut doc = Document::new(kind.element_kind(), ctx, &op.name);
+                doc.add("description", TEXT, &op.description)
+                    .fields(&op.fields)
+                    .texts("invariant", &op.invariants)
+                    .texts("tag", &op.meta.tags);
+                docs.push(doc);
+            }
+        }
+        for policy in &bc.policies {
+            let mut doc = Document::new("policy", ctx, &policy.name);
+            doc.add("description", TEXT, &policy.description);
+            docs.push(doc);
+        }
+        for svc in &bc.services {
+            let mut doc = Document::new("service", ctx, &svc.name);
+            doc.add("description", TEXT, &svc.description)
+                .methods(&svc.methods)
+                .texts("tag", &svc.meta.tags);
+            docs.push(doc);
+        }
+        for repo in &bc.repositories {
+            let mut doc = Document::new("repository", ctx, &repo.name);
+            doc.methods(&repo.methods);
+            docs.push(doc);
+        }
+        for event in &bc.events {
+            let mut doc = Document::new("event", ctx, &event.name);
+            doc.add("description", TEXT, &event.description)
+                .fields(&event.fields)
+                .add("channel", TEXT, &event.channel);
+            docs.push(doc);
+        }
+        for term in &bc.glossary {
+            let mut doc = Document::new("term", ctx, &term.term);
+            doc.add("definition", TEXT, &term.definition);
+            for synonym in &term.synonyms {
+                doc.add("synonym", ALIAS, synonym);
+            }
+            docs.push(doc);
+        }
+    }
+    for system in &model.external_systems {
+        let mut doc = Document::new("external_system", "", &system.name);
+        doc.add("description", TEXT, &system.description).add("protocol", MEMBER, &system.protocol);
+        docs.push(doc);
+    }
+    for rule in &model.rules {
+        let mut doc = Document::new("rule", "", &rule.id);
+        doc.add("rule", TEXT, &rule.description);
+        docs.push(doc);
+    }
+    docs
+}
+
+/// Lowercase, singular words of a name or sentence: `SubscriptionPlans` → subscription, plan.
+pub(super) fn words(text: &str) -> Vec<String> {
+    const STOP: &[&str] = &["a", "an", "and", "be", "by", "for", "in", "is", "of", "on", "or", "the", "to", "with"];
+    text.split(|c: char| !c.is_alphanumeric())
+        .flat_map(|piece| to_snake(piece).split('_').map(String::from).collect::<Vec<_>>())
+        .filter(|w| !w.is_empty() && !STOP.contains(&w.as_str()))
+        .map(|w| singular(&w))
+        .collect()
+}
+
+fn singular(word: &str) -> String {
+    if word.len() > 4 {
+        if let Some(stem) = word.strip_suffix("ies") {
+            return format!("{stem}y");
+        }
+        for suffix in ["sses", "uses", "xes", "ches", "shes"] {
+            if word.ends_with(suffix) {
+                return word[..word.len() - 2].to_string();
+            }
+        }
+    }
+    if word.len() > 3 && word.ends_with('s') && !["ss", "us", "is"].iter().any(|s| word.ends_with(s)) {
+        return word[..word.len() - 1].to_string();
+    }
+    word.to_string()
+}
+
+/// 1 for the same word, less for a prefix of it or a typo, 0 otherwise.
+fn word_match(term: &str, word: &str) -> f64 {
+    if term == word {
+        1.0
+    } else if term.len() >= 3 && word.starts_with(term) {
+        0.6
+    } else if levenshtein(term, word) <= typo_budget(term.len()) {
+        0.5
+    } else {
+        0.0
+    }
+}
+
+/// Typos tolerated in a word of the given length.
+fn typo_budget(len: usize) -> usize {
+    match len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev + usize::from(ca != *cb);
+            prev = row[j + 1];
+            row[j + 1] = substitution.min(prev + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shop() -> DomainModel {
+        let mut m = DomainModel::empty("/tmp/shop");
+        m.bounded_contexts.push(BoundedContext {
+            name: "Billing".into(),
+            description: "Subscriptions and invoices".into(),
+            module_path: "".into(),
+            entities: vec![Entity {
+                name: "Subscription".into(),
+                description: "A customer's paid plan".into(),
+                aggregate_root: true,
+                fields: vec![Field {
+                    name: "plan".into(),
+                    field_type: "Plan".into(),
+                    required: true,
+                    description: "".into(),
+                }],
+                methods: vec![],
+                invariants: vec!["A user has at most one active subscription".into()],
+                state_machine: None,
+                meta: Metadata::default(),
+            }],
+            value_objects: vec![],
+            enumerations: vec![Enumeration {
+                name: "Plan".into(),
+                description: "".into(),
+                variants: vec![],
+                meta: Metadata::default(),
+            }],
+            aggregates: vec![],
+            commands: vec![],
+            queries: vec![],
+            use_cases: vec![],
+            policies: vec![],
+            glossary: vec![],
+            services: vec![],
+            repositories: vec![],
+            events: vec![],
+            dependencies: vec![],
+            meta: Metadata::default(),
+        });
+        m
+    }
+
+    #[test]
+    fn test_search_ranks_names_over_members_and_prose() {
+        let hits = search(&shop(), "plans", 10);
+        let names: Vec<_> = hits.iter().map(|h| h.name.as_str()).collect();
+        assert_eq!(names, vec!["Plan", "Subscription"]);
+        assert_eq!(hits[1].matched, vec!["field 'plan'"]);
+
+        let hits = search(&shop(), "subscripton plan", 10);
+        assert_eq!(hits[0].name, "Subscription");
+        assert_eq!(hits[0].matched, vec!["name", "field 'plan'"]);
+
+        assert!(search(&shop(), "active subscription", 10).iter().any(|h| h.matched.contains(&"invariant".to_string())));
+        assert!(search(&shop(), "warehouse", 10).is_empty());
+    }
+
+    #[test]
+    fn test_suggest() {
+        let names = ["User", "UserProfile", "Subscription"];
+        assert_eq!(suggest("Users", names), vec!["User", "UserProfile"]);
+        assert_eq!(suggest("subscription plan", names), vec!["Subscription"]);
+        assert_eq!(suggest("Subscripton", names), vec!["Subscription"]);
+        assert!(suggest("Invoice", names).is_empty());
+    }
+
+    #[test]
+    fn test_singular() {
+        assert_eq!(singular("policies"), "policy");
+        assert_eq!(singular("addresses"), "address");
+        assert_eq!(singular("statuses"), "status");
+        assert_eq!(singular("status"), "status");
+        assert_eq!(singular("invoices"), "invoice");
+    }
+}
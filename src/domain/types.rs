@@ -0,0 +1,401 @@
+use std::fmt;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use super::model::*;
+
+/// A parsed field type. `Field.field_type` stays a string in the model file;
+/// this is its structure, parsed on demand.
+///
+/// Rust spellings are kept as written (`BTreeMap`, `VecDeque`); common spellings
+/// from other languages are normalized: `T?` → `Option<T>`, `T[]` and `List<T>` →
+/// `Vec<T>`, `Set<T>` → `HashSet<T>`, `Map<K, V>` → `HashMap<K, V>`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TypeRef {
+    /// `Option<T>`
+    Optional { inner: Box<TypeRef> },
+    /// `Vec<T>`, `HashSet<T>`, `VecDeque<T>`, …
+    Collection { name: String, item: Box<TypeRef> },
+    /// `HashMap<K, V>`, `BTreeMap<K, V>`
+    Map { name: String, key: Box<TypeRef>, value: Box<TypeRef> },
+    /// `(A, B)`; `()` has no items
+    Tuple { items: Vec<TypeRef> },
+    /// Any other type, possibly path-qualified or generic: `UserId`, `chrono::DateTime<Utc>`
+    Named { name: String, args: Vec<TypeRef> },
+}
+
+/// How a field type changed, as far as storage and callers are concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeChange {
+    /// `T` → `Option<T>`
+    MadeOptional,
+    /// `Option<T>` → `T`
+    MadeRequired,
+    /// Anything else
+    Changed,
+}
+
+/// A model element a type refers to.
+#[derive(Debug, Clone, Serialize)]
+pub struct ElementRef {
+    pub name: String,
+    /// entity, value_object, enumeration or event
+    pub kind: &'static str,
+    pub context: String,
+    /// Referenced through a collection or map
+    pub many: bool,
+    /// Referenced through `Option`
+    pub optional: bool,
+}
+
+impl TypeRef {
+    pub fn parse(source: &str) -> Result<Self> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let ty = parser.ty()?;
+        if let Some(tok) = parser.tokens.get(parser.pos) {
+            anyhow::bail!("unexpected '{tok}' in type '{source}'");
+        }
+        Ok(ty)
+    }
+
+    pub fn is_optional(&self) -> bool {
+        matches!(self, TypeRef::Optional { .. })
+    }
+
+    /// The type without its outer `Option`.
+    pub fn required(&self) -> &TypeRef {
+        match self {
+            TypeRef::Optional { inner } => inner,
+            other => other,
+        }
+    }
+
+    /// Model elements this type refers to, resolved in `context` first and then
+    /// in the other contexts. Path-qualified names match on their last segment.
+    pub fn references(&self, model: &DomainModel, context: &str) -> Vec<ElementRef> {
+        let mut out = Vec::new();
+        self.walk(false, false, &mut |name, many, optional| {
+            let short = name.rsplit("::").next().unwrap_or(name);
+            if let Some((bc, kind, found)) = resolve(model, context, short) {
+                out.push(ElementRef {
+                    name: found.to_string(),
+                    kind,
+                    context: bc.name.clone(),
+                    many,
+                    optional,
+                });
+            }
+        });
+        out
+    }
+
+    fn walk<'a>(&'a self, many: bool, optional: bool, f: &mut impl FnMut(&'a str, bool, bool)) {
+        match self {
+            TypeRef::Optional { inner } => inner.walk(many, true, f),
+            TypeRef::Collection { item, .. } => item.walk(true, optional, f),
+            TypeRef::Map { key, value, .. } => {
+                key.walk(true, optional, f);
+                value.walk(true, optional, f);
+            }
+            TypeRef::Tuple { items } => items.iter().for_each(|t| t.walk(many, optional, f)),
+            TypeRef::Named { name, args } => {
+                f(name, many, optional);
+                args.iter().for_each(|t| t.walk(many, optional, f));
+            }
+        }
+    }
+}
+
+/// Compare two field types by structure, so `Option<T>` and `T?` are the same type.
+/// `None` when they are equivalent. Unparseable types compare as strings.
+pub fn classify_change(old: &str, new: &str) -> Option<TypeChange> {
+    let (Ok(old_ty), Ok(new_ty)) = (TypeRef::parse(old), TypeRef::parse(new)) else {
+        return (old.trim() != new.trim()).then_some(TypeChange::Changed);
+    };
+    if old_ty == new_ty {
+        None
+    } else if !old_ty.is_optional() && new_ty.required() == &old_ty {
+        Some(TypeChange::MadeOptional)
+    } else if !new_ty.is_optional() && old_ty.required() == &new_ty {
+        Some(TypeChange::MadeRequired)
+    } else {
+        Some(TypeChange::Changed)
+    }
+}
+
+fn resolve<'m>(model: &'m DomainModel, context: &str, name: &str) -> Option<(&'m BoundedContext, &'static str, &'m str)> {
+    fn hit<'m>(names: impl IntoIterator<Item = &'m String>, name: &str) -> Option<&'m str> {
+        names.into_iter().find(|n| n.as_str() == name).map(String::as_str)
+    }
+    let local = model
+        .bounded_contexts
+        .iter()
+        .filter(|bc| bc.name.eq_ignore_ascii_case(context));
+    local.chain(model.bounded_contexts.iter()).find_map(|bc| {
+        let found = hit(bc.entities.iter().map(|e| &e.name), name)
+            .map(|n| ("entity", n))
+            .or_else(|| hit(bc.value_objects.iter().map(|v| &v.name), name).map(|n| ("value_object", n)))
+            .or_else(|| hit(bc.enumerations.iter().map(|e| &e.name), name).map(|n| ("enumeration", n)))
+            .or_else(|| hit(bc.events.iter().map(|e| &e.name), name).map(|n| ("event", n)));
+        found.map(|(kind, n)| (bc, kind, n))
+    })
+}
+
+impl fmt::Display for TypeRef {
+    /// Rust syntax, usable in generated code.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |items: &[TypeRef]| items.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", ");
+        match self {
+            TypeRef::Optional { inner } => write!(f, "Option<{inner}>"),
+            TypeRef::Collection { name, item } => write!(f, "{name}<{item}>"),
+            TypeRef::Map { name, key, value } => write!(f, "{name}<{key}, {value}>"),
+            TypeRef::Tuple { items } if items.len() == 1 => write!(f, "({},)", items[0]),
+            TypeRef::Tuple { items } => write!(f, "({})", list(items)),
+            TypeRef::Named { name, args } if args.is_empty() => write!(f, "{name}"),
+            TypeRef::Named { name, args } => write!(f, "{name}<{}>", list(args)),
+        }
+    }
+}
+
+// ─── Parser ────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Sym(char),
+}
+
+impl fmt::Display for Tok {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tok::Ident(s) => write!(f, "{s}"),
+            Tok::Sym(c) => write!(f, "{c}"),
+        }
+    }
+}
+
+/// Identifiers include path separators (`chrono::Utc`). References and lifetimes
+/// (`&'a str`, `&mut T`) are dropped: they say nothing about the stored value.
+fn tokenize(source: &str) -> Result<Vec<Tok>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() || c == '&' => {
+                chars.next();
+            }
+            '\'' => {
+                chars.next();
+                while chars.peek().is_some_and(|c| c.is_alphanumeric() || *c == '_') {
+                    chars.next();
+                }
+            }
+            '<' | '>' | ',' | '?' | '[' | ']' | '(' | ')' => {
+                tokens.push(Tok::Sym(c));
+                chars.next();
+            }
+            c if c.is_alphanumeric() || c == '_' || c == ':' => {
+                let mut ident = String::new();
+                while let Some(&c) = chars.peek().filter(|c| c.is_alphanumeric() || **c == '_' || **c == ':') {
+                    ident.push(c);
+                    chars.next();
+                }
+                if ident != "mut" && ident != "dyn" {
+                    tokens.push(Tok::Ident(ident));
+                }
+            }
+            other => anyhow::bail!("unexpected '{other}' in type '{source}'"),
+        }
+    }
+    if tokens.is_empty() {
+        anyhow::bail!("type is empty");
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Tok>,
+    pos: usize,
+}
+
+impl Parser {
+    fn eat(&mut self, c: char) -> bool {
+        if self.tokens.get(self.pos) == Some(&Tok::Sym(c)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            match self.tokens.get(self.pos) {
+                Some(tok) => anyhow::bail!("expected '{c}', found '{tok}'"),
+                None => anyhow::bail!("expected '{c}' at end of type"),
+            }
+        }
+    }
+
+    /// type := base ('?' | '[' ']')*
+    fn ty(&mut self) -> Result<TypeRef> {
+        let mut ty = self.base()?;
+        loop {
+            if self.eat('?') {
+                ty = TypeRef::Optional { inner: Box::new(ty) };
+            } else if self.eat('[') {
+                self.expect(']')?;
+                ty = TypeRef::Collection { name: "Vec".into(), item: Box::new(ty) };
+            } else {
+                return Ok(ty);
+            }
+        }
+    }
+
+    /// base := '(' types? ')' | ident ('<' types '>')?
+    fn base(&mut self) -> Result<TypeRef> {
+        if self.eat('(') {
+            let items = if self.eat(')') { vec![] } else { self.list(')')? };
+            return Ok(TypeRef::Tuple { items });
+        }
+        let name = match self.tokens.get(self.pos) {
+            Some(Tok::Ident(name)) => name.clone(),
+            Some(tok) => anyhow::bail!("expected a type name, found '{tok}'"),
+            None => anyhow::bail!("expected a type name at end of type"),
+        };
+        self.pos += 1;
+        let args = if self.eat('<') { self.list('>')? } else { vec![] };
+        Ok(shape(name, args))
+    }
+
+    /// types := type (',' type)* ','? close
+    fn list(&mut self, close: char) -> Result<Vec<TypeRef>> {
+        let mut items = vec![self.ty()?];
+        while self.eat(',') {
+            if self.eat(close) {
+                return Ok(items);
+            }
+            items.push(self.ty()?);
+        }
+        self.expect(close)?;
+        Ok(items)
+    }
+}
+
+/// Recognize the standard wrappers among named types.
+fn shape(name: String, mut args: Vec<TypeRef>) -> TypeRef {
+    let last = name.rsplit("::").next().unwrap_or(&name);
+    match (last, args.len()) {
+        ("Option", 1) => TypeRef::Optional { inner: Box::new(args.remove(0)) },
+        ("Vec" | "VecDeque" | "HashSet" | "BTreeSet" | "IndexSet", 1) => TypeRef::Collection {
+            name: last.to_string(),
+            item: Box::new(args.remove(0)),
+        },
+        ("List" | "Array" | "Seq" | "Collection", 1) => TypeRef::Collection {
+            name: "Vec".into(),
+            item: Box::new(args.remove(0)),
+        },
+        ("Set", 1) => TypeRef::Collection { name: "HashSet".into(), item: Box::new(args.remove(0)) },
+        ("HashMap" | "BTreeMap" | "IndexMap" | "Map", 2) => {
+            let value = args.remove(1);
+            TypeRef::Map {
+                name: if last == "Map" { "HashMap".into() } else { last.to_string() },
+                key: Box::new(args.remove(0)),
+                value: Box::new(value),
+            }
+        }
+        _ => TypeRef::Named { name, args },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> TypeRef {
+        TypeRef::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        assert_eq!(parse("Option<Vec<LineItem>>").to_string(), "Option<Vec<LineItem>>");
+        assert_eq!(parse("HashMap<String,Money>").to_string(), "HashMap<String, Money>");
+        assert_eq!(parse("chrono::DateTime<chrono::Utc>").to_string(), "chrono::DateTime<chrono::Utc>");
+        assert_eq!(parse("Result<(), DomainError>").to_string(), "Result<(), DomainError>");
+        assert_eq!(parse("&'a str").to_string(), "str");
+    }
+
+    #[test]
+    fn test_other_language_spellings_normalize() {
+        assert_eq!(parse("Email?"), parse("Option<Email>"));
+        assert_eq!(parse("LineItem[]").to_string(), "Vec<LineItem>");
+        assert_eq!(parse("List<String>"), parse("Vec<String>"));
+        assert_eq!(parse("Map<String, i32>").to_string(), "HashMap<String, i32>");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(TypeRef::parse("Vec<String").is_err());
+        assert!(TypeRef::parse("Vec<>").is_err());
+        assert!(TypeRef::parse("").is_err());
+        assert!(TypeRef::parse("String>").is_err());
+    }
+
+    #[test]
+    fn test_classify_change() {
+        assert_eq!(classify_change("Email", "Option<Email>"), Some(TypeChange::MadeOptional));
+        assert_eq!(classify_change("Email?", "Email"), Some(TypeChange::MadeRequired));
+        assert_eq!(classify_change("Option<Email>", "Email?"), None);
+        assert_eq!(classify_change("i32", "Option<i64>"), Some(TypeChange::Changed));
+    }
+
+    #[test]
+    fn test_references_resolve_model_elements() {
+        let mut m = DomainModel::empty("/tmp/shop");
+        m.bounded_contexts.push(BoundedContext {
+            name: "Sales".into(),
+            description: "".into(),
+            module_path: "".into(),
+            entities: vec![Entity {
+                name: "LineItem".into(),
+                description: "".into(),
+                aggregate_root: false,
+                fields: vec![],
+                methods: vec![],
+                invariants: vec![],
+                state_machine: None,
+                meta: Metadata::default(),
+            }],
+            value_objects: vec![ValueObject {
+                name: "Email".into(),
+                description: "".into(),
+                fields: vec![],
+                validation_rules: vec![],
+                meta: Metadata::default(),
+            }],
+            enumerations: vec![],
+            aggregates: vec![],
+            commands: vec![],
+            queries: vec![],
+            use_cases: vec![],
+            policies: vec![],
+            glossary: vec![],
+            services: vec![],
+            repositories: vec![],
+            events: vec![],
+            dependencies: vec![],
+            meta: Metadata::default(),
+        });
+        let refs = parse("HashMap<String, Vec<LineItem>>").references(&m, "Sales");
+        assert_eq!(refs.len(), 1);
+        assert_eq!((refs[0].kind, refs[0].many, refs[0].optional), ("entity", true, false));
+
+        let refs = parse("Option<Email>").references(&m, "Other");
+        assert_eq!((refs[0].kind, refs[0].context.as_str(), refs[0].optional), ("value_object", "Sales", true));
+    }
+}
@@ -0,0 +1,426 @@
+use serde::{Deserialize, Serialize};
+
+use super::model::*;
+use super::naming;
+use super::registry::DomainRegistry;
+use super::{to_pascal, to_snake};
+
+/// A problem that does not make the model invalid but should be looked at.
+/// Structural errors are rejected by `DomainModel::validate` instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Finding {
+    pub severity: Severity,
+    /// Dotted path of the offending element, e.g. `Billing.ClientAddress.street`
+    pub path: String,
+    pub message: String,
+}
+
+/// Run every model check and collect the findings.
+pub fn check(model: &DomainModel) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for bc in &model.bounded_contexts {
+        forbidden_aliases(bc, &mut findings);
+        field_types(model, bc, &mut findings);
+        naming_conventions(model, bc, &mut findings);
+    }
+    deprecated_usages(model, &mut findings);
+    homonyms(model, &mut findings);
+    findings
+}
+
+/// Element names that break the naming convention of their kind.
+fn naming_conventions(model: &DomainModel, bc: &BoundedContext, findings: &mut Vec<Finding>) {
+    for (kind, name, _) in bc.elements() {
+        let Some(rule) = model.conventions.naming.for_kind(kind) else {
+            continue;
+        };
+        let problems = naming::problems(rule, name);
+        if problems.is_empty() {
+            continue;
+        }
+        let hint = naming::suggest(rule, name).map(|s| format!(" (e.g. '{s}')")).unwrap_or_default();
+        findings.push(Finding {
+            severity: Severity::Warning,
+            path: format!("{}.{name}", bc.name),
+            message: format!(
+                "'{name}' breaks the {} naming convention: it {}{hint}",
+                kind.replace('_', " "),
+                problems.join(", ")
+            ),
+        });
+    }
+}
+
+/// Element names defined in more than one context. They may be intentional — each
+/// context has its own model — but lookups then need a qualified name.
+fn homonyms(model: &DomainModel, findings: &mut Vec<Finding>) {
+    // (name as first declared, [(context, kind)]) — one entry per context
+    let mut seen: Vec<(&str, Vec<(&str, &str)>)> = Vec::new();
+    for bc in &model.bounded_contexts {
+        for (kind, name, _) in bc.elements() {
+            match seen.iter_mut().find(|(n, _)| n.eq_ignore_ascii_case(name)) {
+                Some((_, defs)) if defs.iter().any(|(ctx, _)| *ctx == bc.name) => {}
+                Some((_, defs)) => defs.push((&bc.name, kind)),
+                None => seen.push((name, vec![(&bc.name, kind)])),
+            }
+        }
+    }
+    for (name, defs) in seen.into_iter().filter(|(_, defs)| defs.len() > 1) {
+        let places: Vec<_> = defs.iter().map(|(ctx, kind)| format!("{ctx} ({})", kind.replace('_', " "))).collect();
+        findings.push(Finding {
+            severity: Severity::Info,
+            path: format!("{}.{name}", defs[0].0),
+            message: format!(
+                "'{name}' is defined in {} — rename one if they are different concepts; lookups need a qualified name such as '{}::{name}'",
+                places.join(" and "),
+                defs[0].0
+            ),
+        });
+    }
+}
+
+/// Elements that still use a deprecated element, unless they are deprecated themselves.
+fn deprecated_usages(model: &DomainModel, findings: &mut Vec<Finding>) {
+    let registry = DomainRegistry::new(model);
+    let deprecated = model.deprecated();
+    let is_deprecated = |context: &str, name: &str| {
+        deprecated.iter().any(|(c, _, n, _)| c.eq_ignore_ascii_case(context) && n.eq_ignore_ascii_case(name))
+    };
+    for (context, kind, name, meta) in &deprecated {
+        if *kind == "bounded_context" {
+            continue;
+        }
+        let Some(warning) = meta.deprecation_warning(name) else {
+            continue;
+        };
+        for usage in registry.usages(context, name) {
+            if is_deprecated(&usage.context, &usage.element) {
+                continue;
+            }
+            findings.push(Finding {
+                severity: Severity::Warning,
+                path: format!("{}.{}", usage.context, usage.element),
+                message: format!("{warning}, but '{}' still uses it ({})", usage.element, usage.via),
+            });
+        }
+    }
+}
+
+/// Flag element and member names that contain a word the context's glossary forbids.
+fn forbidden_aliases(bc: &BoundedContext, findings: &mut Vec<Finding>) {
+    if bc.glossary.iter().all(|t| t.forbidden_aliases.is_empty()) {
+        return;
+    }
+    for (path, name) in element_names(bc) {
+        for term in &bc.glossary {
+            if let Some(alias) = term.forbidden_aliases.iter().find(|a| uses_word(&name, a)) {
+                findings.push(Finding {
+                    severity: Severity::Warning,
+                    path: format!("{}.{}", bc.name, path),
+                    message: format!(
+                        "'{}' uses '{}', which the '{}' glossary forbids — say '{}'",
+                        name, alias, bc.name, term.term
+                    ),
+                });
+            }
+        }
+    }
+}
+
+/// Field types domcp cannot parse, fields marked required but typed `Option<…>`, and
+/// entity fields that hold another aggregate root instead of its id (aggregates
+/// reference each other by identity).
+fn field_types(model: &DomainModel, bc: &BoundedContext, findings: &mut Vec<Finding>) {
+    let owners = bc
+        .entities
+        .iter()
+        .map(|e| (&e.name, &e.fields, true))
+        .chain(bc.value_objects.iter().map(|v| (&v.name, &v.fields, true)))
+        .chain(bc.events.iter().map(|e| (&e.name, &e.fields, false)))
+        .chain(OperationKind::ALL.into_iter().flat_map(|k| bc.operations(k)).map(|o| (&o.name, &o.fields, false)));
+    for (owner, fields, holds_state) in owners {
+        let aggregate = bc.aggregate_of(owner).map(|(_, root)| root).unwrap_or(owner.as_str());
+        for field in fields {
+            let path = format!("{}.{}.{}", bc.name, owner, field.name);
+            let ty = match field.type_ref() {
+                Ok(ty) => ty,
+                Err(e) => {
+                    findings.push(Finding {
+                        severity: Severity::Warning,
+                        path,
+                        message: format!(
+                            "'{}' has a type '{}' that cannot be parsed ({e}) — it is compared as text and not linked to model elements",
+                            field.name, field.field_type
+                        ),
+                    });
+                    continue;
+                }
+            };
+            if field.required && ty.is_optional() {
+                findings.push(Finding {
+                    severity: Severity::Warning,
+                    path: path.clone(),
+                    message: format!("'{}' is marked required but its type '{}' is optional", field.name, field.field_type),
+                });
+            }
+            if !holds_state {
+                continue;
+            }
+            for target in ty.references(model, &bc.name).into_iter().filter(|r| r.kind == "entity") {
+                let Some(target_bc) = model.bounded_contexts.iter().find(|c| c.name == target.context) else {
+                    continue;
+                };
+                if target_bc.is_aggregate_root(&target.name) && !target.name.eq_ignore_ascii_case(aggregate) {
+                    findings.push(Finding {
+                        severity: Severity::Warning,
+                        path: path.clone(),
+                        message: format!(
+                            "'{}' holds the aggregate root '{}' — reference it by id ({}Id) instead",
+                            field.name, target.name, target.name
+                        ),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Every named element of the context with its path relative to the context.
+fn element_names(bc: &BoundedContext) -> Vec<(String, String)> {
+    let mut names = Vec::new();
+    let mut add = |owner: &str, members: &mut dyn Iterator<Item = &str>| {
+        names.push((owner.to_string(), owner.to_string()));
+        for member in members {
+            names.push((format!("{owner}.{member}"), member.to_string()));
+        }
+    };
+
+    for e in &bc.entities {
+        add(&e.name, &mut e.fields.iter().map(|f| f.name.as_str()).chain(e.methods.iter().map(|m| m.name.as_str())));
+    }
+    for vo in &bc.value_objects {
+        add(&vo.name, &mut vo.fields.iter().map(|f| f.name.as_str()));
+    }
+    for en in &bc.enumerations {
+        add(&en.name, &mut en.variants.iter().map(|v| v.name.as_str()));
+    }
+    for agg in &bc.aggregates {
+        add(&agg.name, &mut std::iter::empty());
+    }
+    for kind in OperationKind::ALL {
+        for op in bc.operations(kind) {
+            add(&op.name, &mut op.fields.iter().map(|f| f.name.as_str()));
+        }
+    }
+    for policy in &bc.policies {
+        add(&policy.name, &mut std::iter::empty());
+    }
+    for svc in &bc.services {
+        add(&svc.name, &mut svc.methods.iter().map(|m| m.name.as_str()));
+    }
+    for repo in &bc.repositories {
+        add(&repo.name, &mut std::iter::empty());
+    }
+    for event in &bc.events {
+        add(&event.name, &mut event.fields.iter().map(|f| f.name.as_str()));
+    }
+    names
+}
+
+/// Whether `name` contains `phrase` as whole words, in any casing style:
+/// `ClientAddress`, `client_id` and `Clients` all use "client".
+fn uses_word(name: &str, phrase: &str) -> bool {
+    let words = |s: &str| -> Vec<String> { to_snake(&to_pascal(s)).split('_').map(String::from).collect() };
+    let name = words(name);
+    let phrase = words(phrase);
+    let Some((last, init)) = phrase.split_last() else {
+        return false;
+    };
+    name.windows(phrase.len()).any(|w| {
+        w[..init.len()] == *init && (w[init.len()] == *last || w[init.len()] == format!("{last}s"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn billing() -> DomainModel {
+        let mut m = DomainModel::empty("/tmp/shop");
+        m.bounded_contexts.push(BoundedContext {
+            name: "Billing".into(),
+            description: "".into(),
+            module_path: "".into(),
+            entities: vec![Entity {
+                name: "ClientAccount".into(),
+                description: "".into(),
+                aggregate_root: true,
+                fields: vec![Field {
+                    name: "client_id".into(),
+                    field_type: "ClientId".into(),
+                    required: true,
+                    description: "".into(),
+                }],
+                methods: vec![],
+                invariants: vec![],
+                state_machine: None,
+                meta: Metadata::default(),
+            }],
+            value_objects: vec![],
+            enumerations: vec![],
+            aggregates: vec![],
+            commands: vec![],
+            queries: vec![],
+            use_cases: vec![],
+            policies: vec![],
+            glossary: vec![Term {
+                term: "Customer".into(),
+                definition: "Someone who pays for a subscription".into(),
+                synonyms: vec![],
+                forbidden_aliases: vec!["Client".into()],
+            }],
+            services: vec![],
+            repositories: vec![],
+            events: vec![],
+            dependencies: vec![],
+            meta: Metadata::default(),
+        });
+        m
+    }
+
+    #[test]
+    fn test_forbidden_alias_in_names() {
+        let findings = check(&billing());
+        let paths: Vec<_> = findings.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["Billing.ClientAccount", "Billing.ClientAccount.client_id"]);
+        assert!(findings[0].message.contains("say 'Customer'"));
+    }
+
+    #[test]
+    fn test_uses_word_matches_whole_words() {
+        assert!(uses_word("ClientAddress", "client"));
+        assert!(uses_word("ListClients", "Client"));
+        assert!(uses_word("customer_account_id", "Customer Account"));
+        assert!(!uses_word("ClientelePortal", "Client"));
+        assert!(!uses_word("CustomerAccount", "Account Customer"));
+    }
+
+    #[test]
+    fn test_field_type_findings() {
+        let mut m = billing();
+        m.bounded_contexts[0].glossary.clear();
+        let mut invoice = m.bounded_contexts[0].entities[0].clone();
+        invoice.name = "Invoice".into();
+        invoice.fields = vec![
+            Field {
+                name: "payer".into(),
+                field_type: "ClientAccount".into(),
+                required: true,
+                description: "".into(),
+            },
+            Field {
+                name: "note".into(),
+                field_type: "Option<String>".into(),
+                required: true,
+                description: "".into(),
+            },
+            Field {
+                name: "hash".into(),
+                field_type: "[u8; 32]".into(),
+                required: true,
+                description: "".into(),
+            },
+        ];
+        m.bounded_contexts[0].entities.push(invoice);
+        let messages: Vec<_> = check(&m).into_iter().map(|f| f.message).collect();
+        assert_eq!(messages.len(), 3);
+        assert!(messages[0].contains("reference it by id (ClientAccountId)"));
+        assert!(messages[1].contains("marked required but its type 'Option<String>' is optional"));
+        assert!(messages[2].starts_with("'hash' has a type '[u8; 32]' that cannot be parsed"));
+    }
+
+    #[test]
+    fn test_other_context_glossary_does_not_apply() {
+        let mut m = billing();
+        let mut identity = m.bounded_contexts[0].clone();
+        identity.name = "Identity".into();
+        identity.glossary.clear();
+        m.bounded_contexts[0].entities.clear();
+        m.bounded_contexts.push(identity);
+        assert!(check(&m).is_empty());
+    }
+
+    #[test]
+    fn test_homonyms_across_contexts() {
+        let mut m = billing();
+        m.bounded_contexts[0].glossary.clear();
+        let mut identity = m.bounded_contexts[0].clone();
+        identity.name = "Identity".into();
+        m.bounded_contexts.push(identity);
+        let findings = check(&m);
+        assert_eq!(findings.len(), 1);
+        assert!(matches!(findings[0].severity, Severity::Info));
+        assert_eq!(findings[0].path, "Billing.ClientAccount");
+        assert!(findings[0].message.starts_with("'ClientAccount' is defined in Billing (entity) and Identity (entity)"));
+        assert!(findings[0].message.ends_with("'Billing::ClientAccount'"));
+    }
+
+    #[test]
+    fn test_naming_convention_violations() {
+        let mut m = billing();
+        m.bounded_contexts[0].glossary.clear();
+        m.bounded_contexts[0].entities[0].name = "client_account".into();
+        m.conventions.naming.entities = NamingRule {
+            case: Some(CaseStyle::Pascal),
+            prefix: "".into(),
+            suffix: "".into(),
+            pattern: "".into(),
+            tense: None,
+            description: "PascalCase, singular noun".into(),
+        };
+        m.conventions.naming.events = "Past tense".into();
+        let findings = check(&m);
+        assert_eq!(findings.len(), 1);
+        assert!(matches!(findings[0].severity, Severity::Warning));
+        assert_eq!(findings[0].path, "Billing.client_account");
+        assert_eq!(
+            findings[0].message,
+            "'client_account' breaks the entity naming convention: it is not in PascalCase (e.g. 'ClientAccount')"
+        );
+    }
+
+    #[test]
+    fn test_usages_of_deprecated_elements() {
+        let mut m = billing();
+        m.bounded_contexts[0].glossary.clear();
+        let bc = &mut m.bounded_contexts[0];
+        let mut customer = bc.entities[0].clone();
+        customer.name = "Customer".into();
+        customer.fields.clear();
+        bc.entities.push(customer);
+        bc.entities[0].meta.status = Status::Deprecated;
+        bc.entities[0].meta.replaced_by = "Customer".into();
+        bc.events.push(DomainEvent {
+            name: "AccountOpened".into(),
+            description: "".into(),
+            fields: vec![Field {
+                name: "account".into(),
+                field_type: "ClientAccount".into(),
+                required: true,
+                description: "".into(),
+            }],
+            source: "".into(),
+            published: false,
+            channel: "".into(),
+            meta: Metadata::default(),
+        });
+        m.validate().unwrap();
+        let findings = check(&m);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].path, "Billing.AccountOpened");
+        assert_eq!(
+            findings[0].message,
+            "'ClientAccount' is deprecated — use 'Customer' instead, but 'AccountOpened' still uses it (field 'account')"
+        );
+    }
+}
@@ -0,0 +1,426 @@
+use super::{quote, Writer};
+use crate::domain::model::*;
+use crate::domain::types::TypeRef;
+use crate::domain::{to_pascal, to_snake};
+
+/// Render the model as Context Mapper CML.
+///
+/// Each aggregate root becomes an `Aggregate` holding the root entity, its
+/// repositories, its member entities and value objects, and the events they
+/// emit. Remaining entities, value objects, events and domain/infrastructure
+/// services go into a module named after the context; application services go
+/// into the context's `Application` layer.
+/// Context dependencies become upstream/downstream relationships in the context map.
+pub fn render(model: &DomainModel) -> String {
+    let mut w = Writer::default();
+
+    if !model.bounded_contexts.is_empty() {
+        let names: Vec<&str> = model.bounded_contexts.iter().map(|bc| bc.name.as_str()).collect();
+        w.open(format!("ContextMap {}Map", to_pascal(&model.name)));
+        w.line(format!("contains {}", names.join(", ")));
+        for bc in &model.bounded_contexts {
+            for dep in &bc.dependencies {
+                if let Some(upstream) = names.iter().find(|n| n.eq_ignore_ascii_case(dep)) {
+                    w.line(format!("{upstream} [U]->[D] {}", bc.name));
+                }
+            }
+        }
+        w.close();
+    }
+
+    for bc in &model.bounded_contexts {
+        w.blank();
+        render_context(&mut w, bc);
+    }
+
+    w.out
+}
+
+fn render_context(w: &mut Writer, bc: &BoundedContext) {
+    w.open(format!("BoundedContext {}", bc.name));
+    if !bc.description.is_empty() {
+        w.line(format!("domainVisionStatement = {}", quote(&bc.description)));
+    }
+
+    let app_services: Vec<&Service> = bc
+        .services
+        .iter()
+        .filter(|s| matches!(s.kind, ServiceKind::Application))
+        .collect();
+    if !app_services.is_empty() {
+        w.open("Application");
+        for svc in app_services {
+            render_service(w, bc, svc);
+        }
+        w.close();
+    }
+
+    let roots: Vec<&Entity> = bc.entities.iter().filter(|e| bc.is_aggregate_root(&e.name)).collect();
+    let in_aggregate = |name: &str| bc.aggregate_of(name).is_some();
+
+    for root in &roots {
+        let explicit = bc.aggregates.iter().find(|a| a.root.eq_ignore_ascii_case(&root.name));
+        match explicit {
+            Some(agg) => w.open(format!("{}Aggregate {}", doc(&agg.description), agg.name)),
+            None => w.open(format!("Aggregate {}", root.name)),
+        }
+        let repositories: Vec<&Repository> =
+            bc.repositories.iter().filter(|r| r.aggregate == root.name).collect();
+        render_entity(w, bc, root, &repositories);
+        if let Some(agg) = explicit {
+            for member in bc.entities.iter().filter(|e| agg.entities.iter().any(|m| m.eq_ignore_ascii_case(&e.name))) {
+                render_entity(w, bc, member, &[]);
+            }
+            for vo in bc.value_objects.iter().filter(|v| agg.value_objects.iter().any(|m| m.eq_ignore_ascii_case(&v.name))) {
+                render_value_object(w, bc, vo);
+            }
+        }
+        let sourced_here = |source: &str| bc.aggregate_of(source).is_some_and(|(_, r)| r == root.name);
+        for evt in bc.events.iter().filter(|e| sourced_here(&e.source)) {
+            render_event(w, bc, evt);
+        }
+        w.close();
+    }
+
+    let entities: Vec<&Entity> = bc.entities.iter().filter(|e| !in_aggregate(&e.name)).collect();
+    let value_objects: Vec<&ValueObject> = bc.value_objects.iter().filter(|v| !in_aggregate(&v.name)).collect();
+    let events: Vec<&DomainEvent> = bc.events.iter().filter(|e| !in_aggregate(&e.source)).collect();
+    let services: Vec<&Service> = bc
+        .services
+        .iter()
+        .filter(|s| !matches!(s.kind, ServiceKind::Application))
+        .collect();
+    if !entities.is_empty()
+        || !value_objects.is_empty()
+        || !bc.enumerations.is_empty()
+        || !events.is_empty()
+        || !services.is_empty()
+    {
+        w.open(format!("Module {}", to_snake(&bc.name)));
+        for entity in entities {
+            render_entity(w, bc, entity, &[]);
+        }
+        for vo in value_objects {
+            render_value_object(w, bc, vo);
+        }
+        for en in &bc.enumerations {
+            render_enumeration(w, en);
+        }
+        for evt in events {
+            render_event(w, bc, evt);
+        }
+        for svc in services {
+            render_service(w, bc, svc);
+        }
+        w.close();
+    }
+
+    // Repositories live inside their aggregate root; keep orphans visible
+    for repo in bc.repositories.iter().filter(|r| !bc.is_aggregate_root(&r.aggregate)) {
+        w.line(format!("// Repository {} manages '{}', which is not an aggregate root", repo.name, repo.aggregate));
+    }
+
+    w.close();
+}
+
+fn render_entity(w: &mut Writer, bc: &BoundedContext, entity: &Entity, repositories: &[&Repository]) {
+    w.open(format!("{}Entity {}", doc(&entity.description), entity.name));
+    if bc.is_aggregate_root(&entity.name) {
+        w.line("aggregateRoot");
+    }
+    render_fields(w, bc, &entity.fields);
+    for m in &entity.methods {
+        w.line(format!("def {};", operation(bc, m)));
+    }
+    for repo in repositories {
+        w.open(format!("Repository {}", repo.name));
+        for m in &repo.methods {
+            w.line(format!("{};", operation(bc, m)));
+        }
+        w.close();
+    }
+    w.close();
+}
+
+fn render_value_object(w: &mut Writer, bc: &BoundedContext, vo: &ValueObject) {
+    w.open(format!("{}ValueObject {}", doc(&vo.description), vo.name));
+    render_fields(w, bc, &vo.fields);
+    w.close();
+}
+
+fn render_enumeration(w: &mut Writer, en: &Enumeration) {
+    w.open(format!("{}enum {}", doc(&en.description), en.name));
+    let variants: Vec<String> = en
+        .variants
+        .iter()
+        .map(|v| format!("{}{}", doc(&v.description), v.name))
+        .collect();
+    w.line(variants.join(", "));
+    w.close();
+}
+
+fn render_event(w: &mut Writer, bc: &BoundedContext, evt: &DomainEvent) {
+    w.open(format!("{}DomainEvent {}", doc(&evt.description), evt.name));
+    render_fields(w, bc, &evt.fields);
+    w.close();
+}
+
+fn render_service(w: &mut Writer, bc: &BoundedContext, svc: &Service) {
+    w.open(format!("{}Service {}", doc(&svc.description), svc.name));
+    for m in &svc.methods {
+        w.line(format!("{};", operation(bc, m)));
+    }
+    w.close();
+}
+
+fn render_fields(w: &mut Writer, bc: &BoundedContext, fields: &[Field]) {
+    for f in fields {
+        let (ty, optional) = cml_type(&f.field_type);
+        let reference = if references_domain_object(bc, &ty) { "- " } else { "" };
+        let nullable = if optional || !f.required { " nullable" } else { "" };
+        w.line(format!("{reference}{ty} {}{nullable}", attribute_name(&f.name)));
+    }
+}
+
+fn operation(bc: &BoundedContext, m: &Method) -> String {
+    let params: Vec<String> = m
+        .parameters
+        .iter()
+        .map(|p| {
+            let (ty, _) = cml_type(&p.field_type);
+            let ty = if references_domain_object(bc, &ty) { format!("@{ty}") } else { ty };
+            format!("{ty} {}", p.name)
+        })
+        .collect();
+    format!("{} {}({})", return_type(&m.return_type), m.name, params.join(", "))
+}
+
+/// Rust return type → CML: `Result<T, E>` unwraps to `T`, unit becomes `void`.
+fn return_type(ty: &str) -> String {
+    if ty.trim().is_empty() {
+        return "void".into();
+    }
+    let Ok(parsed) = TypeRef::parse(ty) else {
+        return ty.trim().to_string();
+    };
+    let inner = match parsed {
+        TypeRef::Named { name, mut args } if last_segment(&name) == "Result" && !args.is_empty() => args.remove(0),
+        other => other,
+    };
+    match inner {
+        TypeRef::Tuple { items } if items.is_empty() => "void".into(),
+        other => cml_ref(&other),
+    }
+}
+
+/// Rust field type → CML type, plus whether it was optional.
+/// A type that does not parse is written as it is.
+fn cml_type(ty: &str) -> (String, bool) {
+    match TypeRef::parse(ty) {
+        Ok(TypeRef::Optional { inner }) => (cml_ref(&inner), true),
+        Ok(parsed) => (cml_ref(&parsed), false),
+        Err(_) => (ty.trim().to_string(), false),
+    }
+}
+
+fn cml_ref(ty: &TypeRef) -> String {
+    match ty {
+        TypeRef::Optional { inner } => cml_ref(inner),
+        TypeRef::Collection { name, item } if name.ends_with("Set") => format!("Set<{}>", cml_ref(item)),
+        TypeRef::Collection { item, .. } => format!("List<{}>", cml_ref(item)),
+        TypeRef::Map { key, value, .. } => format!("Map<{}, {}>", cml_ref(key), cml_ref(value)),
+        // CML has no tuples
+        TypeRef::Tuple { .. } => "Object".into(),
+        TypeRef::Named { name, args } => {
+            let mapped = match last_segment(name) {
+                "String" | "str" => "String",
+                "i8" | "i16" | "u8" | "u16" => "short",
+                "i32" | "u32" => "int",
+                "i64" | "u64" | "usize" | "isize" => "long",
+                "f32" => "float",
+                "f64" => "double",
+                "bool" => "boolean",
+                "Decimal" => "BigDecimal",
+                "DateTime" | "NaiveDateTime" => "DateTime",
+                "NaiveDate" => "Date",
+                "Uuid" => "UUID",
+                "Value" => "Object",
+                "Box" | "Arc" | "Rc" if args.len() == 1 => return cml_ref(&args[0]),
+                // Path-qualified types keep their last segment
+                other => other,
+            };
+            mapped.to_string()
+        }
+    }
+}
+
+fn last_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// CML names must not start with a digit: tuple struct fields `0`, `1` become `_0`, `_1`.
+fn attribute_name(name: &str) -> String {
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+fn references_domain_object(bc: &BoundedContext, ty: &str) -> bool {
+    let base = ty
+        .strip_prefix("List<")
+        .and_then(|t| t.strip_suffix('>'))
+        .unwrap_or(ty);
+    bc.entities.iter().any(|e| e.name == base) || bc.value_objects.iter().any(|v| v.name == base)
+}
+
+/// Sculptor-style doc string prefix for an element, if it has a description.
+fn doc(description: &str) -> String {
+    if description.is_empty() {
+        String::new()
+    } else {
+        format!("{} ", quote(description))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> DomainModel {
+        let mut m = DomainModel::empty("/tmp/shop");
+        m.bounded_contexts.push(BoundedContext {
+            name: "Identity".into(),
+            description: "Users and \"auth\"".into(),
+            module_path: "src/identity".into(),
+            entities: vec![Entity {
+                name: "User".into(),
+                description: "".into(),
+                aggregate_root: true,
+                fields: vec![
+                    Field {
+                        name: "email".into(),
+                        field_type: "Email".into(),
+                        required: true,
+                        description: "".into(),
+                    },
+                    Field {
+                        name: "last_login".into(),
+                        field_type: "Option<DateTime<Utc>>".into(),
+                        required: false,
+                        description: "".into(),
+                    },
+                ],
+                methods: vec![Method {
+                    name: "change_email".into(),
+                    description: "".into(),
+                    parameters: vec![Field {
+                        name: "email".into(),
+                        field_type: "Email".into(),
+                        required: true,
+                        description: "".into(),
+                    }],
+                    return_type: "Result<()>".into(),
+                }],
+                invariants: vec![],
+                state_machine: None,
+                meta: Metadata::default(),
+            }],
+            value_objects: vec![ValueObject {
+                name: "Email".into(),
+                description: "".into(),
+                fields: vec![Field {
+                    name: "value".into(),
+                    field_type: "String".into(),
+                    required: true,
+                    description: "".into(),
+                }],
+                validation_rules: vec![],
+                meta: Metadata::default(),
+            }],
+            enumerations: vec![],
+            aggregates: vec![],
+            commands: vec![],
+            queries: vec![],
+            use_cases: vec![],
+            policies: vec![],
+            glossary: vec![],
+            services: vec![],
+            repositories: vec![Repository {
+                name: "UserRepository".into(),
+                aggregate: "User".into(),
+                methods: vec![],
+                meta: Metadata::default(),
+            }],
+            events: vec![DomainEvent {
+                name: "UserRegistered".into(),
+                description: "".into(),
+                fields: vec![],
+                source: "User".into(),
+                published: false,
+                channel: "".into(),
+                meta: Metadata::default(),
+            }],
+            dependencies: vec![],
+            meta: Metadata::default(),
+        });
+        m.bounded_contexts.push(BoundedContext {
+            name: "Billing".into(),
+            description: "".into(),
+            module_path: "src/billing".into(),
+            entities: vec![],
+            value_objects: vec![],
+            enumerations: vec![],
+            aggregates: vec![],
+            commands: vec![],
+            queries: vec![],
+            use_cases: vec![],
+            policies: vec![],
+            glossary: vec![],
+            services: vec![],
+            repositories: vec![],
+            events: vec![],
+            dependencies: vec!["Identity".into()],
+            meta: Metadata::default(),
+        });
+        m
+    }
+
+    #[test]
+    fn test_context_map_relationships() {
+        let cml = render(&model());
+        assert!(cml.contains("ContextMap ShopMap {"));
+        assert!(cml.contains("contains Identity, Billing"));
+        assert!(cml.contains("Identity [U]->[D] Billing"));
+    }
+
+    #[test]
+    fn test_aggregate_holds_root_repository_and_events() {
+        let cml = render(&model());
+        assert!(cml.contains("domainVisionStatement = \"Users and \\\"auth\\\"\""));
+        assert!(cml.contains("    Aggregate User {\n        Entity User {\n            aggregateRoot"));
+        assert!(cml.contains("- Email email\n"));
+        assert!(cml.contains("DateTime last_login nullable"));
+        assert!(cml.contains("def void change_email(@Email email);"));
+        assert!(cml.contains("Repository UserRepository {"));
+        assert!(cml.contains("DomainEvent UserRegistered {"));
+        assert!(cml.contains("Module identity {\n        ValueObject Email {"));
+    }
+
+    #[test]
+    fn test_type_mapping() {
+        assert_eq!(cml_type("Vec<Uuid>"), ("List<UUID>".into(), false));
+        assert_eq!(cml_type("Option<i64>"), ("long".into(), true));
+        assert_eq!(return_type("Result<Vec<User>>"), "List<User>");
+        assert_eq!(return_type("Result<HashMap<String, i32>, DomainError>"), "Map<String, int>");
+        assert_eq!(return_type("Result<(), DomainError>"), "void");
+        assert_eq!(return_type(""), "void");
+        assert_eq!(
+            cml_type("Option<HashMap<(String, i32), Vec<Option<chrono::DateTime<chrono::Utc>>>>>"),
+            ("Map<Object, List<DateTime>>".into(), true)
+        );
+        assert_eq!(cml_type("HashSet<Email>"), ("Set<Email>".into(), false));
+        assert_eq!(cml_type("[u8; 32]"), ("[u8; 32]".into(), false));
+    }
+}
@@ -0,0 +1,55 @@
+pub mod cml;
+pub mod structurizr;
+
+use anyhow::Result;
+
+use crate::domain::model::DomainModel;
+
+/// Render a domain model in the given export format.
+pub fn render(model: &DomainModel, format: &str) -> Result<String> {
+    match format {
+        "json" => Ok(serde_json::to_string_pretty(model)?),
+        "cml" => Ok(cml::render(model)),
+        "structurizr" => Ok(structurizr::render(model)),
+        other => anyhow::bail!(
+            "Unsupported export format '{other}'. Supported: json, cml, structurizr"
+        ),
+    }
+}
+
+/// Indentation-aware line writer shared by the DSL exporters.
+#[derive(Default)]
+struct Writer {
+    out: String,
+    depth: usize,
+}
+
+impl Writer {
+    fn line(&mut self, s: impl AsRef<str>) {
+        for _ in 0..self.depth {
+            self.out.push_str("    ");
+        }
+        self.out.push_str(s.as_ref());
+        self.out.push('\n');
+    }
+
+    fn blank(&mut self) {
+        self.out.push('\n');
+    }
+
+    fn open(&mut self, header: impl AsRef<str>) {
+        self.line(format!("{} {{", header.as_ref()));
+        self.depth += 1;
+    }
+
+    fn close(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        self.line("}");
+    }
+}
+
+/// Single-line double-quoted string literal (valid in CML and Structurizr DSL).
+fn quote(s: &str) -> String {
+    let escaped = s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', " ");
+    format!("\"{escaped}\"")
+}
@@ -0,0 +1,297 @@
+use super::{quote, Writer};
+use crate::domain::model::*;
+use crate::domain::to_snake;
+
+/// Render the model as a Structurizr DSL workspace.
+///
+/// The project is one software system; each bounded context is a container and
+/// each service or repository a component of it. Context dependencies become
+/// container relationships, service dependencies that name another service or
+/// repository become component relationships. External systems are separate
+/// software systems tagged `External`, used by their adapter component or, without
+/// one, by the integrating container. Views: a system context view when there are
+/// external systems, one container view for the system and one component view per context.
+pub fn render(model: &DomainModel) -> String {
+    let mut w = Writer::default();
+    let technology = [&model.tech_stack.language, &model.tech_stack.framework]
+        .iter()
+        .filter(|t| !t.is_empty())
+        .map(|t| t.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    w.open(format!("workspace {} {}", quote(&model.name), quote(&model.description)));
+    w.open("model");
+    w.open(format!("system = softwareSystem {} {}", quote(&model.name), quote(&model.description)));
+    for bc in &model.bounded_contexts {
+        w.open(format!(
+            "{} = container {} {} {}",
+            container_id(&bc.name),
+            quote(&bc.name),
+            quote(&bc.description),
+            quote(&technology)
+        ));
+        for svc in &bc.services {
+            let kind = match svc.kind {
+                ServiceKind::Domain => "Domain service",
+                ServiceKind::Application => "Application service",
+                ServiceKind::Infrastructure => "Infrastructure service",
+            };
+            w.line(format!(
+                "{} = component {} {} {}",
+                component_id(&bc.name, &svc.name),
+                quote(&svc.name),
+                quote(&svc.description),
+                quote(kind)
+            ));
+        }
+        for repo in &bc.repositories {
+            w.line(format!(
+                "{} = component {} {} {}",
+                component_id(&bc.name, &repo.name),
+                quote(&repo.name),
+                quote(&format!("Persists the {} aggregate", repo.aggregate)),
+                quote("Repository")
+            ));
+        }
+        w.close();
+    }
+    w.close();
+    for system in &model.external_systems {
+        w.open(format!(
+            "{} = softwareSystem {} {}",
+            external_id(&system.name),
+            quote(&system.name),
+            quote(&system.description)
+        ));
+        w.line("tags \"External\"");
+        w.close();
+    }
+
+    // Relationships
+    for bc in &model.bounded_contexts {
+        for dep in &bc.dependencies {
+            if let Some(target) = find_context(model, dep) {
+                w.line(format!(
+                    "{} -> {} \"Depends on\"",
+                    container_id(&bc.name),
+                    container_id(&target.name)
+                ));
+            }
+        }
+        for svc in &bc.services {
+            for dep in &svc.dependencies {
+                if let Some(target) = find_component(model, bc, dep) {
+                    w.line(format!("{} -> {} \"Uses\"", component_id(&bc.name, &svc.name), target));
+                }
+            }
+        }
+    }
+    for system in &model.external_systems {
+        for integration in &system.integrations {
+            let Some(bc) = find_context(model, &integration.context) else {
+                continue;
+            };
+            let source = match bc.services.iter().find(|s| s.name.eq_ignore_ascii_case(&integration.adapter)) {
+                Some(adapter) => component_id(&bc.name, &adapter.name),
+                None => container_id(&bc.name),
+            };
+            w.line(format!(
+                "{} -> {} \"Calls\" {}",
+                source,
+                external_id(&system.name),
+                quote(&system.protocol)
+            ));
+        }
+    }
+    w.close();
+
+    w.open("views");
+    if !model.external_systems.is_empty() {
+        w.open("systemContext system \"Context\"");
+        w.line("include *");
+        w.line("autoLayout");
+        w.close();
+    }
+    w.open("container system \"Containers\"");
+    w.line("include *");
+    w.line("autoLayout");
+    w.close();
+    for bc in &model.bounded_contexts {
+        if bc.services.is_empty() && bc.repositories.is_empty() {
+            continue;
+        }
+        w.open(format!("component {} {}", container_id(&bc.name), quote(&bc.name)));
+        w.line("include *");
+        w.line("autoLayout");
+        w.close();
+    }
+    if !model.external_systems.is_empty() {
+        w.open("styles");
+        w.open("element \"External\"");
+        w.line("background #999999");
+        w.line("color #ffffff");
+        w.close();
+        w.close();
+    }
+    w.close();
+
+    w.close();
+    w.out
+}
+
+fn find_context<'a>(model: &'a DomainModel, name: &str) -> Option<&'a BoundedContext> {
+    model
+        .bounded_contexts
+        .iter()
+        .find(|bc| bc.name.eq_ignore_ascii_case(name))
+}
+
+/// Resolve a service dependency to a component id: same context first, then any context.
+fn find_component(model: &DomainModel, ctx: &BoundedContext, name: &str) -> Option<String> {
+    std::iter::once(ctx)
+        .chain(model.bounded_contexts.iter())
+        .find_map(|bc| {
+            bc.services
+                .iter()
+                .map(|s| &s.name)
+                .chain(bc.repositories.iter().map(|r| &r.name))
+                .find(|n| n.eq_ignore_ascii_case(name))
+                .map(|n| component_id(&bc.name, n))
+        })
+}
+
+fn container_id(context: &str) -> String {
+    to_snake(context).replace([' ', '-'], "_")
+}
+
+fn external_id(system: &str) -> String {
+    format!("ext_{}", container_id(system))
+}
+
+fn component_id(context: &str, name: &str) -> String {
+    format!("{}_{}", container_id(context), to_snake(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service(name: &str, kind: ServiceKind, deps: &[&str]) -> Service {
+        Service {
+            name: name.into(),
+            description: "".into(),
+            kind,
+            methods: vec![],
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            meta: Metadata::default(),
+        }
+    }
+
+    fn model() -> DomainModel {
+        let mut m = DomainModel::empty("/tmp/shop");
+        m.tech_stack.language = "Rust".into();
+        m.tech_stack.framework = "Axum".into();
+        m.bounded_contexts.push(BoundedContext {
+            name: "Identity".into(),
+            description: "Users".into(),
+            module_path: "src/identity".into(),
+            entities: vec![],
+            value_objects: vec![],
+            enumerations: vec![],
+            aggregates: vec![],
+            commands: vec![],
+            queries: vec![],
+            use_cases: vec![],
+            policies: vec![],
+            glossary: vec![],
+            services: vec![service(
+                "RegistrationService",
+                ServiceKind::Application,
+                &["UserRepository"],
+            )],
+            repositories: vec![Repository {
+                name: "UserRepository".into(),
+                aggregate: "User".into(),
+                methods: vec![],
+                meta: Metadata::default(),
+            }],
+            events: vec![],
+            dependencies: vec![],
+            meta: Metadata::default(),
+        });
+        m.bounded_contexts.push(BoundedContext {
+            name: "Billing".into(),
+            description: "".into(),
+            module_path: "src/billing".into(),
+            entities: vec![],
+            value_objects: vec![],
+            enumerations: vec![],
+            aggregates: vec![],
+            commands: vec![],
+            queries: vec![],
+            use_cases: vec![],
+            policies: vec![],
+            glossary: vec![],
+            services: vec![service("InvoiceService", ServiceKind::Domain, &["RegistrationService"])],
+            repositories: vec![],
+            events: vec![],
+            dependencies: vec!["Identity".into()],
+            meta: Metadata::default(),
+        });
+        m
+    }
+
+    #[test]
+    fn test_contexts_become_containers_with_components() {
+        let dsl = render(&model());
+        assert!(dsl.contains("identity = container \"Identity\" \"Users\" \"Rust, Axum\" {"));
+        assert!(dsl.contains(
+            "identity_registration_service = component \"RegistrationService\" \"\" \"Application service\""
+        ));
+        assert!(dsl.contains("identity_user_repository = component \"UserRepository\""));
+    }
+
+    #[test]
+    fn test_dependencies_become_relationships() {
+        let dsl = render(&model());
+        assert!(dsl.contains("billing -> identity \"Depends on\""));
+        assert!(dsl.contains("identity_registration_service -> identity_user_repository \"Uses\""));
+        assert!(dsl.contains("billing_invoice_service -> identity_registration_service \"Uses\""));
+    }
+
+    #[test]
+    fn test_views_skip_contexts_without_components() {
+        let mut m = model();
+        m.bounded_contexts[1].services.clear();
+        let dsl = render(&m);
+        assert!(dsl.contains("container system \"Containers\" {"));
+        assert!(dsl.contains("component identity \"Identity\" {"));
+        assert!(!dsl.contains("component billing"));
+    }
+
+    #[test]
+    fn test_external_systems_are_boundary_nodes() {
+        let mut m = model();
+        m.external_systems.push(ExternalSystem {
+            name: "Stripe".into(),
+            description: "Card payments".into(),
+            protocol: "REST".into(),
+            integrations: vec![
+                Integration {
+                    context: "Billing".into(),
+                    adapter: "InvoiceService".into(),
+                },
+                Integration {
+                    context: "Identity".into(),
+                    adapter: "".into(),
+                },
+            ],
+        });
+        let dsl = render(&m);
+        assert!(dsl.contains("ext_stripe = softwareSystem \"Stripe\" \"Card payments\" {\n            tags \"External\""));
+        assert!(dsl.contains("billing_invoice_service -> ext_stripe \"Calls\" \"REST\""));
+        assert!(dsl.contains("identity -> ext_stripe \"Calls\" \"REST\""));
+        assert!(dsl.contains("systemContext system \"Context\" {"));
+    }
+}
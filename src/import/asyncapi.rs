@@ -0,0 +1,280 @@
+use anyhow::Result;
+use serde_json::Value;
+
+use super::schema::{fields_of, ref_name, resolve, str_of};
+use crate::domain::model::*;
+use crate::domain::{to_pascal, to_snake};
+
+/// Build a draft bounded context holding the domain events described by an
+/// AsyncAPI 2.x or 3.x document.
+///
+/// Only messages the application *sends* become events (2.x `subscribe`,
+/// 3.x `action: send`); messages it merely receives belong to the publishing
+/// context. Imported events are marked `published` and keep their channel address.
+/// `source` is taken from `x-domcp-source` or a `source:<Entity>` message tag.
+pub fn import(spec: &Value, context: &str) -> Result<BoundedContext> {
+    let version = spec.get("asyncapi").and_then(|v| v.as_str()).unwrap_or("");
+    let sent = if version.starts_with('2') {
+        sent_messages_v2(spec)
+    } else if version.starts_with('3') {
+        sent_messages_v3(spec)
+    } else {
+        anyhow::bail!(
+            "Unsupported AsyncAPI version '{}': only AsyncAPI 2.x and 3.x documents can be imported",
+            if version.is_empty() { "missing" } else { version }
+        );
+    };
+
+    // A pure message catalogue without operations: import every component message
+    let sent = if sent.is_empty() {
+        spec.pointer("/components/messages")
+            .and_then(|v| v.as_object())
+            .map(|messages| {
+                messages
+                    .iter()
+                    .map(|(key, msg)| (key.clone(), msg.clone(), String::new()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        sent
+    };
+
+    let mut bc = BoundedContext {
+        name: context.to_string(),
+        description: spec
+            .pointer("/info/description")
+            .or_else(|| spec.pointer("/info/title"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        module_path: format!("src/{}", to_snake(context)),
+        ..Default::default()
+    };
+
+    for (key, message, channel) in sent {
+        let message = resolve(spec, &message);
+        let event = import_message(spec, &key, message, &channel);
+        if !bc.events.iter().any(|e| e.name == event.name) {
+            bc.events.push(event);
+        }
+    }
+
+    Ok(bc)
+}
+
+fn import_message(spec: &Value, key: &str, message: &Value, channel: &str) -> DomainEvent {
+    let name = message
+        .get("name")
+        .or_else(|| message.get("messageId"))
+        .and_then(|v| v.as_str())
+        .map(to_pascal)
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| to_pascal(key));
+
+    // 3.x multi-format payloads wrap the schema: { schemaFormat, schema }
+    let payload = message.get("payload").map(|p| resolve(spec, p));
+    let payload = match payload {
+        Some(p) if p.get("schemaFormat").is_some() => p.get("schema"),
+        other => other,
+    };
+
+    let source = message
+        .get("x-domcp-source")
+        .and_then(|v| v.as_str())
+        .map(String::from)
+        .or_else(|| {
+            message
+                .get("tags")
+                .and_then(|t| t.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|t| resolve(spec, t).get("name").and_then(|n| n.as_str()))
+                .find_map(|t| t.strip_prefix("source:").map(|s| s.trim().to_string()))
+        })
+        .unwrap_or_default();
+
+    DomainEvent {
+        name,
+        description: ["summary", "description", "title"]
+            .iter()
+            .map(|k| str_of(message, k))
+            .find(|d| !d.is_empty())
+            .unwrap_or_default(),
+        fields: payload.map(|p| fields_of(spec, p)).unwrap_or_default(),
+        source,
+        published: true,
+        channel: channel.to_string(),
+        meta: Metadata::default(),
+    }
+}
+
+/// (message key, message, channel address) for every message under a 2.x `subscribe` operation.
+fn sent_messages_v2(spec: &Value) -> Vec<(String, Value, String)> {
+    let mut sent = Vec::new();
+    let Some(channels) = spec.get("channels").and_then(|v| v.as_object()) else {
+        return sent;
+    };
+    for (address, channel) in channels {
+        let Some(message) = channel.pointer("/subscribe/message") else {
+            continue;
+        };
+        let variants = match message.get("oneOf").and_then(|v| v.as_array()) {
+            Some(one_of) => one_of.clone(),
+            None => vec![message.clone()],
+        };
+        for msg in variants {
+            sent.push((message_key(&msg, address), msg, address.clone()));
+        }
+    }
+    sent
+}
+
+/// (message key, message, channel address) for every message of a 3.x `send` operation.
+fn sent_messages_v3(spec: &Value) -> Vec<(String, Value, String)> {
+    let mut sent = Vec::new();
+    let Some(operations) = spec.get("operations").and_then(|v| v.as_object()) else {
+        return sent;
+    };
+    for op in operations.values() {
+        let op = resolve(spec, op);
+        if op.get("action").and_then(|v| v.as_str()) != Some("send") {
+            continue;
+        }
+        let Some(channel_ref) = op.get("channel") else {
+            continue;
+        };
+        let channel_key = channel_ref
+            .get("$ref")
+            .and_then(|v| v.as_str())
+            .map(ref_name)
+            .unwrap_or_default();
+        let channel = resolve(spec, channel_ref);
+        let address = channel
+            .get("address")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .unwrap_or(channel_key);
+
+        let messages: Vec<Value> = match op.get("messages").and_then(|v| v.as_array()) {
+            Some(listed) => listed.clone(),
+            None => channel
+                .get("messages")
+                .and_then(|v| v.as_object())
+                .map(|m| m.values().cloned().collect())
+                .unwrap_or_default(),
+        };
+        for msg in messages {
+            // Operation messages point at the channel's message, which points at the component
+            let msg = resolve(spec, &msg).clone();
+            sent.push((message_key(&msg, &address), msg, address.clone()));
+        }
+    }
+    sent
+}
+
+fn message_key(msg: &Value, fallback: &str) -> String {
+    match msg.get("$ref").and_then(|v| v.as_str()) {
+        Some(r) => ref_name(r),
+        None => fallback.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn components() -> Value {
+        json!({
+            "messages": {
+                "UserRegistered": {
+                    "summary": "A user signed up",
+                    "tags": [{ "name": "source:User" }],
+                    "payload": { "$ref": "#/components/schemas/UserRegisteredPayload" }
+                },
+                "PasswordResetRequested": {
+                    "payload": { "type": "object", "properties": { "email": { "type": "string" } } }
+                }
+            },
+            "schemas": {
+                "UserRegisteredPayload": {
+                    "type": "object",
+                    "required": ["userId"],
+                    "properties": {
+                        "userId": { "type": "string", "format": "uuid" },
+                        "registeredAt": { "type": "string", "format": "date-time" }
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_v2_subscribe_messages_become_published_events() {
+        let spec = json!({
+            "asyncapi": "2.6.0",
+            "channels": {
+                "identity.user.registered": {
+                    "subscribe": { "message": { "$ref": "#/components/messages/UserRegistered" } }
+                },
+                "identity.password.reset": {
+                    "publish": { "message": { "$ref": "#/components/messages/PasswordResetRequested" } }
+                }
+            },
+            "components": components()
+        });
+        let bc = import(&spec, "Identity").unwrap();
+        assert_eq!(bc.events.len(), 1);
+        let evt = &bc.events[0];
+        assert_eq!(evt.name, "UserRegistered");
+        assert_eq!(evt.source, "User");
+        assert!(evt.published);
+        assert_eq!(evt.channel, "identity.user.registered");
+        assert_eq!(evt.description, "A user signed up");
+        let user_id = evt.fields.iter().find(|f| f.name == "user_id").unwrap();
+        assert_eq!(user_id.field_type, "Uuid");
+        assert!(user_id.required);
+    }
+
+    #[test]
+    fn test_v3_send_operations_resolve_channel_address() {
+        let spec = json!({
+            "asyncapi": "3.0.0",
+            "channels": {
+                "userRegistered": {
+                    "address": "identity.user.registered",
+                    "messages": {
+                        "UserRegistered": { "$ref": "#/components/messages/UserRegistered" }
+                    }
+                }
+            },
+            "operations": {
+                "publishUserRegistered": {
+                    "action": "send",
+                    "channel": { "$ref": "#/channels/userRegistered" },
+                    "messages": [{ "$ref": "#/channels/userRegistered/messages/UserRegistered" }]
+                }
+            },
+            "components": components()
+        });
+        let bc = import(&spec, "Identity").unwrap();
+        assert_eq!(bc.events.len(), 1);
+        assert_eq!(bc.events[0].name, "UserRegistered");
+        assert_eq!(bc.events[0].channel, "identity.user.registered");
+        assert_eq!(bc.events[0].fields.len(), 2);
+    }
+
+    #[test]
+    fn test_catalogue_without_operations_imports_all_messages() {
+        let spec = json!({ "asyncapi": "3.0.0", "components": components() });
+        let bc = import(&spec, "Identity").unwrap();
+        assert_eq!(bc.events.len(), 2);
+        assert!(bc.events.iter().all(|e| e.published));
+    }
+
+    #[test]
+    fn test_rejects_missing_version() {
+        assert!(import(&json!({}), "Identity").is_err());
+    }
+}
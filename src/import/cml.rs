@@ -0,0 +1,785 @@
+use anyhow::Result;
+
+use crate::domain::model::*;
+use crate::domain::to_snake;
+
+/// Build bounded contexts from a Context Mapper CML document.
+///
+/// Reads `BoundedContext` blocks with their aggregates, modules and
+/// `Application` layer: `Entity` (with `aggregateRoot`), `ValueObject`,
+/// `DomainEvent`, `Service` and `Repository` elements, their attributes and
+/// operations. Aggregates with child entities or value objects become explicit
+/// aggregates; events declared in an aggregate are sourced from its root.
+/// `A [U]->[D] B` / `B [D]<-[U] A` relationships in the context map become
+/// dependencies of the downstream context. Other CML constructs are skipped.
+pub fn import(source: &str) -> Result<Vec<BoundedContext>> {
+    let mut p = Parser {
+        toks: tokenize(source)?,
+        pos: 0,
+    };
+    let mut contexts = Vec::new();
+    let mut relationships = Vec::new();
+
+    while let Some(tok) = p.next() {
+        match tok {
+            Tok::Ident(kw) if kw == "ContextMap" => {
+                p.skip_until_block();
+                if p.eat_sym('{') {
+                    relationships.extend(p.context_map());
+                }
+            }
+            Tok::Ident(kw) if kw == "BoundedContext" => {
+                let name = p.ident()?;
+                p.skip_until_block();
+                let mut bc = BoundedContext {
+                    name: name.clone(),
+                    description: String::new(),
+                    module_path: format!("src/{}", to_snake(&name)),
+                    ..Default::default()
+                };
+                if p.eat_sym('{') {
+                    p.context_body(&mut bc, Scope::Context)?;
+                }
+                contexts.push(bc);
+            }
+            // Domains, subdomains, use cases, stakeholders, ...
+            Tok::Ident(_) => {
+                p.skip_until_block();
+                if p.eat_sym('{') {
+                    p.skip_block();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for (upstream, downstream) in relationships {
+        if let Some(bc) = contexts.iter_mut().find(|bc| bc.name == downstream) {
+            if !bc.dependencies.contains(&upstream) {
+                bc.dependencies.push(upstream);
+            }
+        }
+    }
+
+    Ok(contexts)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Str(String),
+    Sym(char),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Scope {
+    Context,
+    Application,
+    /// Inside an aggregate; events and repositories attach to its root
+    Aggregate,
+}
+
+struct Parser {
+    toks: Vec<Tok>,
+    pos: usize,
+}
+
+/// Top-level keywords that end a header without a body.
+const TOP_LEVEL: &[&str] = &["ContextMap", "BoundedContext", "Domain", "Subdomain", "UseCase", "UserStory"];
+
+/// Attribute flags that can follow `Type name`.
+const ATTRIBUTE_FLAGS: &[&str] = &[
+    "nullable", "key", "required", "notChanged", "unique", "index", "transient", "cache",
+    "changeable",
+];
+
+impl Parser {
+    fn peek(&self) -> Option<&Tok> {
+        self.toks.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Tok> {
+        self.toks.get(self.pos + offset)
+    }
+
+    fn next(&mut self) -> Option<Tok> {
+        let tok = self.toks.get(self.pos).cloned();
+        self.pos += 1;
+        tok
+    }
+
+    fn eat_sym(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Tok::Sym(c)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn ident(&mut self) -> Result<String> {
+        match self.next() {
+            Some(Tok::Ident(s)) => Ok(s),
+            other => anyhow::bail!("Expected a name in CML, found {:?}", other),
+        }
+    }
+
+    /// Skip header tokens (`implements X`, `extends Y`, ...) up to the next `{`.
+    /// Stops early at a top-level keyword or `}` for elements without a body.
+    fn skip_until_block(&mut self) {
+        while let Some(tok) = self.peek() {
+            match tok {
+                Tok::Sym('{') | Tok::Sym('}') => return,
+                Tok::Ident(kw) if TOP_LEVEL.contains(&kw.as_str()) => return,
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    /// Skip to the `}` matching an already consumed `{`.
+    fn skip_block(&mut self) {
+        let mut depth = 1;
+        while let Some(tok) = self.next() {
+            match tok {
+                Tok::Sym('{') => depth += 1,
+                Tok::Sym('}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// (upstream, downstream) pairs of a context map body.
+    fn context_map(&mut self) -> Vec<(String, String)> {
+        let mut pairs = Vec::new();
+        while let Some(tok) = self.next() {
+            match tok {
+                Tok::Sym('}') => break,
+                Tok::Sym('{') => self.skip_block(),
+                Tok::Ident(kw) if kw == "contains" => {
+                    // Context names separated by commas
+                    while matches!(self.peek(), Some(Tok::Ident(_))) {
+                        self.pos += 1;
+                        if !self.eat_sym(',') {
+                            break;
+                        }
+                    }
+                }
+                Tok::Ident(left) => {
+                    // Arrow notation: A [U]->[D] B, B [D]<-[U] A
+                    let mut arrow = String::new();
+                    while let Some(Tok::Sym(c)) = self.peek().cloned() {
+                        if c == '{' || c == '}' {
+                            break;
+                        }
+                        self.pos += 1;
+                        if c == '[' {
+                            // Relationship roles such as [U,OHS,PL]
+                            while let Some(t) = self.next() {
+                                if t == Tok::Sym(']') {
+                                    break;
+                                }
+                            }
+                        } else {
+                            arrow.push(c);
+                        }
+                    }
+                    if let Some(Tok::Ident(right)) = self.peek().cloned() {
+                        match arrow.as_str() {
+                            "->" => pairs.push((left, right)),
+                            "<-" => pairs.push((right, left)),
+                            _ => continue,
+                        }
+                        self.pos += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+        pairs
+    }
+
+    /// Body of a bounded context, aggregate, module or application layer.
+    fn context_body(&mut self, bc: &mut BoundedContext, scope: Scope) -> Result<()> {
+        let mut doc = String::new();
+        let mut root: Option<String> = None;
+        let mut pending_events: Vec<usize> = Vec::new();
+        let mut pending_repos: Vec<usize> = Vec::new();
+
+        while let Some(tok) = self.next() {
+            let keyword = match tok {
+                Tok::Sym('}') => break,
+                Tok::Str(s) => {
+                    doc = s;
+                    continue;
+                }
+                Tok::Ident(kw) => kw,
+                _ => continue,
+            };
+
+            match keyword.as_str() {
+                "domainVisionStatement" if scope == Scope::Context => {
+                    self.eat_sym('=');
+                    if let Some(Tok::Str(s)) = self.next() {
+                        bc.description = s;
+                    }
+                }
+                "Aggregate" | "Module" | "Application" => {
+                    let name = match self.peek() {
+                        Some(Tok::Ident(n)) => n.clone(),
+                        _ => String::new(),
+                    };
+                    if !name.is_empty() {
+                        self.pos += 1;
+                    }
+                    self.skip_until_block();
+                    if self.eat_sym('{') {
+                        let inner = match keyword.as_str() {
+                            "Aggregate" => Scope::Aggregate,
+                            "Application" => Scope::Application,
+                            _ => scope,
+                        };
+                        let (entities_before, vos_before) = (bc.entities.len(), bc.value_objects.len());
+                        self.context_body(bc, inner)?;
+                        let description = std::mem::take(&mut doc);
+                        if keyword == "Aggregate" {
+                            record_aggregate(bc, name, description, entities_before, vos_before);
+                        }
+                    }
+                }
+                "Entity" => {
+                    let name = self.ident()?;
+                    self.skip_until_block();
+                    self.eat_sym('{');
+                    let mut entity = Entity {
+                        name: name.clone(),
+                        description: std::mem::take(&mut doc),
+                        aggregate_root: false,
+                        fields: vec![],
+                        methods: vec![],
+                        invariants: vec![],
+                        state_machine: None,
+                        meta: Metadata::default(),
+                    };
+                    let repos = self.object_body(&mut entity.fields, &mut entity.methods, &mut entity.aggregate_root)?;
+                    for mut repo in repos {
+                        repo.aggregate = name.clone();
+                        bc.repositories.push(repo);
+                    }
+                    if entity.aggregate_root {
+                        root = Some(name);
+                    }
+                    bc.entities.push(entity);
+                }
+                "ValueObject" | "DomainEvent" | "CommandEvent" => {
+                    let name = self.ident()?;
+                    self.skip_until_block();
+                    self.eat_sym('{');
+                    let (mut fields, mut methods, mut root_flag) = (Vec::new(), Vec::new(), false);
+                    self.object_body(&mut fields, &mut methods, &mut root_flag)?;
+                    let description = std::mem::take(&mut doc);
+                    if keyword == "ValueObject" {
+                        bc.value_objects.push(ValueObject {
+                            name,
+                            description,
+                            fields,
+                            validation_rules: vec![],
+                            meta: Metadata::default(),
+                        });
+                    } else {
+                        if scope == Scope::Aggregate {
+                            pending_events.push(bc.events.len());
+                        }
+                        bc.events.push(DomainEvent {
+                            name,
+                            description,
+                            fields,
+                            source: String::new(),
+                            published: false,
+                            channel: String::new(),
+                            meta: Metadata::default(),
+                        });
+                    }
+                }
+                "enum" => {
+                    let name = self.ident()?;
+                    self.skip_until_block();
+                    self.eat_sym('{');
+                    bc.enumerations.push(Enumeration {
+                        name,
+                        description: std::mem::take(&mut doc),
+                        variants: self.variants(),
+                        meta: Metadata::default(),
+                    });
+                }
+                "Service" => {
+                    let name = self.ident()?;
+                    self.skip_until_block();
+                    self.eat_sym('{');
+                    bc.services.push(Service {
+                        name,
+                        description: std::mem::take(&mut doc),
+                        kind: if scope == Scope::Application {
+                            ServiceKind::Application
+                        } else {
+                            ServiceKind::Domain
+                        },
+                        methods: self.operations()?,
+                        dependencies: vec![],
+                        meta: Metadata::default(),
+                    });
+                }
+                "Repository" => {
+                    let name = self.ident()?;
+                    self.skip_until_block();
+                    self.eat_sym('{');
+                    pending_repos.push(bc.repositories.len());
+                    bc.repositories.push(Repository {
+                        name,
+                        aggregate: String::new(),
+                        methods: self.operations()?,
+                        meta: Metadata::default(),
+                    });
+                }
+                _ => {
+                    // Unknown setting (`type = X`) or element (`Enum Foo { ... }`)
+                    if self.eat_sym('=') {
+                        self.pos += 1;
+                        while self.eat_sym(',') {
+                            self.pos += 1;
+                        }
+                    } else {
+                        self.skip_until_block();
+                        if self.eat_sym('{') {
+                            self.skip_block();
+                        }
+                    }
+                    doc.clear();
+                }
+            }
+        }
+
+        if let Some(root) = &root {
+            for i in pending_events {
+                bc.events[i].source = root.clone();
+            }
+            for i in pending_repos {
+                bc.repositories[i].aggregate = root.clone();
+            }
+        }
+        Ok(())
+    }
+
+    /// Comma-separated enum values up to the closing brace. Options (`ordinal`,
+    /// `aggregateLifecycle`) and value arguments are skipped.
+    fn variants(&mut self) -> Vec<Variant> {
+        let mut variants = Vec::new();
+        let mut doc = String::new();
+        while let Some(tok) = self.next() {
+            match tok {
+                Tok::Sym('}') => break,
+                Tok::Sym('(') => while !matches!(self.next(), Some(Tok::Sym(')')) | None) {},
+                Tok::Str(s) => doc = s,
+                Tok::Ident(id) if id == "ordinal" || id == "aggregateLifecycle" => {}
+                Tok::Ident(name) => variants.push(Variant {
+                    name,
+                    description: std::mem::take(&mut doc),
+                }),
+                _ => {}
+            }
+        }
+        variants
+    }
+
+    /// Attributes, `def` operations and nested repositories of a domain object.
+    fn object_body(
+        &mut self,
+        fields: &mut Vec<Field>,
+        methods: &mut Vec<Method>,
+        aggregate_root: &mut bool,
+    ) -> Result<Vec<Repository>> {
+        let mut repositories = Vec::new();
+        let mut doc = String::new();
+        while let Some(tok) = self.peek().cloned() {
+            match tok {
+                Tok::Sym('}') => {
+                    self.pos += 1;
+                    break;
+                }
+                Tok::Str(s) => {
+                    doc = s;
+                    self.pos += 1;
+                }
+                Tok::Sym('-') => {
+                    // Reference attribute: - Type name
+                    self.pos += 1;
+                }
+                Tok::Ident(kw) if kw == "aggregateRoot" => {
+                    *aggregate_root = true;
+                    self.pos += 1;
+                }
+                Tok::Ident(kw) if kw == "def" => {
+                    self.pos += 1;
+                    let mut method = self.operation()?;
+                    method.description = std::mem::take(&mut doc);
+                    methods.push(method);
+                }
+                Tok::Ident(kw) if kw == "Repository" => {
+                    self.pos += 1;
+                    let name = self.ident()?;
+                    self.skip_until_block();
+                    self.eat_sym('{');
+                    repositories.push(Repository {
+                        name,
+                        aggregate: String::new(),
+                        methods: self.operations()?,
+                        meta: Metadata::default(),
+                    });
+                }
+                Tok::Ident(_) => {
+                    let field_type = self.type_ref()?;
+                    let name = self.ident()?;
+                    // Exported tuple struct fields: _0 → 0
+                    let name = match name.strip_prefix('_') {
+                        Some(index) if index.chars().all(|c| c.is_ascii_digit()) => index.to_string(),
+                        _ => name,
+                    };
+                    let mut nullable = false;
+                    loop {
+                        match self.peek() {
+                            Some(Tok::Ident(flag)) if ATTRIBUTE_FLAGS.contains(&flag.as_str()) => {
+                                nullable |= flag == "nullable";
+                                self.pos += 1;
+                            }
+                            Some(Tok::Sym('!')) => self.pos += 2,
+                            // hint = "...", length = "..."
+                            Some(Tok::Ident(_)) if self.peek_at(1) == Some(&Tok::Sym('=')) => self.pos += 3,
+                            _ => break,
+                        }
+                    }
+                    fields.push(Field {
+                        name,
+                        field_type,
+                        required: !nullable,
+                        description: std::mem::take(&mut doc),
+                    });
+                }
+                _ => self.pos += 1,
+            }
+        }
+        Ok(repositories)
+    }
+
+    /// Operations of a service or repository body, up to the closing `}`.
+    fn operations(&mut self) -> Result<Vec<Method>> {
+        let mut methods = Vec::new();
+        let mut doc = String::new();
+        while let Some(tok) = self.peek().cloned() {
+            match tok {
+                Tok::Sym('}') => {
+                    self.pos += 1;
+                    break;
+                }
+                Tok::Str(s) => {
+                    doc = s;
+                    self.pos += 1;
+                }
+                Tok::Ident(kw) if kw == "def" => self.pos += 1,
+                // Bare repository operation: `findById;`
+                Tok::Ident(name) if self.peek_at(1) == Some(&Tok::Sym(';')) => {
+                    self.pos += 2;
+                    methods.push(Method {
+                        name,
+                        description: std::mem::take(&mut doc),
+                        parameters: vec![],
+                        return_type: String::new(),
+                    });
+                }
+                Tok::Ident(_) => {
+                    let mut method = self.operation()?;
+                    method.description = std::mem::take(&mut doc);
+                    methods.push(method);
+                }
+                _ => self.pos += 1,
+            }
+        }
+        Ok(methods)
+    }
+
+    /// `ReturnType name(Type param, ...)` followed by anything up to `;`.
+    fn operation(&mut self) -> Result<Method> {
+        let return_type = self.type_ref()?;
+        let name = self.ident()?;
+        let mut parameters = Vec::new();
+        if self.eat_sym('(') {
+            while !self.eat_sym(')') {
+                if self.eat_sym(',') || self.eat_sym('-') {
+                    continue;
+                }
+                let field_type = self.type_ref()?;
+                let name = match self.peek() {
+                    Some(Tok::Ident(_)) => self.ident()?,
+                    _ => to_snake(&field_type),
+                };
+                parameters.push(Field {
+                    name,
+                    field_type,
+                    required: true,
+                    description: String::new(),
+                });
+            }
+        }
+        // throws, hints, ...
+        while let Some(tok) = self.peek() {
+            match tok {
+                Tok::Sym(';') => {
+                    self.pos += 1;
+                    break;
+                }
+                Tok::Sym('}') => break,
+                _ => self.pos += 1,
+            }
+        }
+        Ok(Method {
+            name,
+            description: String::new(),
+            parameters,
+            return_type: if return_type == "()" { String::new() } else { return_type },
+        })
+    }
+
+    /// A possibly generic type such as `List<@Customer>`, mapped to a Rust type.
+    fn type_ref(&mut self) -> Result<String> {
+        self.eat_sym('@');
+        let name = self.ident()?;
+        let mut args = Vec::new();
+        if self.eat_sym('<') {
+            while !self.eat_sym('>') {
+                if self.eat_sym(',') {
+                    continue;
+                }
+                args.push(self.type_ref()?);
+            }
+        }
+        Ok(rust_type(&name, &args))
+    }
+}
+
+/// Record an explicit aggregate for the elements declared since the given offsets.
+/// Single-entity aggregates named after their root stay implicit.
+fn record_aggregate(bc: &mut BoundedContext, name: String, description: String, entities_from: usize, vos_from: usize) {
+    let declared = &bc.entities[entities_from..];
+    let Some(root) = declared.iter().find(|e| e.aggregate_root).map(|e| e.name.clone()) else {
+        return;
+    };
+    let entities: Vec<String> = declared
+        .iter()
+        .filter(|e| !e.aggregate_root)
+        .map(|e| e.name.clone())
+        .collect();
+    let value_objects: Vec<String> = bc.value_objects[vos_from..].iter().map(|v| v.name.clone()).collect();
+    if entities.is_empty() && value_objects.is_empty() && name == root {
+        return;
+    }
+    bc.aggregates.push(Aggregate {
+        name,
+        description,
+        root,
+        entities,
+        value_objects,
+        meta: Metadata::default(),
+    });
+}
+
+/// CML / Sculptor type → Rust type. Unknown names are kept as domain types.
+fn rust_type(name: &str, args: &[String]) -> String {
+    match (name, args) {
+        ("List" | "Set" | "Collection" | "Bag", [inner]) => format!("Vec<{inner}>"),
+        ("Map", [k, v]) => format!("HashMap<{k}, {v}>"),
+        ("void", _) => "()".into(),
+        ("String", _) => "String".into(),
+        ("int" | "Integer", _) => "i32".into(),
+        ("long" | "Long", _) => "i64".into(),
+        ("short" | "Short", _) => "i16".into(),
+        ("float" | "Float", _) => "f32".into(),
+        ("double" | "Double", _) => "f64".into(),
+        ("boolean" | "Boolean", _) => "bool".into(),
+        ("BigDecimal", _) => "Decimal".into(),
+        ("DateTime" | "Timestamp", _) => "DateTime<Utc>".into(),
+        ("Date", _) => "NaiveDate".into(),
+        ("UUID", _) => "Uuid".into(),
+        ("Object", _) => "serde_json::Value".into(),
+        (other, []) => other.to_string(),
+        (other, args) => format!("{other}<{}>", args.join(", ")),
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Tok>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut toks = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i += 2;
+        } else if c == '"' {
+            let mut s = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => anyhow::bail!("Unterminated string in CML"),
+                    Some('"') => break,
+                    Some('\\') => {
+                        if let Some(&escaped) = chars.get(i + 1) {
+                            s.push(escaped);
+                        }
+                        i += 2;
+                    }
+                    Some(&ch) => {
+                        s.push(ch);
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+            toks.push(Tok::Str(s));
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.') {
+                i += 1;
+            }
+            toks.push(Tok::Ident(chars[start..i].iter().collect()));
+        } else {
+            toks.push(Tok::Sym(c));
+            i += 1;
+        }
+    }
+    Ok(toks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CML: &str = r#"
+        ContextMap ShopMap {
+            contains Identity, Billing
+            Identity [U,OHS]->[D,ACL] Billing {
+                implementationTechnology = "REST"
+            }
+        }
+
+        /* Users and authentication */
+        BoundedContext Identity implements IdentityDomain {
+            domainVisionStatement = "Manages users"
+            Application {
+                Service RegistrationService {
+                    void register(String email);
+                }
+            }
+            Aggregate Users {
+                "A registered user"
+                Entity User {
+                    aggregateRoot
+                    - Email email
+                    DateTime lastLogin nullable
+                    - List<Role> roles
+                    def void changeEmail(@Email email);
+                    Repository UserRepository {
+                        @User findByEmail(@Email email);
+                        findAll;
+                    }
+                }
+                DomainEvent UserRegistered {
+                    UUID userId
+                }
+                ValueObject Email {
+                    String value key
+                }
+            }
+            Module accounts {
+                "Lifecycle of an account"
+                enum UserStatus {
+                    aggregateLifecycle
+                    ACTIVE, "Temporarily locked" SUSPENDED, DELETED("deleted")
+                }
+            }
+        }
+
+        BoundedContext Billing
+    "#;
+
+    #[test]
+    fn test_contexts_and_relationships() {
+        let contexts = import(CML).unwrap();
+        assert_eq!(contexts.len(), 2);
+        assert_eq!(contexts[0].description, "Manages users");
+        assert_eq!(contexts[1].name, "Billing");
+        assert_eq!(contexts[1].dependencies, vec!["Identity"]);
+        assert!(contexts[0].dependencies.is_empty());
+    }
+
+    #[test]
+    fn test_aggregate_contents() {
+        let identity = &import(CML).unwrap()[0];
+        let user = &identity.entities[0];
+        assert!(user.aggregate_root);
+        assert_eq!(user.description, "A registered user");
+        assert_eq!(user.fields.len(), 3);
+        assert_eq!(user.fields[1].field_type, "DateTime<Utc>");
+        assert!(!user.fields[1].required);
+        assert_eq!(user.fields[2].field_type, "Vec<Role>");
+        assert_eq!(user.methods[0].name, "changeEmail");
+        assert_eq!(user.methods[0].parameters[0].field_type, "Email");
+
+        assert_eq!(identity.repositories[0].aggregate, "User");
+        assert_eq!(identity.repositories[0].methods.len(), 2);
+        assert_eq!(identity.events[0].source, "User");
+        assert_eq!(identity.events[0].fields[0].field_type, "Uuid");
+        assert_eq!(identity.value_objects[0].fields[0].name, "value");
+        assert_eq!(identity.aggregates[0].name, "Users");
+        assert_eq!(identity.aggregates[0].root, "User");
+        assert_eq!(identity.aggregates[0].value_objects, vec!["Email"]);
+        assert!(matches!(identity.services[0].kind, ServiceKind::Application));
+
+        let status = &identity.enumerations[0];
+        assert_eq!(status.description, "Lifecycle of an account");
+        let names: Vec<_> = status.variants.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["ACTIVE", "SUSPENDED", "DELETED"]);
+        assert_eq!(status.variants[1].description, "Temporarily locked");
+    }
+
+    #[test]
+    fn test_round_trip_through_exporter() {
+        let contexts = import(CML).unwrap();
+        let mut model = DomainModel::empty("/tmp/shop");
+        model.bounded_contexts = contexts;
+        let again = import(&crate::export::cml::render(&model)).unwrap();
+        assert_eq!(again.len(), 2);
+        assert_eq!(again[1].dependencies, vec!["Identity"]);
+        assert_eq!(again[0].entities[0].fields.len(), 3);
+        assert_eq!(again[0].events[0].source, "User");
+        assert_eq!(again[0].repositories[0].aggregate, "User");
+        assert_eq!(again[0].aggregates[0].value_objects, vec!["Email"]);
+        assert_eq!(again[0].enumerations, model.bounded_contexts[0].enumerations);
+    }
+
+    #[test]
+    fn test_unterminated_string_is_an_error() {
+        assert!(import("BoundedContext A { domainVisionStatement = \"oops }").is_err());
+    }
+}
@@ -0,0 +1,318 @@
+pub mod asyncapi;
+pub mod cml;
+pub mod openapi;
+mod schema;
+pub mod sql;
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::path::Path;
+
+use crate::domain::model::*;
+use crate::domain::types::TypeRef;
+
+/// Read a JSON or YAML document into a generic JSON value.
+/// The format is picked from the file extension; unknown extensions try JSON first.
+pub fn read_document(path: &str) -> Result<Value> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {path}"))?;
+    let ext = Path::new(path)
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "yaml" | "yml" => serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse YAML document {path}")),
+        "json" => serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse JSON document {path}")),
+        _ => serde_json::from_str(&content)
+            .or_else(|_| serde_yaml::from_str(&content))
+            .with_context(|| format!("Failed to parse document {path} as JSON or YAML")),
+    }
+}
+
+/// Import specification files of the given format into draft bounded contexts.
+/// SQL migrations are applied in order to a single draft; API documents yield one draft each.
+pub fn import_files(format: &str, paths: &[String], context: &str) -> Result<Vec<BoundedContext>> {
+    if context.is_empty() {
+        anyhow::bail!("A target bounded context is required");
+    }
+    if paths.is_empty() {
+        anyhow::bail!("At least one file is required");
+    }
+    let importer = match format {
+        "openapi" => openapi::import,
+        "asyncapi" => asyncapi::import,
+        "sql" => {
+            let sources = paths
+                .iter()
+                .map(|p| std::fs::read_to_string(p).with_context(|| format!("Failed to read {p}")))
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(vec![sql::import(&sources, context)?]);
+        }
+        other => anyhow::bail!(
+            "Unsupported import format '{other}'. Supported: openapi, asyncapi, sql"
+        ),
+    };
+    paths
+        .iter()
+        .map(|p| importer(&read_document(p)?, context))
+        .collect()
+}
+
+/// Merge an imported bounded context draft into the model.
+/// Existing elements are merged by name (fields and methods are added or updated,
+/// never removed, and a modelled field type is not downgraded to an imported
+/// primitive; whether an entity is an aggregate root stays as modelled); new
+/// elements are appended. Creates the context if missing.
+pub fn merge_context(model: &mut DomainModel, draft: BoundedContext) {
+    let bc = match model
+        .bounded_contexts
+        .iter_mut()
+        .find(|bc| bc.name.eq_ignore_ascii_case(&draft.name))
+    {
+        Some(bc) => bc,
+        None => {
+            model.bounded_contexts.push(draft);
+            return;
+        }
+    };
+
+    if bc.description.is_empty() {
+        bc.description = draft.description;
+    }
+
+    for entity in draft.entities {
+        match bc.entities.iter_mut().find(|e| e.name.eq_ignore_ascii_case(&entity.name)) {
+            Some(existing) => {
+                merge_fields(&mut existing.fields, entity.fields);
+                merge_methods(&mut existing.methods, entity.methods);
+            }
+            None => bc.entities.push(entity),
+        }
+    }
+
+    for vo in draft.value_objects {
+        match bc.value_objects.iter_mut().find(|v| v.name.eq_ignore_ascii_case(&vo.name)) {
+            Some(existing) => {
+                merge_fields(&mut existing.fields, vo.fields);
+                for rule in vo.validation_rules {
+                    if !existing.validation_rules.contains(&rule) {
+                        existing.validation_rules.push(rule);
+                    }
+                }
+            }
+            None => bc.value_objects.push(vo),
+        }
+    }
+
+    for en in draft.enumerations {
+        match bc.enumerations.iter_mut().find(|e| e.name.eq_ignore_ascii_case(&en.name)) {
+            Some(existing) => {
+                for variant in en.variants {
+                    if !existing.variants.iter().any(|v| v.name.eq_ignore_ascii_case(&variant.name)) {
+                        existing.variants.push(variant);
+                    }
+                }
+            }
+            None => bc.enumerations.push(en),
+        }
+    }
+
+    for agg in draft.aggregates {
+        match bc.aggregates.iter_mut().find(|a| a.name.eq_ignore_ascii_case(&agg.name)) {
+            Some(existing) => {
+                if !agg.root.is_empty() {
+                    existing.root = agg.root;
+                }
+                for member in agg.entities {
+                    if !existing.entities.contains(&member) {
+                        existing.entities.push(member);
+                    }
+                }
+                for member in agg.value_objects {
+                    if !existing.value_objects.contains(&member) {
+                        existing.value_objects.push(member);
+                    }
+                }
+            }
+            None => bc.aggregates.push(agg),
+        }
+    }
+
+    for svc in draft.services {
+        match bc.services.iter_mut().find(|s| s.name.eq_ignore_ascii_case(&svc.name)) {
+            Some(existing) => merge_methods(&mut existing.methods, svc.methods),
+            None => bc.services.push(svc),
+        }
+    }
+
+    for repo in draft.repositories {
+        match bc.repositories.iter_mut().find(|r| r.name.eq_ignore_ascii_case(&repo.name)) {
+            Some(existing) => merge_methods(&mut existing.methods, repo.methods),
+            None => bc.repositories.push(repo),
+        }
+    }
+
+    for evt in draft.events {
+        match bc.events.iter_mut().find(|e| e.name.eq_ignore_ascii_case(&evt.name)) {
+            Some(existing) => {
+                if existing.source.is_empty() {
+                    existing.source = evt.source;
+                }
+                existing.published |= evt.published;
+                if !evt.channel.is_empty() {
+                    existing.channel = evt.channel;
+                }
+                merge_fields(&mut existing.fields, evt.fields);
+            }
+            None => bc.events.push(evt),
+        }
+    }
+
+    for dep in draft.dependencies {
+        if !bc.dependencies.iter().any(|d| d.eq_ignore_ascii_case(&dep)) {
+            bc.dependencies.push(dep);
+        }
+    }
+}
+
+fn merge_fields(existing: &mut Vec<Field>, imported: Vec<Field>) {
+    for field in imported {
+        match existing.iter_mut().find(|f| f.name == field.name) {
+            Some(f) => {
+                // an imported primitive never replaces a modelled type such as `UserId`
+                if !is_primitive(&field.field_type) || is_primitive(&f.field_type) {
+                    f.field_type = field.field_type;
+                }
+                f.required = field.required;
+                if !field.description.is_empty() {
+                    f.description = field.description;
+                }
+            }
+            None => existing.push(field),
+        }
+    }
+}
+
+/// Types the importers produce for plain schema and column types, possibly wrapped
+/// in `Option`, `Vec` or a map.
+const PRIMITIVE_TYPES: &[&str] = &[
+    "String", "Uuid", "bool", "i16", "i32", "i64", "f32", "f64", "u8", "Decimal", "DateTime", "Utc",
+    "NaiveDate", "NaiveDateTime", "NaiveTime", "serde_json::Value",
+];
+
+fn is_primitive(field_type: &str) -> bool {
+    fn primitive(ty: &TypeRef) -> bool {
+        match ty {
+            TypeRef::Optional { inner } => primitive(inner),
+            TypeRef::Collection { item, .. } => primitive(item),
+            TypeRef::Map { key, value, .. } => primitive(key) && primitive(value),
+            TypeRef::Tuple { items } => items.iter().all(primitive),
+            TypeRef::Named { name, args } => PRIMITIVE_TYPES.contains(&name.as_str()) && args.iter().all(primitive),
+        }
+    }
+    TypeRef::parse(field_type).is_ok_and(|ty| primitive(&ty))
+}
+
+fn merge_methods(existing: &mut Vec<Method>, imported: Vec<Method>) {
+    for method in imported {
+        match existing.iter_mut().find(|m| m.name == method.name) {
+            Some(m) => {
+                m.parameters = method.parameters;
+                m.return_type = method.return_type;
+                if !method.description.is_empty() {
+                    m.description = method.description;
+                }
+            }
+            None => existing.push(method),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::fixtures::context;
+
+    fn field(name: &str, ty: &str) -> Field {
+        Field {
+            name: name.into(),
+            field_type: ty.into(),
+            required: true,
+            description: "".into(),
+        }
+    }
+
+    #[test]
+    fn test_merge_creates_missing_context() {
+        let mut model = DomainModel::empty("/tmp/proj");
+        merge_context(&mut model, context("Billing", &[]));
+        assert_eq!(model.bounded_contexts.len(), 1);
+        assert_eq!(model.bounded_contexts[0].name, "Billing");
+    }
+
+    #[test]
+    fn test_merge_adds_fields_to_existing_entity() {
+        let mut model = DomainModel::empty("/tmp/proj");
+        let mut existing = context("Billing", &[]);
+        existing.entities.push(Entity {
+            name: "Invoice".into(),
+            description: "Kept".into(),
+            aggregate_root: true,
+            fields: vec![field("id", "InvoiceId"), field("paid", "Option<bool>")],
+            methods: vec![],
+            invariants: vec!["Amount is positive".into()],
+            state_machine: None,
+            meta: Metadata::default(),
+        });
+        model.bounded_contexts.push(existing);
+
+        let mut draft = context("billing", &[]);
+        draft.entities.push(Entity {
+            name: "Invoice".into(),
+            description: "".into(),
+            aggregate_root: false,
+            fields: vec![field("id", "Uuid"), field("amount", "Money"), field("paid", "bool")],
+            methods: vec![],
+            invariants: vec![],
+            state_machine: None,
+            meta: Metadata::default(),
+        });
+        merge_context(&mut model, draft);
+
+        let invoice = &model.bounded_contexts[0].entities[0];
+        assert_eq!(model.bounded_contexts.len(), 1);
+        assert!(invoice.aggregate_root);
+        assert_eq!(invoice.description, "Kept");
+        assert_eq!(invoice.fields.len(), 3);
+        assert_eq!(invoice.fields[0].field_type, "InvoiceId");
+        assert_eq!(invoice.fields[1].field_type, "bool");
+        assert_eq!(invoice.fields[2].field_type, "Money");
+        assert_eq!(invoice.invariants.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_keeps_existing_member_out_of_roots() {
+        let entity = |name: &str, aggregate_root: bool| Entity {
+            name: name.into(),
+            description: "".into(),
+            aggregate_root,
+            fields: vec![field("id", "Uuid")],
+            methods: vec![],
+            invariants: vec![],
+            state_machine: None,
+            meta: Metadata::default(),
+        };
+        let mut model = DomainModel::empty("/tmp/proj");
+        let mut existing = context("Billing", &[]);
+        existing.entities.push(entity("LineItem", false));
+        model.bounded_contexts.push(existing);
+
+        let mut draft = context("Billing", &[]);
+        draft.entities.push(entity("LineItem", true));
+        merge_context(&mut model, draft);
+        assert!(!model.bounded_contexts[0].entities[0].aggregate_root);
+    }
+}
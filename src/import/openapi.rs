@@ -0,0 +1,375 @@
+use anyhow::Result;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::schema::{collect_properties, fields_of, resolve, schema_type, str_of};
+use crate::domain::model::*;
+use crate::domain::{to_pascal, to_snake};
+
+const HTTP_METHODS: &[&str] = &["get", "put", "post", "delete", "patch", "head", "options"];
+
+/// Build a draft bounded context from an OpenAPI 3 document.
+///
+/// - Component schemas with an `id` property become entities, other object
+///   schemas become value objects. `x-domcp-kind: entity | value_object` overrides
+///   the guess, `x-domcp-aggregate-root: true` marks aggregate roots.
+/// - Schemas with `enum` become enumerations; variants are PascalCased and keep
+///   their wire value in the description when it differs.
+/// - Operations become methods on application services, one per tag.
+pub fn import(spec: &Value, context: &str) -> Result<BoundedContext> {
+    let version = spec.get("openapi").and_then(|v| v.as_str()).unwrap_or("");
+    if !version.starts_with('3') {
+        anyhow::bail!(
+            "Unsupported OpenAPI version '{}': only OpenAPI 3.x documents can be imported",
+            if version.is_empty() { "missing" } else { version }
+        );
+    }
+
+    let mut bc = BoundedContext {
+        name: context.to_string(),
+        description: spec
+            .pointer("/info/description")
+            .or_else(|| spec.pointer("/info/title"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        module_path: format!("src/{}", to_snake(context)),
+        ..Default::default()
+    };
+
+    if let Some(schemas) = spec.pointer("/components/schemas").and_then(|v| v.as_object()) {
+        for (name, schema) in schemas {
+            import_schema(spec, name, schema, &mut bc);
+        }
+    }
+
+    // Group operations by tag → one application service per tag
+    let mut services: BTreeMap<String, Service> = BTreeMap::new();
+    if let Some(paths) = spec.get("paths").and_then(|v| v.as_object()) {
+        for (path, item) in paths {
+            let item = resolve(spec, item);
+            for http_method in HTTP_METHODS {
+                let Some(op) = item.get(*http_method) else {
+                    continue;
+                };
+                let svc_name = service_name(op, context);
+                let method = import_operation(spec, path, http_method, item, op);
+                services
+                    .entry(svc_name.clone())
+                    .or_insert_with(|| Service {
+                        name: svc_name,
+                        description: String::new(),
+                        kind: ServiceKind::Application,
+                        methods: vec![],
+                        dependencies: vec![],
+                        meta: Metadata::default(),
+                    })
+                    .methods
+                    .push(method);
+            }
+        }
+    }
+    bc.services = services.into_values().collect();
+
+    Ok(bc)
+}
+
+fn import_schema(spec: &Value, name: &str, schema: &Value, bc: &mut BoundedContext) {
+    let schema = resolve(spec, schema);
+    let description = str_of(schema, "description");
+
+    if let Some(variants) = schema.get("enum").and_then(|v| v.as_array()) {
+        let variants = variants
+            .iter()
+            .map(|v| {
+                let raw = v.as_str().map(String::from).unwrap_or_else(|| v.to_string());
+                let name = to_pascal(&raw);
+                Variant {
+                    description: if name == raw { String::new() } else { format!("Serialized as \"{raw}\"") },
+                    name,
+                }
+            })
+            .collect();
+        bc.enumerations.push(Enumeration {
+            name: name.to_string(),
+            description,
+            variants,
+            meta: Metadata::default(),
+        });
+        return;
+    }
+
+    let (properties, _) = collect_properties(spec, schema);
+    if properties.is_empty() {
+        return;
+    }
+
+    let fields = fields_of(spec, schema);
+
+    let is_entity = match schema.get("x-domcp-kind").and_then(|v| v.as_str()) {
+        Some("entity") => true,
+        Some("value_object") => false,
+        _ => properties.contains_key("id"),
+    };
+
+    if is_entity {
+        bc.entities.push(Entity {
+            name: name.to_string(),
+            description,
+            aggregate_root: schema
+                .get("x-domcp-aggregate-root")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            fields,
+            methods: vec![],
+            invariants: vec![],
+            state_machine: None,
+            meta: Metadata::default(),
+        });
+    } else {
+        let validation_rules = properties
+            .iter()
+            .filter_map(|(prop, s)| {
+                let s = resolve(spec, s);
+                s.get("pattern")
+                    .and_then(|p| p.as_str())
+                    .map(|p| format!("{prop} must match {p}"))
+            })
+            .collect();
+        bc.value_objects.push(ValueObject {
+            name: name.to_string(),
+            description,
+            fields,
+            validation_rules,
+            meta: Metadata::default(),
+        });
+    }
+}
+
+fn import_operation(spec: &Value, path: &str, http_method: &str, item: &Value, op: &Value) -> Method {
+    let name = match op.get("operationId").and_then(|v| v.as_str()) {
+        Some(id) => to_snake(id),
+        None => {
+            let segments: Vec<String> = path
+                .split('/')
+                .filter(|s| !s.is_empty())
+                .map(|s| match s.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                    Some(param) => format!("by_{}", to_snake(param)),
+                    None => to_snake(&s.replace('-', "_")),
+                })
+                .collect();
+            format!("{}_{}", http_method, segments.join("_"))
+        }
+    };
+
+    // Path-level parameters apply to every operation on the path
+    let mut parameters = Vec::new();
+    let declared = item
+        .get("parameters")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .chain(op.get("parameters").and_then(|v| v.as_array()).into_iter().flatten());
+    for param in declared {
+        let param = resolve(spec, param);
+        let Some(param_name) = param.get("name").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let field = Field {
+            name: to_snake(param_name),
+            field_type: param
+                .get("schema")
+                .map(schema_type)
+                .unwrap_or_else(|| "String".into()),
+            required: param.get("required").and_then(|v| v.as_bool()).unwrap_or(false),
+            description: str_of(param, "description"),
+        };
+        match parameters.iter_mut().find(|p: &&mut Field| p.name == field.name) {
+            Some(existing) => *existing = field,
+            None => parameters.push(field),
+        }
+    }
+
+    if let Some(body) = op.get("requestBody").map(|b| resolve(spec, b)) {
+        if let Some(schema) = json_schema(body) {
+            let body_type = schema_type(schema);
+            parameters.push(Field {
+                name: match schema.get("$ref") {
+                    Some(_) => to_snake(&body_type),
+                    None => "body".into(),
+                },
+                field_type: body_type,
+                required: body.get("required").and_then(|v| v.as_bool()).unwrap_or(false),
+                description: str_of(body, "description"),
+            });
+        }
+    }
+
+    let success = op.get("responses").and_then(|v| v.as_object()).and_then(|responses| {
+        responses
+            .iter()
+            .filter(|(code, _)| code.starts_with('2'))
+            .find_map(|(_, resp)| json_schema(resolve(spec, resp)))
+    });
+    let return_type = match success {
+        Some(schema) => format!("Result<{}>", schema_type(schema)),
+        None => "Result<()>".into(),
+    };
+
+    Method {
+        name,
+        description: op
+            .get("summary")
+            .or_else(|| op.get("description"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        parameters,
+        return_type,
+    }
+}
+
+fn service_name(op: &Value, context: &str) -> String {
+    let base = op
+        .get("tags")
+        .and_then(|t| t.as_array())
+        .and_then(|t| t.first())
+        .and_then(|t| t.as_str())
+        .map(to_pascal)
+        .unwrap_or_else(|| to_pascal(context));
+    if base.ends_with("Service") {
+        base
+    } else {
+        format!("{base}Service")
+    }
+}
+
+/// The JSON media-type schema of a request body or response, if any.
+fn json_schema(body: &Value) -> Option<&Value> {
+    let content = body.get("content")?.as_object()?;
+    content
+        .iter()
+        .find(|(mime, _)| mime.contains("json"))
+        .or_else(|| content.iter().next())
+        .and_then(|(_, media)| media.get("schema"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn spec() -> Value {
+        json!({
+            "openapi": "3.0.3",
+            "info": { "title": "Billing API", "version": "1.0" },
+            "paths": {
+                "/invoices/{invoiceId}": {
+                    "parameters": [
+                        { "name": "invoiceId", "in": "path", "required": true,
+                          "schema": { "type": "string", "format": "uuid" } }
+                    ],
+                    "get": {
+                        "operationId": "getInvoice",
+                        "tags": ["invoices"],
+                        "summary": "Fetch an invoice",
+                        "responses": {
+                            "200": { "content": { "application/json": {
+                                "schema": { "$ref": "#/components/schemas/Invoice" } } } }
+                        }
+                    }
+                },
+                "/invoices": {
+                    "post": {
+                        "tags": ["invoices"],
+                        "requestBody": { "required": true, "content": { "application/json": {
+                            "schema": { "$ref": "#/components/schemas/Money" } } } },
+                        "responses": { "204": { "description": "Created" } }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Invoice": {
+                        "type": "object",
+                        "required": ["id", "amount"],
+                        "properties": {
+                            "id": { "type": "string", "format": "uuid" },
+                            "amount": { "$ref": "#/components/schemas/Money" },
+                            "lineItems": { "type": "array", "items": { "type": "string" } },
+                            "paidAt": { "type": "string", "format": "date-time", "nullable": true },
+                            "note": { "type": ["string", "null"] }
+                        }
+                    },
+                    "Money": {
+                        "type": "object",
+                        "required": ["cents"],
+                        "properties": {
+                            "cents": { "type": "integer", "format": "int64" },
+                            "currency": { "type": "string", "pattern": "^[A-Z]{3}$" }
+                        }
+                    },
+                    "InvoiceStatus": { "type": "string", "enum": ["draft", "paid"] }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_schema_with_id_becomes_entity() {
+        let bc = import(&spec(), "Billing").unwrap();
+        let invoice = bc.entities.iter().find(|e| e.name == "Invoice").unwrap();
+        let amount = invoice.fields.iter().find(|f| f.name == "amount").unwrap();
+        assert_eq!(amount.field_type, "Money");
+        assert!(amount.required);
+        let items = invoice.fields.iter().find(|f| f.name == "line_items").unwrap();
+        assert_eq!(items.field_type, "Vec<String>");
+        assert!(!items.required);
+        let paid_at = invoice.fields.iter().find(|f| f.name == "paid_at").unwrap();
+        assert_eq!(paid_at.field_type, "Option<DateTime<Utc>>");
+        let note = invoice.fields.iter().find(|f| f.name == "note").unwrap();
+        assert_eq!(note.field_type, "Option<String>");
+    }
+
+    #[test]
+    fn test_schema_without_id_becomes_value_object() {
+        let bc = import(&spec(), "Billing").unwrap();
+        let money = bc.value_objects.iter().find(|v| v.name == "Money").unwrap();
+        assert_eq!(money.fields[0].field_type, "i64");
+        assert!(money.validation_rules[0].contains("^[A-Z]{3}$"));
+    }
+
+    #[test]
+    fn test_enum_schema_becomes_enumeration() {
+        let bc = import(&spec(), "Billing").unwrap();
+        let status = bc.find_enumeration("InvoiceStatus").unwrap();
+        let names: Vec<_> = status.variants.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["Draft", "Paid"]);
+        assert_eq!(status.variants[0].description, "Serialized as \"draft\"");
+        assert!(!bc.value_objects.iter().any(|v| v.name == "InvoiceStatus"));
+    }
+
+    #[test]
+    fn test_operations_become_service_methods() {
+        let bc = import(&spec(), "Billing").unwrap();
+        assert_eq!(bc.services.len(), 1);
+        let svc = &bc.services[0];
+        assert_eq!(svc.name, "InvoicesService");
+        assert!(matches!(svc.kind, ServiceKind::Application));
+
+        let get = svc.methods.iter().find(|m| m.name == "get_invoice").unwrap();
+        assert_eq!(get.return_type, "Result<Invoice>");
+        assert_eq!(get.parameters[0].name, "invoice_id");
+        assert_eq!(get.parameters[0].field_type, "Uuid");
+
+        let post = svc.methods.iter().find(|m| m.name == "post_invoices").unwrap();
+        assert_eq!(post.parameters[0].field_type, "Money");
+        assert_eq!(post.return_type, "Result<()>");
+    }
+
+    #[test]
+    fn test_rejects_swagger_2() {
+        let err = import(&json!({ "swagger": "2.0" }), "Billing").unwrap_err();
+        assert!(err.to_string().contains("OpenAPI 3"));
+    }
+}
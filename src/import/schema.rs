@@ -0,0 +1,115 @@
+//! JSON Schema helpers shared by the OpenAPI and AsyncAPI importers.
+
+use serde_json::{Map, Value};
+
+use crate::domain::model::Field;
+use crate::domain::to_snake;
+
+/// Flatten `properties` and `required`, following `allOf` compositions.
+pub fn collect_properties(spec: &Value, schema: &Value) -> (Map<String, Value>, Vec<String>) {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    if let Some(parts) = schema.get("allOf").and_then(|v| v.as_array()) {
+        for part in parts {
+            let (p, r) = collect_properties(spec, resolve(spec, part));
+            properties.extend(p);
+            required.extend(r);
+        }
+    }
+    if let Some(props) = schema.get("properties").and_then(|v| v.as_object()) {
+        properties.extend(props.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+    if let Some(req) = schema.get("required").and_then(|v| v.as_array()) {
+        required.extend(req.iter().filter_map(|r| r.as_str().map(String::from)));
+    }
+
+    (properties, required)
+}
+
+/// Convert an object schema's properties into model fields.
+pub fn fields_of(spec: &Value, schema: &Value) -> Vec<Field> {
+    let (properties, required) = collect_properties(spec, resolve(spec, schema));
+    properties
+        .iter()
+        .map(|(prop, prop_schema)| Field {
+            name: to_snake(prop),
+            field_type: schema_type(prop_schema),
+            required: required.iter().any(|r| r == prop),
+            description: str_of(resolve(spec, prop_schema), "description"),
+        })
+        .collect()
+}
+
+/// Map a JSON schema to a Rust-flavoured field type string.
+pub fn schema_type(schema: &Value) -> String {
+    if let Some(r) = schema.get("$ref").and_then(|v| v.as_str()) {
+        return ref_name(r);
+    }
+
+    // OpenAPI 3.1 writes nullable types as `type: [T, "null"]`
+    let (ty, null_type) = match schema.get("type") {
+        Some(Value::Array(types)) => (
+            types.iter().filter_map(|t| t.as_str()).find(|t| *t != "null").unwrap_or(""),
+            types.iter().any(|t| t.as_str() == Some("null")),
+        ),
+        other => (other.and_then(|v| v.as_str()).unwrap_or(""), false),
+    };
+
+    let base = match ty {
+        "string" => match schema.get("format").and_then(|v| v.as_str()).unwrap_or("") {
+            "uuid" => "Uuid".to_string(),
+            "date-time" => "DateTime<Utc>".to_string(),
+            "date" => "NaiveDate".to_string(),
+            "byte" | "binary" => "Vec<u8>".to_string(),
+            _ => "String".to_string(),
+        },
+        "integer" => match schema.get("format").and_then(|v| v.as_str()) {
+            Some("int64") => "i64".to_string(),
+            _ => "i32".to_string(),
+        },
+        "number" => match schema.get("format").and_then(|v| v.as_str()) {
+            Some("float") => "f32".to_string(),
+            _ => "f64".to_string(),
+        },
+        "boolean" => "bool".to_string(),
+        "array" => format!(
+            "Vec<{}>",
+            schema
+                .get("items")
+                .map(schema_type)
+                .unwrap_or_else(|| "serde_json::Value".into())
+        ),
+        "object" => match schema.get("additionalProperties") {
+            Some(v) if v.is_object() => format!("HashMap<String, {}>", schema_type(v)),
+            _ => "serde_json::Value".to_string(),
+        },
+        _ => match schema.get("allOf").and_then(|v| v.as_array()) {
+            // `allOf: [$ref]` is the common idiom for annotating a reference
+            Some(parts) if parts.len() == 1 => schema_type(&parts[0]),
+            _ => "serde_json::Value".to_string(),
+        },
+    };
+
+    if null_type || schema.get("nullable").and_then(|v| v.as_bool()).unwrap_or(false) {
+        format!("Option<{base}>")
+    } else {
+        base
+    }
+}
+
+/// Follow a local `$ref` (e.g. `#/components/parameters/Id`); other values are returned as-is.
+pub fn resolve<'a>(spec: &'a Value, value: &'a Value) -> &'a Value {
+    match value.get("$ref").and_then(|v| v.as_str()) {
+        Some(r) if r.starts_with("#/") => spec.pointer(&r[1..]).unwrap_or(value),
+        _ => value,
+    }
+}
+
+pub fn ref_name(r: &str) -> String {
+    r.rsplit('/').next().unwrap_or(r).to_string()
+}
+
+pub fn str_of(value: &Value, key: &str) -> String {
+    value.get(key).and_then(|v| v.as_str()).unwrap_or("").to_string()
+}
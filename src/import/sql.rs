@@ -0,0 +1,586 @@
+use anyhow::Result;
+
+use crate::domain::model::*;
+use crate::domain::{to_pascal, to_snake};
+
+/// Build a draft bounded context from SQL DDL migrations, applied in the order given.
+///
+/// `CREATE TABLE` defines an entity per table, `ALTER TABLE` adds, drops, retypes
+/// and renames columns, `DROP TABLE` removes the entity again. Column types map to
+/// Rust field types, `NOT NULL` and primary keys map to `required` (other columns
+/// become `Option<T>`), and tables with a single-column primary key are tagged as
+/// aggregate root candidates — whether they are roots is left to the modeller.
+pub fn import(sources: &[String], context: &str) -> Result<BoundedContext> {
+    let mut tables: Vec<Table> = Vec::new();
+
+    for source in sources {
+        for statement in split_statements(source) {
+            apply_statement(&mut tables, &tokenize(&statement));
+        }
+    }
+
+    Ok(BoundedContext {
+        name: context.to_string(),
+        description: String::new(),
+        module_path: format!("src/{}", to_snake(context)),
+        entities: tables.iter().map(Table::to_entity).collect(),
+        ..Default::default()
+    })
+}
+
+/// Tag on entities whose table has a single-column primary key.
+const ROOT_CANDIDATE_TAG: &str = "aggregate-root-candidate";
+
+#[derive(Debug)]
+struct Table {
+    name: String,
+    columns: Vec<Column>,
+    primary_key: Vec<String>,
+}
+
+#[derive(Debug)]
+struct Column {
+    name: String,
+    sql_type: String,
+    not_null: bool,
+}
+
+impl Table {
+    fn to_entity(&self) -> Entity {
+        Entity {
+            name: to_pascal(&singularize(&self.name)),
+            description: format!("Imported from table '{}'", self.name),
+            aggregate_root: false,
+            fields: self
+                .columns
+                .iter()
+                .map(|c| {
+                    let required = c.not_null || self.primary_key.contains(&c.name);
+                    let field_type = map_type(&c.sql_type);
+                    Field {
+                        name: c.name.clone(),
+                        field_type: if required { field_type } else { format!("Option<{field_type}>") },
+                        required,
+                        description: String::new(),
+                    }
+                })
+                .collect(),
+            methods: vec![],
+            invariants: vec![],
+            state_machine: None,
+            meta: Metadata {
+                tags: if self.primary_key.len() == 1 { vec![ROOT_CANDIDATE_TAG.to_string()] } else { vec![] },
+                ..Default::default()
+            },
+        }
+    }
+
+    fn column_mut(&mut self, name: &str) -> Option<&mut Column> {
+        self.columns.iter_mut().find(|c| c.name.eq_ignore_ascii_case(name))
+    }
+}
+
+fn apply_statement(tables: &mut Vec<Table>, tokens: &[String]) {
+    let upper: Vec<String> = tokens.iter().map(|t| t.to_ascii_uppercase()).collect();
+    let words: Vec<&str> = upper.iter().map(String::as_str).collect();
+
+    match words.as_slice() {
+        ["CREATE", rest @ ..] => {
+            // CREATE [TEMP | UNLOGGED ...] TABLE [IF NOT EXISTS] name ( defs )
+            let Some(pos) = rest.iter().position(|w| *w == "TABLE") else {
+                return;
+            };
+            let mut i = 1 + pos + 1;
+            i += skip_keywords(&words[i..], &["IF", "NOT", "EXISTS"]);
+            let (Some(name), Some(body)) = (tokens.get(i), tokens.get(i + 1)) else {
+                return;
+            };
+            let Some(defs) = strip_parens(body) else {
+                return;
+            };
+            let name = table_name(name);
+            tables.retain(|t| !t.name.eq_ignore_ascii_case(&name));
+            let mut table = Table {
+                name,
+                columns: vec![],
+                primary_key: vec![],
+            };
+            for def in split_top_level(defs, ',') {
+                apply_definition(&mut table, &tokenize(&def));
+            }
+            tables.push(table);
+        }
+        ["ALTER", "TABLE", ..] => {
+            let mut i = 2;
+            i += skip_keywords(&words[i..], &["IF", "EXISTS", "ONLY"]);
+            let Some(name) = tokens.get(i) else {
+                return;
+            };
+            let name = table_name(name);
+            let Some(table) = tables.iter_mut().find(|t| t.name.eq_ignore_ascii_case(&name)) else {
+                return;
+            };
+            // Several actions may be chained with commas
+            let actions = tokens[i + 1..].join(" ");
+            for action in split_top_level(&actions, ',') {
+                let new_name = apply_alter(table, &tokenize(&action));
+                if let Some(new_name) = new_name {
+                    table.name = new_name;
+                }
+            }
+        }
+        ["DROP", "TABLE", ..] => {
+            let mut i = 2;
+            i += skip_keywords(&words[i..], &["IF", "EXISTS"]);
+            for name in tokens[i..].iter().filter(|t| *t != ",") {
+                let upper = name.to_ascii_uppercase();
+                if upper == "CASCADE" || upper == "RESTRICT" {
+                    continue;
+                }
+                let name = table_name(name);
+                tables.retain(|t| !t.name.eq_ignore_ascii_case(&name));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Apply one column or table-constraint definition inside `CREATE TABLE (...)`.
+fn apply_definition(table: &mut Table, tokens: &[String]) {
+    let Some(first) = tokens.first() else {
+        return;
+    };
+    match first.to_ascii_uppercase().as_str() {
+        "CONSTRAINT" | "PRIMARY" => {
+            if let Some(cols) = primary_key_columns(tokens) {
+                table.primary_key = cols;
+            }
+        }
+        "FOREIGN" | "UNIQUE" | "CHECK" | "INDEX" | "KEY" | "EXCLUDE" => {}
+        _ => {
+            let (column, primary) = parse_column(tokens);
+            if primary {
+                table.primary_key = vec![column.name.clone()];
+            }
+            table.columns.push(column);
+        }
+    }
+}
+
+/// Apply one `ALTER TABLE` action. Returns the new table name for `RENAME TO`.
+fn apply_alter(table: &mut Table, tokens: &[String]) -> Option<String> {
+    let upper: Vec<String> = tokens.iter().map(|t| t.to_ascii_uppercase()).collect();
+    let words: Vec<&str> = upper.iter().map(String::as_str).collect();
+
+    match words.as_slice() {
+        ["ADD", "CONSTRAINT", ..] | ["ADD", "PRIMARY", ..] => {
+            if let Some(cols) = primary_key_columns(&tokens[1..]) {
+                table.primary_key = cols;
+            }
+        }
+        ["ADD", "FOREIGN" | "UNIQUE" | "CHECK" | "INDEX", ..] => {}
+        ["ADD", ..] => {
+            let mut i = 1;
+            i += skip_keywords(&words[i..], &["COLUMN", "IF", "NOT", "EXISTS"]);
+            let (column, primary) = parse_column(&tokens[i..]);
+            if primary {
+                table.primary_key = vec![column.name.clone()];
+            }
+            table.columns.retain(|c| !c.name.eq_ignore_ascii_case(&column.name));
+            table.columns.push(column);
+        }
+        ["DROP", "CONSTRAINT", ..] => {}
+        ["DROP", ..] => {
+            let mut i = 1;
+            i += skip_keywords(&words[i..], &["COLUMN", "IF", "EXISTS"]);
+            let name = ident(tokens.get(i)?);
+            table.columns.retain(|c| !c.name.eq_ignore_ascii_case(&name));
+            table.primary_key.retain(|c| !c.eq_ignore_ascii_case(&name));
+        }
+        ["ALTER" | "MODIFY", ..] => {
+            let mut i = 1;
+            i += skip_keywords(&words[i..], &["COLUMN"]);
+            let name = ident(tokens.get(i)?);
+            let rest = &words[i + 1..];
+            match rest {
+                ["SET", "NOT", "NULL", ..] => table.column_mut(&name)?.not_null = true,
+                ["DROP", "NOT", "NULL", ..] => table.column_mut(&name)?.not_null = false,
+                ["SET", "DATA", "TYPE", ..] | ["TYPE", ..] => {
+                    let start = i + 1 + if rest[0] == "SET" { 3 } else { 1 };
+                    table.column_mut(&name)?.sql_type = column_type(&tokens[start..]);
+                }
+                // MySQL: MODIFY [COLUMN] name new_type [NOT NULL]
+                _ if words[0] == "MODIFY" => {
+                    let (column, _) = parse_column(&tokens[i..]);
+                    let existing = table.column_mut(&name)?;
+                    existing.sql_type = column.sql_type;
+                    existing.not_null = column.not_null;
+                }
+                _ => {}
+            }
+        }
+        ["RENAME", "TO", ..] => return tokens.get(2).map(|t| table_name(t)),
+        ["RENAME", ..] => {
+            let mut i = 1;
+            i += skip_keywords(&words[i..], &["COLUMN"]);
+            let from = ident(tokens.get(i)?);
+            let to = ident(tokens.get(i + 2)?);
+            if let Some(col) = table.column_mut(&from) {
+                col.name = to.clone();
+            }
+            for pk in table.primary_key.iter_mut().filter(|c| c.eq_ignore_ascii_case(&from)) {
+                *pk = to.clone();
+            }
+        }
+        _ => {}
+    }
+    None
+}
+
+/// Parse `name type [constraints...]`. Returns the column and whether it is an inline primary key.
+fn parse_column(tokens: &[String]) -> (Column, bool) {
+    let name = tokens.first().map(|t| ident(t)).unwrap_or_default();
+    let rest = tokens.get(1..).unwrap_or_default();
+    let upper: Vec<String> = rest.iter().map(|t| t.to_ascii_uppercase()).collect();
+
+    let not_null = upper.windows(2).any(|w| w[0] == "NOT" && w[1] == "NULL");
+    let primary = upper.windows(2).any(|w| w[0] == "PRIMARY" && w[1] == "KEY");
+
+    (
+        Column {
+            name,
+            sql_type: column_type(rest),
+            not_null: not_null || primary,
+        },
+        primary,
+    )
+}
+
+/// Collect type tokens up to the first constraint keyword, e.g. `timestamp(3) with time zone`.
+fn column_type(tokens: &[String]) -> String {
+    const CONSTRAINTS: &[&str] = &[
+        "NOT", "NULL", "PRIMARY", "DEFAULT", "REFERENCES", "UNIQUE", "CHECK", "CONSTRAINT",
+        "GENERATED", "COLLATE", "AUTO_INCREMENT", "AUTOINCREMENT", "IDENTITY", "USING", "FIRST",
+        "AFTER", "COMMENT",
+    ];
+    let mut parts: Vec<&str> = Vec::new();
+    for token in tokens {
+        if CONSTRAINTS.contains(&token.to_ascii_uppercase().as_str()) {
+            break;
+        }
+        parts.push(token);
+    }
+    parts.join(" ").replace(" (", "(")
+}
+
+/// `[CONSTRAINT name] PRIMARY KEY (a, b)` → `[a, b]`.
+fn primary_key_columns(tokens: &[String]) -> Option<Vec<String>> {
+    let pos = tokens
+        .windows(2)
+        .position(|w| w[0].eq_ignore_ascii_case("PRIMARY") && w[1].eq_ignore_ascii_case("KEY"))?;
+    let cols = strip_parens(tokens.get(pos + 2)?)?;
+    Some(split_top_level(cols, ',').iter().map(|c| ident(c.trim())).collect())
+}
+
+/// Map a SQL column type to a Rust-flavoured field type.
+fn map_type(sql_type: &str) -> String {
+    let lower = sql_type.trim().to_ascii_lowercase();
+    if let Some(inner) = lower.strip_suffix("[]") {
+        return format!("Vec<{}>", map_type(inner));
+    }
+    // Drop length / precision arguments: varchar(255), numeric(10, 2), timestamp(3)
+    let base: String = {
+        let mut out = String::new();
+        let mut depth = 0;
+        for ch in lower.chars() {
+            match ch {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ if depth == 0 => out.push(ch),
+                _ => {}
+            }
+        }
+        out.split_whitespace().collect::<Vec<_>>().join(" ")
+    };
+
+    match base.as_str() {
+        "uuid" => "Uuid",
+        "text" | "varchar" | "character varying" | "char" | "character" | "citext" | "string"
+        | "nvarchar" | "nchar" | "tinytext" | "mediumtext" | "longtext" | "clob" => "String",
+        "smallint" | "int2" | "smallserial" => "i16",
+        "integer" | "int" | "int4" | "serial" | "mediumint" => "i32",
+        "bigint" | "int8" | "bigserial" => "i64",
+        "boolean" | "bool" => "bool",
+        "real" | "float4" => "f32",
+        "double precision" | "float8" | "float" | "double" => "f64",
+        "numeric" | "decimal" | "money" => "Decimal",
+        "timestamptz" | "timestamp with time zone" => "DateTime<Utc>",
+        "timestamp" | "timestamp without time zone" | "datetime" => "NaiveDateTime",
+        "date" => "NaiveDate",
+        "time" | "time without time zone" => "NaiveTime",
+        "json" | "jsonb" => "serde_json::Value",
+        "bytea" | "blob" | "binary" | "varbinary" | "longblob" => "Vec<u8>",
+        // User-defined types (e.g. Postgres enums) keep their name
+        other => return to_pascal(other.rsplit('.').next().unwrap_or(other)),
+    }
+    .to_string()
+}
+
+/// Very small English singularizer for table names: `users` → `user`, `categories` → `category`.
+fn singularize(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies") {
+        format!("{stem}y")
+    } else if name.ends_with("sses") || name.ends_with("xes") || name.ends_with("ches") {
+        name[..name.len() - 2].to_string()
+    } else if name.ends_with('s') && !name.ends_with("ss") && !name.ends_with("us") && !name.ends_with("is") {
+        name[..name.len() - 1].to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+fn table_name(token: &str) -> String {
+    // Drop the schema qualifier: public.users → users
+    ident(token.rsplit('.').next().unwrap_or(token))
+}
+
+fn ident(token: &str) -> String {
+    token
+        .trim_matches(|c| c == '"' || c == '`' || c == '[' || c == ']')
+        .to_string()
+}
+
+fn skip_keywords(words: &[&str], keywords: &[&str]) -> usize {
+    words.iter().take_while(|w| keywords.contains(w)).count()
+}
+
+fn strip_parens(token: &str) -> Option<&str> {
+    token.strip_prefix('(')?.strip_suffix(')')
+}
+
+/// Remove comments and split a script into statements on top-level `;`.
+fn split_statements(source: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut chars = source.chars().peekable();
+    let mut quote: Option<char> = None;
+
+    while let Some(ch) = chars.next() {
+        if let Some(q) = quote {
+            current.push(ch);
+            if ch == q {
+                quote = None;
+            }
+            continue;
+        }
+        match ch {
+            '\'' | '"' | '`' => {
+                quote = Some(ch);
+                current.push(ch);
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+                current.push(' ');
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+                current.push(' ');
+            }
+            ';' => {
+                if !current.trim().is_empty() {
+                    statements.push(current.trim().to_string());
+                }
+                current.clear();
+            }
+            _ => current.push(ch),
+        }
+    }
+    if !current.trim().is_empty() {
+        statements.push(current.trim().to_string());
+    }
+    statements
+}
+
+/// Split on a separator that is not nested in parentheses or quotes.
+fn split_top_level(s: &str, sep: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+
+    for ch in s.chars() {
+        match (quote, ch) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(ch),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, c) if c == sep && depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(ch);
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
+
+/// Split a statement into words, quoted identifiers, string literals,
+/// commas and parenthesized groups (kept whole, including the parentheses).
+fn tokenize(s: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = s.chars().peekable();
+
+    let flush = |current: &mut String, tokens: &mut Vec<String>| {
+        if !current.is_empty() {
+            tokens.push(std::mem::take(current));
+        }
+    };
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            c if c.is_whitespace() => flush(&mut current, &mut tokens),
+            ',' => {
+                flush(&mut current, &mut tokens);
+                tokens.push(",".into());
+            }
+            '(' => {
+                flush(&mut current, &mut tokens);
+                let mut group = String::from("(");
+                let mut depth = 1;
+                for c in chars.by_ref() {
+                    group.push(c);
+                    match c {
+                        '(' => depth += 1,
+                        ')' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                tokens.push(group);
+            }
+            '\'' | '"' | '`' => {
+                current.push(ch);
+                for c in chars.by_ref() {
+                    current.push(c);
+                    if c == ch {
+                        break;
+                    }
+                }
+            }
+            _ => current.push(ch),
+        }
+    }
+    flush(&mut current, &mut tokens);
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import_one(sql: &str) -> BoundedContext {
+        import(&[sql.to_string()], "Identity").unwrap()
+    }
+
+    #[test]
+    fn test_create_table_maps_columns_to_fields() {
+        let bc = import_one(
+            r#"
+            -- users of the platform
+            CREATE TABLE IF NOT EXISTS public.users (
+                id UUID PRIMARY KEY,
+                email VARCHAR(255) NOT NULL UNIQUE,
+                display_name text,
+                status user_status NOT NULL DEFAULT 'active',
+                created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+                balance NUMERIC(10, 2)
+            );
+            "#,
+        );
+        assert_eq!(bc.entities.len(), 1);
+        let user = &bc.entities[0];
+        assert_eq!(user.name, "User");
+        assert!(!user.aggregate_root);
+        assert!(user.meta.has_tag(ROOT_CANDIDATE_TAG));
+
+        let field = |n: &str| user.fields.iter().find(|f| f.name == n).unwrap();
+        assert_eq!(field("id").field_type, "Uuid");
+        assert!(field("id").required);
+        assert_eq!(field("email").field_type, "String");
+        assert!(field("email").required);
+        assert!(!field("display_name").required);
+        assert_eq!(field("display_name").field_type, "Option<String>");
+        assert_eq!(field("status").field_type, "UserStatus");
+        assert_eq!(field("created_at").field_type, "DateTime<Utc>");
+        assert_eq!(field("balance").field_type, "Option<Decimal>");
+    }
+
+    #[test]
+    fn test_composite_primary_key_is_not_root_candidate() {
+        let bc = import_one(
+            "CREATE TABLE user_roles (user_id uuid NOT NULL, role_id uuid NOT NULL, \
+             CONSTRAINT user_roles_pk PRIMARY KEY (user_id, role_id));",
+        );
+        assert_eq!(bc.entities[0].name, "UserRole");
+        assert!(!bc.entities[0].meta.has_tag(ROOT_CANDIDATE_TAG));
+    }
+
+    #[test]
+    fn test_alter_table_across_migrations() {
+        let bc = import(
+            &[
+                "CREATE TABLE invoices (id bigserial PRIMARY KEY, amount integer, legacy text);".into(),
+                "ALTER TABLE invoices ADD COLUMN paid_at timestamptz, DROP COLUMN legacy;\n\
+                 ALTER TABLE invoices ALTER COLUMN amount TYPE numeric(12, 2);\n\
+                 ALTER TABLE invoices ALTER COLUMN amount SET NOT NULL;\n\
+                 ALTER TABLE invoices RENAME COLUMN paid_at TO settled_at;"
+                    .into(),
+            ],
+            "Billing",
+        )
+        .unwrap();
+        let invoice = &bc.entities[0];
+        let names: Vec<&str> = invoice.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "amount", "settled_at"]);
+        assert_eq!(invoice.fields[2].field_type, "Option<DateTime<Utc>>");
+        assert_eq!(invoice.fields[0].field_type, "i64");
+        assert_eq!(invoice.fields[1].field_type, "Decimal");
+        assert!(invoice.fields[1].required);
+    }
+
+    #[test]
+    fn test_drop_table_removes_entity() {
+        let bc = import_one("CREATE TABLE sessions (id uuid PRIMARY KEY); DROP TABLE IF EXISTS sessions;");
+        assert!(bc.entities.is_empty());
+    }
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("categories"), "category");
+        assert_eq!(singularize("addresses"), "address");
+        assert_eq!(singularize("status"), "status");
+        assert_eq!(singularize("user"), "user");
+    }
+}
@@ -1,4 +1,6 @@
 mod domain;
+mod export;
+mod import;
 mod mcp;
 mod server;
 mod store;
@@ -23,17 +25,27 @@ enum Commands {
         workspace: String,
     },
 
-    /// Import a domcp.json file into the store for a workspace
+    /// Import a domcp.json file into the store for a workspace, or merge a Context Mapper
+    /// CML file into its model and print the resulting changes
     Import {
-        /// Path to the JSON file to import
+        /// Path to the file to import
         file: String,
 
         /// Workspace path to associate with this model
         #[arg(short, long)]
         workspace: String,
+
+        /// Input format: json (replaces the model) or cml (merges contexts into a draft).
+        /// Defaults to cml for .cml files, json otherwise
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// cml only: write the merged draft model to this file (apply it later with `domcp import`)
+        #[arg(short, long)]
+        output: Option<String>,
     },
 
-    /// Export a workspace's domain model to a JSON file
+    /// Export a workspace's domain model to a file
     Export {
         /// Output file path
         file: String,
@@ -41,6 +53,103 @@ enum Commands {
         /// Workspace path whose model to export
         #[arg(short, long)]
         workspace: String,
+
+        /// Output format: json, cml (Context Mapper) or structurizr (Structurizr DSL)
+        #[arg(short, long, default_value = "json")]
+        format: String,
+    },
+
+    /// Import an OpenAPI 3 spec into a bounded context and print the resulting changes
+    ImportOpenapi {
+        /// Path to the OpenAPI document (JSON or YAML)
+        spec: String,
+
+        /// Bounded context the schemas and operations are imported into
+        #[arg(short, long)]
+        context: String,
+
+        /// Workspace path whose model the spec is merged into
+        #[arg(short, long)]
+        workspace: String,
+
+        /// Write the merged draft model to this file (apply it later with `domcp import`)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Import AsyncAPI messages as published domain events and print the resulting changes
+    ImportAsyncapi {
+        /// Path to the AsyncAPI document (JSON or YAML)
+        spec: String,
+
+        /// Bounded context the events are imported into
+        #[arg(short, long)]
+        context: String,
+
+        /// Workspace path whose model the spec is merged into
+        #[arg(short, long)]
+        workspace: String,
+
+        /// Write the merged draft model to this file (apply it later with `domcp import`)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Import entities from SQL DDL migrations (applied in order) and print the resulting changes
+    ImportSql {
+        /// Migration files, e.g. migrations/*.sql
+        #[arg(required = true)]
+        files: Vec<String>,
+
+        /// Bounded context the tables are imported into
+        #[arg(short, long)]
+        context: String,
+
+        /// Workspace path whose model the migrations are merged into
+        #[arg(short, long)]
+        workspace: String,
+
+        /// Write the merged draft model to this file (apply it later with `domcp import`)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Plan the refactoring from a workspace's stored model to a target model file.
+    /// Prints the plan as JSON; SQL migrations can be written as up/down files.
+    Plan {
+        /// Path to the target domcp.json
+        file: String,
+
+        /// Workspace path whose stored model is the starting point
+        #[arg(short, long)]
+        workspace: String,
+
+        /// Write SQL migration files (<version>_<name>.up.sql / .down.sql) to this directory
+        #[arg(short, long)]
+        migrations_dir: Option<String>,
+
+        /// SQL dialect (postgres, sqlite); defaults to the target model's tech_stack.database
+        #[arg(short, long)]
+        dialect: Option<String>,
+    },
+
+    /// Show what is affected by changing an element of a workspace's stored model,
+    /// grouped by context and ranked by distance
+    Impact {
+        /// Element name, e.g. User
+        name: String,
+
+        /// Workspace path whose model to analyze
+        #[arg(short, long)]
+        workspace: String,
+
+        /// How many reference hops to follow
+        #[arg(short = 'd', long, default_value_t = 3)]
+        max_distance: usize,
+
+        /// Output format: text or json
+        #[arg(short, long, default_value = "text")]
+        format: String,
     },
 
     /// List all projects stored in the local database
@@ -60,8 +169,13 @@ async fn main() -> Result<()> {
         // Default: serve
         None => {
             eprintln!("Usage: domcp serve --workspace <path>");
-            eprintln!("       domcp import <file> --workspace <path>");
-            eprintln!("       domcp export <file> --workspace <path>");
+            eprintln!("       domcp import <file> --workspace <path> [--format json|cml] [--output <file>]");
+            eprintln!("       domcp export <file> --workspace <path> [--format json|cml|structurizr]");
+            eprintln!("       domcp import-openapi <spec> --context <name> --workspace <path> [--output <file>]");
+            eprintln!("       domcp import-asyncapi <spec> --context <name> --workspace <path> [--output <file>]");
+            eprintln!("       domcp import-sql <files>... --context <name> --workspace <path> [--output <file>]");
+            eprintln!("       domcp plan <file> --workspace <path> [--migrations-dir <dir>] [--dialect <db>]");
+            eprintln!("       domcp impact <name> --workspace <path> [--max-distance <n>] [--format text|json]");
             eprintln!("       domcp list");
             std::process::exit(1);
         }
@@ -100,21 +214,139 @@ async fn main() -> Result<()> {
             server::stdio::run(model, workspace, store).await?;
         }
 
-        Some(Commands::Import { file, workspace }) => {
+        Some(Commands::Import {
+            file,
+            workspace,
+            format,
+            output,
+        }) => {
             let store = store::Store::open_default()?;
-            let model = store.import_from_file(&workspace, &file)?;
-            eprintln!(
-                "Imported '{}' ({} contexts) into store for workspace: {}",
-                model.name,
-                model.bounded_contexts.len(),
-                workspace
-            );
+            let format = format.unwrap_or_else(|| {
+                if file.to_lowercase().ends_with(".cml") { "cml" } else { "json" }.to_string()
+            });
+            match format.as_str() {
+                "json" => {
+                    if output.is_some() {
+                        anyhow::bail!("--output only applies to cml imports");
+                    }
+                    let model = store.import_from_file(&workspace, &file)?;
+                    eprintln!(
+                        "Imported '{}' ({} contexts) into store for workspace: {}",
+                        model.name,
+                        model.bounded_contexts.len(),
+                        workspace
+                    );
+                }
+                "cml" => {
+                    let source = std::fs::read_to_string(&file)?;
+                    let drafts = import::cml::import(&source)?;
+                    import_draft(&store, &workspace, drafts, output.as_deref())?;
+                }
+                other => anyhow::bail!("Unsupported import format '{other}'. Supported: json, cml"),
+            }
+        }
+
+        Some(Commands::Export {
+            file,
+            workspace,
+            format,
+        }) => {
+            let store = store::Store::open_default()?;
+            if format == "json" {
+                store.export_to_file(&workspace, &file)?;
+            } else {
+                let model = store
+                    .load(&workspace)?
+                    .ok_or_else(|| anyhow::anyhow!("No model found for workspace: {workspace}"))?;
+                std::fs::write(&file, export::render(&model, &format)?)?;
+            }
+            eprintln!("Exported model for workspace '{}' to: {} ({})", workspace, file, format);
+        }
+
+        Some(Commands::ImportOpenapi {
+            spec,
+            context,
+            workspace,
+            output,
+        }) => {
+            let store = store::Store::open_default()?;
+            let drafts = import::import_files("openapi", &[spec], &context)?;
+            import_draft(&store, &workspace, drafts, output.as_deref())?;
+        }
+
+        Some(Commands::ImportAsyncapi {
+            spec,
+            context,
+            workspace,
+            output,
+        }) => {
+            let store = store::Store::open_default()?;
+            let drafts = import::import_files("asyncapi", &[spec], &context)?;
+            import_draft(&store, &workspace, drafts, output.as_deref())?;
+        }
+
+        Some(Commands::ImportSql {
+            files,
+            context,
+            workspace,
+            output,
+        }) => {
+            let store = store::Store::open_default()?;
+            let drafts = import::import_files("sql", &files, &context)?;
+            import_draft(&store, &workspace, drafts, output.as_deref())?;
         }
 
-        Some(Commands::Export { file, workspace }) => {
+        Some(Commands::Plan {
+            file,
+            workspace,
+            migrations_dir,
+            dialect,
+        }) => {
             let store = store::Store::open_default()?;
-            store.export_to_file(&workspace, &file)?;
-            eprintln!("Exported model for workspace '{}' to: {}", workspace, file);
+            let persisted = store
+                .load(&workspace)?
+                .unwrap_or_else(|| domain::model::DomainModel::empty(&workspace));
+            let mut target = domain::model::DomainModel::load(&file)?;
+            if let Some(db) = dialect {
+                if domain::migration::SqlDialect::from_database(&db).is_none() {
+                    anyhow::bail!("Unsupported SQL dialect '{db}'. Supported: postgres, sqlite");
+                }
+                target.tech_stack.database = db;
+            }
+
+            let changes = domain::diff::diff_models(&persisted, &target);
+            let plan = domain::diff::plan_refactoring(&changes, &target);
+            println!("{}", serde_json::to_string_pretty(&plan)?);
+
+            match migrations_dir {
+                Some(dir) if !plan.migrations.is_empty() => {
+                    let files = write_migrations(&dir, &plan.migrations)?;
+                    eprintln!("{} change(s). Wrote {} migration file(s) to: {}", changes.len(), files, dir);
+                }
+                Some(_) => eprintln!(
+                    "{} change(s). No SQL migrations to write (check tech_stack.database).",
+                    changes.len()
+                ),
+                None => eprintln!("{} change(s), {} SQL migration(s).", changes.len(), plan.migrations.len()),
+            }
+        }
+
+        Some(Commands::Impact {
+            name,
+            workspace,
+            max_distance,
+            format,
+        }) => {
+            let store = store::Store::open_default()?;
+            let model = store
+                .load(&workspace)?
+                .ok_or_else(|| anyhow::anyhow!("No model found for workspace: {workspace}"))?;
+            let impact = domain::impact::analyze(&model, &name, max_distance)?;
+            match format.as_str() {
+                "text" => print!("{}", impact.render()),
+                "json" => println!("{}", serde_json::to_string_pretty(&impact)?),
+                other => anyhow::bail!("Unsupported format '{other}'. Supported: text, json"),
+            }
         }
 
         Some(Commands::List) => {
@@ -123,7 +355,7 @@ async fn main() -> Result<()> {
             if projects.is_empty() {
                 eprintln!("No projects in store.");
             } else {
-                eprintln!("{:<50} {:<25} {}", "WORKSPACE", "PROJECT", "UPDATED");
+                eprintln!("{:<50} {:<25} UPDATED", "WORKSPACE", "PROJECT");
                 eprintln!("{}", "-".repeat(95));
                 for p in &projects {
                     eprintln!(
@@ -138,3 +370,59 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Merge imported contexts into the workspace's stored model without saving it.
+/// Prints the resulting change list as JSON to stdout; optionally writes the draft model.
+fn import_draft(
+    store: &store::Store,
+    workspace: &str,
+    drafts: Vec<domain::model::BoundedContext>,
+    output: Option<&str>,
+) -> Result<()> {
+    let persisted = store
+        .load(workspace)?
+        .unwrap_or_else(|| domain::model::DomainModel::empty(workspace));
+    let mut draft_model = persisted.clone();
+    for draft in drafts {
+        import::merge_context(&mut draft_model, draft);
+    }
+
+    let changes = domain::diff::diff_models(&persisted, &draft_model);
+    println!("{}", serde_json::to_string_pretty(&changes)?);
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, serde_json::to_string_pretty(&draft_model)?)?;
+            eprintln!(
+                "{} change(s). Draft model written to: {} — apply with `domcp import {} --workspace {}`",
+                changes.len(),
+                path,
+                path,
+                workspace
+            );
+        }
+        None => eprintln!(
+            "{} change(s). Nothing saved — rerun with --output to write a draft model.",
+            changes.len()
+        ),
+    }
+    Ok(())
+}
+
+/// Write each migration as `<version>_<name>.up.sql` and `.down.sql`.
+/// Versions are consecutive Unix timestamps so files sort in plan order.
+/// Returns the number of files written.
+fn write_migrations(dir: &str, migrations: &[domain::migration::SqlMigration]) -> Result<usize> {
+    std::fs::create_dir_all(dir)?;
+    let base = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs();
+    for (i, migration) in migrations.iter().enumerate() {
+        let stem = format!("{}_{}", base + i as u64, migration.name);
+        let header = format!("-- {}\n", migration.description);
+        let dir = std::path::Path::new(dir);
+        std::fs::write(dir.join(format!("{stem}.up.sql")), format!("{header}{}", migration.up))?;
+        std::fs::write(dir.join(format!("{stem}.down.sql")), format!("{header}{}", migration.down))?;
+    }
+    Ok(migrations.len() * 2)
+}
@@ -55,6 +55,45 @@ fn build_guidelines_prompt(model: &DomainModel) -> PromptGetResult {
         format!("\n### Rules\n\n{}\n", rules.join("\n"))
     };
 
+    let language: Vec<String> = model
+        .bounded_contexts
+        .iter()
+        .flat_map(|bc| {
+            bc.glossary
+                .iter()
+                .filter(|t| !t.forbidden_aliases.is_empty())
+                .map(move |t| format!("- **{}**: say *{}*, not {}", bc.name, t.term, t.forbidden_aliases.join(", ")))
+        })
+        .collect();
+    let language_section = if language.is_empty() {
+        String::new()
+    } else {
+        format!("\n### Ubiquitous language\n\n{}\n", language.join("\n"))
+    };
+
+    let deprecated: Vec<String> = model
+        .deprecated()
+        .into_iter()
+        .map(|(ctx, kind, name, meta)| {
+            let item = match kind {
+                "bounded_context" => format!("- **{ctx}** (bounded context)"),
+                _ => format!("- **{ctx}.{name}** ({})", kind.replace('_', " ")),
+            };
+            match meta.replaced_by.as_str() {
+                "" => item,
+                replacement => format!("{item} — use *{replacement}*"),
+            }
+        })
+        .collect();
+    let deprecated_section = if deprecated.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "\n### Deprecated — do not use in new code\n\n{}\n",
+            deprecated.join("\n")
+        )
+    };
+
     let text = format!(
         r#"## DOMCP — {project_name}
 
@@ -63,10 +102,11 @@ fn build_guidelines_prompt(model: &DomainModel) -> PromptGetResult {
 ### Workflow
 
 1. **Before writing code** → call `get_architecture_overview`
-2. **Before creating files** → call `suggest_file_path`
+2. **Before creating files** → call `suggest_file_path`; name types, fields and methods with the terms from `get_glossary` and check new element names with `check_naming`
 3. **Before cross-context imports** → call `validate_dependency`
-4. **After model changes** → call `compare_model`, then `draft_refactoring_plan`, then `save_model`
-{rules_section}"#
+4. **Before implementing a command, query or use case** → call `get_operation`; for event handlers and policies → call `trace_event_flow`
+5. **After model changes** → call `validate_model` and `check_rules`, `compare_model`, then `draft_refactoring_plan`, then `save_model`
+{language_section}{deprecated_section}{rules_section}"#
     );
 
     PromptGetResult {
@@ -93,11 +133,20 @@ mod tests {
                 module_path: "".into(),
                 entities: vec![],
                 value_objects: vec![],
+                enumerations: vec![],
+                aggregates: vec![],
+                commands: vec![],
+                queries: vec![],
+                use_cases: vec![],
+                policies: vec![],
+                glossary: vec![],
                 services: vec![],
                 repositories: vec![],
                 events: vec![],
                 dependencies: vec![],
+                meta: Metadata::default(),
             }],
+            external_systems: vec![],
             rules: vec![],
             tech_stack: TechStack::default(),
             conventions: Conventions::default(),
@@ -136,4 +185,47 @@ mod tests {
         };
         assert!(text.contains("Identity"));
     }
+
+    #[test]
+    fn test_prompt_lists_forbidden_aliases() {
+        let mut model = test_model();
+        model.bounded_contexts[0].glossary.push(Term {
+            term: "Member".into(),
+            definition: "".into(),
+            synonyms: vec![],
+            forbidden_aliases: vec!["Client".into(), "Customer".into()],
+        });
+        let prompt = get_prompt(&model, "domcp_guidelines").unwrap();
+        let text = match &prompt.messages[0].content {
+            ContentBlock::Text { text } => text,
+        };
+        assert!(text.contains("say *Member*, not Client, Customer"));
+    }
+
+    #[test]
+    fn test_prompt_warns_against_deprecated_elements() {
+        let mut model = test_model();
+        model.bounded_contexts[0].events.push(DomainEvent {
+            name: "UserRenamed".into(),
+            description: "".into(),
+            fields: vec![],
+            source: "".into(),
+            published: false,
+            channel: "".into(),
+            meta: Metadata {
+                owners: vec![],
+                tags: vec![],
+                status: Status::Deprecated,
+                links: vec![],
+                replaced_by: "".into(),
+                sunset: "".into(),
+            },
+        });
+        let prompt = get_prompt(&model, "domcp_guidelines").unwrap();
+        let text = match &prompt.messages[0].content {
+            ContentBlock::Text { text } => text,
+        };
+        assert!(text.contains("### Deprecated — do not use in new code"));
+        assert!(text.contains("- **Identity.UserRenamed** (event)"));
+    }
 }
@@ -1,5 +1,5 @@
 use crate::domain::model::DomainModel;
-use crate::domain::registry::DomainRegistry;
+use crate::domain::registry::{DomainRegistry, ElementQuery};
 use crate::mcp::protocol::*;
 
 /// Returns the list of resources the DOMCP server exposes.
@@ -36,6 +36,22 @@ pub fn list_resources(model: &DomainModel) -> Vec<ResourceDefinition> {
             ),
             mime_type: "application/json".into(),
         });
+        if !bc.elements().is_empty() {
+            resources.push(ResourceDefinition {
+                uri: format!("domcp://context/{}/elements", bc.name.to_lowercase()),
+                name: format!("Elements: {}", bc.name),
+                description: format!("Every element of '{}' with its kind and status", bc.name),
+                mime_type: "application/json".into(),
+            });
+        }
+        if !bc.glossary.is_empty() {
+            resources.push(ResourceDefinition {
+                uri: format!("domcp://context/{}/glossary", bc.name.to_lowercase()),
+                name: format!("Glossary: {}", bc.name),
+                description: format!("Ubiquitous language of '{}' — terms, synonyms, forbidden aliases", bc.name),
+                mime_type: "application/json".into(),
+            });
+        }
     }
 
     resources
@@ -56,8 +72,27 @@ pub fn read_resource(model: &DomainModel, uri: &str) -> ResourceReadResult {
             serde_json::to_string(&model.conventions).unwrap_or_default(),
         ),
         _ if uri.starts_with("domcp://context/") => {
-            let ctx_name = uri.strip_prefix("domcp://context/").unwrap_or("");
+            let path = uri.strip_prefix("domcp://context/").unwrap_or("");
+            let (ctx_name, part) = match path.split_once('/') {
+                Some((name, part)) => (name, part),
+                None => (path, ""),
+            };
             match registry.find_context(ctx_name) {
+                Some(bc) if part == "glossary" => (
+                    "application/json",
+                    serde_json::to_string(&bc.glossary).unwrap_or_default(),
+                ),
+                Some(bc) if part == "elements" => {
+                    let query = ElementQuery {
+                        context: Some(bc.name.clone()),
+                        ..Default::default()
+                    };
+                    (
+                        "application/json",
+                        serde_json::to_string(&registry.list_elements(&query)).unwrap_or_default(),
+                    )
+                }
+                Some(_) if !part.is_empty() => ("text/plain", format!("Unknown resource: {}", uri)),
                 Some(bc) => (
                     "application/json",
                     serde_json::to_string(bc).unwrap_or_default(),
@@ -95,11 +130,20 @@ mod tests {
                 module_path: "src/identity".into(),
                 entities: vec![],
                 value_objects: vec![],
+                enumerations: vec![],
+                aggregates: vec![],
+                commands: vec![],
+                queries: vec![],
+                use_cases: vec![],
+                policies: vec![],
+                glossary: vec![],
                 services: vec![],
                 repositories: vec![],
                 events: vec![],
                 dependencies: vec![],
+                meta: Metadata::default(),
             }],
+            external_systems: vec![],
             rules: vec![],
             tech_stack: TechStack::default(),
             conventions: Conventions::default(),
@@ -132,6 +176,39 @@ mod tests {
         assert!(result.contents[0].text.contains("Identity"));
     }
 
+    #[test]
+    fn test_glossary_resource() {
+        let mut model = test_model();
+        model.bounded_contexts[0].glossary.push(Term {
+            term: "Member".into(),
+            definition: "A registered person".into(),
+            synonyms: vec![],
+            forbidden_aliases: vec!["Client".into()],
+        });
+        assert!(list_resources(&model).iter().any(|r| r.uri == "domcp://context/identity/glossary"));
+        let result = read_resource(&model, "domcp://context/identity/glossary");
+        assert_eq!(result.contents[0].mime_type, "application/json");
+        assert!(result.contents[0].text.contains("forbidden_aliases"));
+    }
+
+    #[test]
+    fn test_elements_resource() {
+        let mut model = test_model();
+        model.bounded_contexts[0].services.push(Service {
+            name: "AuthService".into(),
+            description: "".into(),
+            kind: ServiceKind::Application,
+            methods: vec![],
+            dependencies: vec![],
+            meta: Metadata::default(),
+        });
+        assert!(list_resources(&model).iter().any(|r| r.uri == "domcp://context/identity/elements"));
+        let result = read_resource(&model, "domcp://context/identity/elements");
+        assert!(result.contents[0].text.contains(r#""kind":"service","name":"AuthService","status":"active""#));
+        let result = read_resource(&model, "domcp://context/identity/other");
+        assert!(result.contents[0].text.contains("Unknown resource"));
+    }
+
     #[test]
     fn test_read_resource_unknown() {
         let model = test_model();
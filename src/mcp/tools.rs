@@ -1,8 +1,11 @@
 use serde_json::{json, Value};
 
-use crate::domain::model::DomainModel;
-use crate::domain::registry::DomainRegistry;
-use crate::domain::to_snake;
+use crate::domain::{flow, impact, naming, relevance, rules, search, validation};
+use crate::domain::model::{
+    BoundedContext, DomainModel, Metadata, MetadataFilter, Operation, OperationKind, PathVars, ServiceKind, Status,
+    ELEMENT_KINDS,
+};
+use crate::domain::registry::{Detail, DomainRegistry, ElementQuery, LookupError, OverviewOptions, split_qualified};
 use crate::mcp::protocol::*;
 
 /// Returns the list of tools the DOMCP server exposes.
@@ -11,12 +14,38 @@ pub fn list_tools() -> Vec<ToolDefinition> {
         ToolDefinition {
             name: "get_architecture_overview".into(),
             description: "Returns a full architecture overview including bounded contexts, \
-                          entities, services, events, rules, and conventions. \
+                          entities, services, events, rules, and conventions, plus the deprecated \
+                          elements new code must not use. Pass `tag` or `status` to narrow it down. \
+                          On large models, ask for less `detail`, set a `max_tokens` budget, and name \
+                          the `focus` context you are working in to keep it and its dependencies in full. \
                           Use this before writing any new code to understand the system structure."
                 .into(),
             input_schema: json!({
                 "type": "object",
-                "properties": {},
+                "properties": {
+                    "detail": {
+                        "type": "string",
+                        "enum": ["contexts", "names", "full"],
+                        "description": "Contexts only, with element names, or full (default)"
+                    },
+                    "max_tokens": {
+                        "type": "integer",
+                        "description": "Approximate token budget; contexts outside the focus are shortened, last declared first"
+                    },
+                    "focus": {
+                        "type": "string",
+                        "description": "Context kept at full detail together with its direct dependencies"
+                    },
+                    "tag": {
+                        "type": "string",
+                        "description": "Only contexts and elements with this tag"
+                    },
+                    "status": {
+                        "type": "string",
+                        "enum": ["proposed", "active", "deprecated"],
+                        "description": "Only contexts and elements with this lifecycle status"
+                    }
+                },
                 "required": []
             }),
         },
@@ -24,7 +53,8 @@ pub fn list_tools() -> Vec<ToolDefinition> {
             name: "get_bounded_context".into(),
             description: "Returns detailed information about a specific bounded context, \
                           including its entities, value objects, services, repositories, \
-                          domain events, and allowed dependencies."
+                          domain events, allowed dependencies, owners and status. \
+                          Pass `tag` or `status` to list only matching elements."
                 .into(),
             input_schema: json!({
                 "type": "object",
@@ -32,6 +62,15 @@ pub fn list_tools() -> Vec<ToolDefinition> {
                     "name": {
                         "type": "string",
                         "description": "Name of the bounded context"
+                    },
+                    "tag": {
+                        "type": "string",
+                        "description": "Only elements with this tag"
+                    },
+                    "status": {
+                        "type": "string",
+                        "enum": ["proposed", "active", "deprecated"],
+                        "description": "Only elements with this lifecycle status"
                     }
                 },
                 "required": ["name"]
@@ -40,7 +79,10 @@ pub fn list_tools() -> Vec<ToolDefinition> {
         ToolDefinition {
             name: "get_entity".into(),
             description: "Returns the full specification of a domain entity including fields, \
-                          methods, invariants, and whether it is an aggregate root. \
+                          methods, invariants, the aggregate it belongs to with its root, the \
+                          enumerations its field types refer to, and a deprecation warning naming \
+                          its replacement when it must no longer be used. \
+                          Mutations of child entities must go through the aggregate root. \
                           Use this when implementing or modifying an entity."
                 .into(),
             input_schema: json!({
@@ -54,6 +96,23 @@ pub fn list_tools() -> Vec<ToolDefinition> {
                 "required": ["name"]
             }),
         },
+        ToolDefinition {
+            name: "get_state_machine".into(),
+            description: "Returns an entity's lifecycle: states, initial state, and transitions \
+                          with the method that performs each and the event it emits, plus a \
+                          Mermaid state diagram. Only call a transition's method in its `from` state."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "entity": {
+                        "type": "string",
+                        "description": "Name of the entity"
+                    }
+                },
+                "required": ["entity"]
+            }),
+        },
         ToolDefinition {
             name: "get_service_spec".into(),
             description: "Returns the specification for a domain/application/infrastructure \
@@ -70,11 +129,176 @@ pub fn list_tools() -> Vec<ToolDefinition> {
                 "required": ["name"]
             }),
         },
+        ToolDefinition {
+            name: "get_value_object".into(),
+            description: "Returns a value object's fields and validation rules, the aggregate it \
+                          belongs to, the enumerations its fields use and every element using it."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "Value object name, e.g. Email"
+                    }
+                },
+                "required": ["name"]
+            }),
+        },
+        ToolDefinition {
+            name: "get_repository".into(),
+            description: "Returns a repository's methods and the aggregate root it persists, \
+                          with that root's invariants."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "Repository name, e.g. UserRepository"
+                    }
+                },
+                "required": ["name"]
+            }),
+        },
+        ToolDefinition {
+            name: "get_event".into(),
+            description: "Returns a domain event's payload, source and channel, the operations \
+                          that emit it and the policies that react to it."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "Event name, e.g. UserRegistered"
+                    }
+                },
+                "required": ["name"]
+            }),
+        },
+        ToolDefinition {
+            name: "list_elements".into(),
+            description: "Lists element names with their context, kind and status. Filter by \
+                          `kind`, `context`, `tag`, `status`, or only aggregate roots. Use this to \
+                          find what exists before asking for one element's details."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "kind": {
+                        "type": "string",
+                        "enum": ELEMENT_KINDS,
+                        "description": "Only elements of this kind"
+                    },
+                    "context": {
+                        "type": "string",
+                        "description": "Only elements of this bounded context"
+                    },
+                    "aggregate_roots_only": {
+                        "type": "boolean",
+                        "description": "Only entities that are aggregate roots"
+                    },
+                    "tag": {
+                        "type": "string",
+                        "description": "Only elements with this tag"
+                    },
+                    "status": {
+                        "type": "string",
+                        "enum": ["proposed", "active", "deprecated"],
+                        "description": "Only elements with this lifecycle status"
+                    }
+                },
+                "required": []
+            }),
+        },
+        ToolDefinition {
+            name: "get_operation".into(),
+            description: "Returns a command, query or use case with its whole flow: the handling \
+                          service, the aggregate it loads, the invariants it must check, the \
+                          events it emits and, for use cases, the commands and queries it runs. \
+                          Use this when implementing a handler or asking what happens on an action."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "Name of the command, query or use case"
+                    }
+                },
+                "required": ["name"]
+            }),
+        },
+        ToolDefinition {
+            name: "trace_event_flow".into(),
+            description: "Starting from a command or domain event, walks emit → react chains \
+                          across bounded contexts (command emits event, policy reacts to event, \
+                          policy issues command) and returns the ordered flow. Reactions that \
+                          cross into a context not listed in `dependencies` are flagged."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "start": {
+                        "type": "string",
+                        "description": "Name of the command or event to start from"
+                    }
+                },
+                "required": ["start"]
+            }),
+        },
+        ToolDefinition {
+            name: "get_relevant_context".into(),
+            description: "Returns only the contexts, elements, rules and conventions likely to matter \
+                          for a task, ranked by how well they match its words, the glossary and their \
+                          references. Prefer this over the full overview when starting a specific task \
+                          such as \"add a refund endpoint\"."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "task": {
+                        "type": "string",
+                        "description": "What you are about to do, in plain words"
+                    },
+                    "limit": {
+                        "type": "integer",
+                        "description": "Maximum number of elements (default 12)"
+                    }
+                },
+                "required": ["task"]
+            }),
+        },
+        ToolDefinition {
+            name: "analyze_impact".into(),
+            description: "Lists everything that may have to change along with an element: events it \
+                          sources, repositories aggregating it, fields typed as it or its id, operations, \
+                          policies and services reaching it, services of contexts depending on its \
+                          context, and rules scoped to it. Grouped by context, ranked by distance. \
+                          Call this before changing or removing an element."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "Element to change, e.g. User"
+                    },
+                    "max_distance": {
+                        "type": "integer",
+                        "description": "How many reference hops to follow (default 3)"
+                    }
+                },
+                "required": ["name"]
+            }),
+        },
         ToolDefinition {
             name: "validate_dependency".into(),
-            description: "Checks whether a dependency from one bounded context to another \
-                          is allowed per the architectural rules. Returns allowed/denied \
-                          with explanation."
+            description: "Checks whether a dependency from one bounded context to another, \
+                          or to an external system, is allowed per the architectural rules. \
+                          Returns allowed/denied with explanation and, for external systems, \
+                          the adapter service to go through."
                 .into(),
             input_schema: json!({
                 "type": "object",
@@ -85,7 +309,7 @@ pub fn list_tools() -> Vec<ToolDefinition> {
                     },
                     "to_context": {
                         "type": "string",
-                        "description": "Target bounded context name"
+                        "description": "Target bounded context or external system name"
                     }
                 },
                 "required": ["from_context", "to_context"]
@@ -102,6 +326,62 @@ pub fn list_tools() -> Vec<ToolDefinition> {
                 "required": []
             }),
         },
+        ToolDefinition {
+            name: "check_rules".into(),
+            description: "Evaluates every rule that has a machine-checkable `check` and returns the \
+                          violations with their rule ID and severity. Pass the import edges between \
+                          workspace files to also check layer rules against the code, e.g. a domain \
+                          file importing an infrastructure one."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "imports": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "from": { "type": "string", "description": "Importing file, e.g. src/billing/domain/invoice.rs" },
+                                "to": { "type": "string", "description": "Imported file" }
+                            },
+                            "required": ["from", "to"]
+                        }
+                    },
+                    "rule": {
+                        "type": "string",
+                        "description": "Only evaluate this rule ID"
+                    }
+                },
+                "required": []
+            }),
+        },
+        ToolDefinition {
+            name: "check_naming".into(),
+            description: "Checks a proposed element name against the naming convention of its kind \
+                          (case, prefix, suffix, pattern, tense) before you create it. Returns the \
+                          problems, a conforming suggestion where one can be derived, and where the \
+                          name is already taken."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "kind": {
+                        "type": "string",
+                        "enum": ["entity", "value_object", "enumeration", "command", "query", "service", "repository", "event"],
+                        "description": "Kind of element the name is for"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Proposed name, optionally qualified as Context::Name"
+                    },
+                    "context": {
+                        "type": "string",
+                        "description": "Bounded context the element will be created in; a name already taken there is a problem"
+                    }
+                },
+                "required": ["kind", "name"]
+            }),
+        },
         ToolDefinition {
             name: "get_conventions".into(),
             description: "Returns naming conventions, file structure patterns, error handling \
@@ -113,11 +393,76 @@ pub fn list_tools() -> Vec<ToolDefinition> {
                 "required": []
             }),
         },
+        ToolDefinition {
+            name: "get_glossary".into(),
+            description: "Returns the ubiquitous language: per bounded context, each term with its \
+                          definition, synonyms and forbidden aliases. Pass `term` to see what a word \
+                          means in every context, or which term to use instead. \
+                          Name new types, fields and methods with these terms."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "context": {
+                        "type": "string",
+                        "description": "Only this bounded context's glossary"
+                    },
+                    "term": {
+                        "type": "string",
+                        "description": "Look up a single word across contexts"
+                    }
+                },
+                "required": []
+            }),
+        },
+        ToolDefinition {
+            name: "search_model".into(),
+            description: "Searches the whole model — names, descriptions, fields, methods, invariants, \
+                          glossary terms and rules — and returns ranked matches with where they matched. \
+                          Tolerates plurals, partial words and typos. Use this when you do not know \
+                          the exact name of an element."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "Words to look for, e.g. \"subscription plan\""
+                    },
+                    "kind": {
+                        "type": "string",
+                        "enum": ["bounded_context", "entity", "value_object", "enumeration", "aggregate", "command", "query", "use_case", "policy", "service", "repository", "event", "term", "external_system", "rule"],
+                        "description": "Only results of this kind"
+                    },
+                    "limit": {
+                        "type": "integer",
+                        "description": "Maximum number of results (default 10)"
+                    }
+                },
+                "required": ["query"]
+            }),
+        },
+        ToolDefinition {
+            name: "validate_model".into(),
+            description: "Checks the domain model for problems that do not block saving, such as \
+                          element names that use a word the context's glossary forbids or \
+                          entity fields that hold another aggregate root instead of its id. \
+                          Returns findings with severity and element path."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {},
+                "required": []
+            }),
+        },
         ToolDefinition {
             name: "suggest_file_path".into(),
-            description: "Given a type category (entity, service, repository, event, value_object) \
-                          and a bounded context, suggests the correct file path following project \
-                          conventions."
+            description: "Given a type category (entity, service, repository, event, value_object, enumeration, \
+                          command, query, use_case, policy) and a bounded context, suggests the correct \
+                          file path following project conventions and the configured layer of that \
+                          kind. Also lists every file the element needs with its role, e.g. a \
+                          repository's implementation and trait, its tests and the module to register \
+                          it in. A service is placed by its service kind."
                 .into(),
             input_schema: json!({
                 "type": "object",
@@ -128,12 +473,17 @@ pub fn list_tools() -> Vec<ToolDefinition> {
                     },
                     "kind": {
                         "type": "string",
-                        "enum": ["entity", "value_object", "service", "repository", "event"],
+                        "enum": ELEMENT_KINDS,
                         "description": "Type of domain artifact"
                     },
                     "name": {
                         "type": "string",
                         "description": "Name of the artifact"
+                    },
+                    "service_kind": {
+                        "type": "string",
+                        "enum": ["domain", "application", "infrastructure"],
+                        "description": "Kind of a new service; defaults to the modelled service's kind, else application"
                     }
                 },
                 "required": ["context", "kind", "name"]
@@ -148,48 +498,285 @@ pub fn call_tool(model: &DomainModel, name: &str, args: &Value) -> ToolCallResul
 
     match name {
         "get_architecture_overview" => {
-            let summary = registry.architecture_summary();
-            text_result(summary)
+            let filter = match metadata_filter(args) {
+                Ok(filter) => filter,
+                Err(e) => return error_result(e),
+            };
+            let detail = match args["detail"].as_str().filter(|d| !d.is_empty()) {
+                Some(d) => match Detail::parse(d) {
+                    Some(detail) => detail,
+                    None => return error_result(format!("Unknown detail '{d}'. Use contexts, names or full")),
+                },
+                None => Detail::Full,
+            };
+            let options = OverviewOptions {
+                detail,
+                max_tokens: args["max_tokens"].as_u64().map(|t| t as usize),
+                focus: args["focus"].as_str().map(String::from),
+            };
+            let overview = if filter.is_empty() {
+                registry.overview(&options)
+            } else {
+                DomainRegistry::new(&model.filtered(&filter)).overview(&options)
+            };
+            match overview {
+                Ok(text) => text_result(text),
+                Err(e) => error_result(e.to_string()),
+            }
         }
 
         "get_bounded_context" => {
             let ctx_name = args["name"].as_str().unwrap_or("");
+            let filter = match metadata_filter(args) {
+                Ok(filter) => filter,
+                Err(e) => return error_result(e),
+            };
             match registry.find_context(ctx_name) {
-                Some(bc) => text_result(serde_json::to_string(bc).unwrap()),
-                None => error_result(format!(
-                    "Bounded context '{}' not found. Available: {}",
+                Some(bc) if filter.is_empty() => text_result(serde_json::to_string(bc).unwrap()),
+                Some(bc) => match bc.filtered(&filter) {
+                    Some(narrowed) => text_result(serde_json::to_string(&narrowed).unwrap()),
+                    None => error_result(format!("Nothing in bounded context '{}' matches the filter", bc.name)),
+                },
+                None => not_found(
+                    format!(
+                        "Bounded context '{}' not found. Available: {}",
+                        ctx_name,
+                        registry.context_names().join(", ")
+                    ),
                     ctx_name,
-                    registry.context_names().join(", ")
-                )),
+                    registry.context_names(),
+                ),
             }
         }
 
         "get_entity" => {
             let entity_name = args["name"].as_str().unwrap_or("");
             match registry.find_entity(entity_name) {
-                Some((bc, entity)) => {
+                Ok((bc, entity)) => {
+                    let aggregate = bc.aggregate_of(&entity.name).map(|(agg, root)| {
+                        json!({
+                            "name": agg,
+                            "root": root,
+                            "is_root": root.eq_ignore_ascii_case(&entity.name),
+                        })
+                    });
+                    let enumerations: Vec<_> = registry
+                        .referenced_enumerations(&bc.name, &entity.fields)
+                        .into_iter()
+                        .map(|(owner, e)| json!({ "bounded_context": owner.name, "enumeration": e }))
+                        .collect();
                     let result = json!({
                         "bounded_context": bc.name,
                         "module_path": bc.module_path,
                         "entity": entity,
+                        "deprecation": deprecation(model, &bc.name, &entity.name, &entity.meta),
+                        "aggregate": aggregate,
+                        "enumerations": enumerations,
                     });
                     text_result(serde_json::to_string(&result).unwrap())
                 }
-                None => error_result(format!("Entity '{}' not found in any bounded context", entity_name)),
+                Err(e) => lookup_failed(
+                    e,
+                    format!("Entity '{}' not found in any bounded context", entity_name),
+                    entity_name,
+                    registry.element_names(&["entity"]),
+                ),
+            }
+        }
+
+        "get_state_machine" => {
+            let entity_name = args["entity"].as_str().unwrap_or("");
+            match registry.find_entity(entity_name) {
+                Ok((bc, entity)) => match &entity.state_machine {
+                    Some(sm) => {
+                        let result = json!({
+                            "bounded_context": bc.name,
+                            "entity": entity.name,
+                            "state_machine": sm,
+                            "terminal_states": sm.terminal_states(),
+                            "mermaid": sm.to_mermaid(),
+                        });
+                        text_result(serde_json::to_string(&result).unwrap())
+                    }
+                    None => error_result(format!("Entity '{}' has no state machine", entity.name)),
+                },
+                Err(e) => lookup_failed(
+                    e,
+                    format!("Entity '{}' not found in any bounded context", entity_name),
+                    entity_name,
+                    registry.element_names(&["entity"]),
+                ),
             }
         }
 
         "get_service_spec" => {
             let svc_name = args["name"].as_str().unwrap_or("");
             match registry.find_service(svc_name) {
-                Some((bc, svc)) => {
+                Ok((bc, svc)) => {
                     let result = json!({
                         "bounded_context": bc.name,
                         "service": svc,
+                        "deprecation": deprecation(model, &bc.name, &svc.name, &svc.meta),
+                    });
+                    text_result(serde_json::to_string(&result).unwrap())
+                }
+                Err(e) => lookup_failed(
+                    e,
+                    format!("Service '{}' not found", svc_name),
+                    svc_name,
+                    registry.element_names(&["service"]),
+                ),
+            }
+        }
+
+        "get_value_object" => {
+            let vo_name = args["name"].as_str().unwrap_or("");
+            match registry.find_value_object(vo_name) {
+                Ok((bc, vo)) => {
+                    let aggregate = bc
+                        .aggregate_of(&vo.name)
+                        .map(|(agg, root)| json!({ "name": agg, "root": root }));
+                    let enumerations: Vec<_> = registry
+                        .referenced_enumerations(&bc.name, &vo.fields)
+                        .into_iter()
+                        .map(|(owner, e)| json!({ "bounded_context": owner.name, "enumeration": e }))
+                        .collect();
+                    let result = json!({
+                        "bounded_context": bc.name,
+                        "module_path": bc.module_path,
+                        "value_object": vo,
+                        "deprecation": deprecation(model, &bc.name, &vo.name, &vo.meta),
+                        "aggregate": aggregate,
+                        "enumerations": enumerations,
+                        "used_by": registry.usages(&bc.name, &vo.name),
+                    });
+                    text_result(serde_json::to_string(&result).unwrap())
+                }
+                Err(e) => lookup_failed(
+                    e,
+                    format!("Value object '{}' not found in any bounded context", vo_name),
+                    vo_name,
+                    registry.element_names(&["value_object"]),
+                ),
+            }
+        }
+
+        "get_repository" => {
+            let repo_name = args["name"].as_str().unwrap_or("");
+            match registry.find_repository(repo_name) {
+                Ok((bc, repo)) => {
+                    let root = registry
+                        .find_entity(&format!("{}::{}", bc.name, repo.aggregate))
+                        .ok()
+                        .map(|(_, e)| json!({ "root": e.name, "invariants": e.invariants }));
+                    let result = json!({
+                        "bounded_context": bc.name,
+                        "repository": repo,
+                        "deprecation": deprecation(model, &bc.name, &repo.name, &repo.meta),
+                        "aggregate": root,
+                    });
+                    text_result(serde_json::to_string(&result).unwrap())
+                }
+                Err(e) => lookup_failed(
+                    e,
+                    format!("Repository '{}' not found in any bounded context", repo_name),
+                    repo_name,
+                    registry.element_names(&["repository"]),
+                ),
+            }
+        }
+
+        "get_event" => {
+            let event_name = args["name"].as_str().unwrap_or("");
+            match registry.find_event(event_name) {
+                Ok((bc, event)) => {
+                    let (emitted_by, reacted_by): (Vec<_>, Vec<_>) = registry
+                        .usages(&bc.name, &event.name)
+                        .into_iter()
+                        .filter(|u| u.via == "emits" || u.via == "reacts to")
+                        .partition(|u| u.via == "emits");
+                    let result = json!({
+                        "bounded_context": bc.name,
+                        "event": event,
+                        "deprecation": deprecation(model, &bc.name, &event.name, &event.meta),
+                        "emitted_by": emitted_by,
+                        "reacted_by": reacted_by,
                     });
                     text_result(serde_json::to_string(&result).unwrap())
                 }
-                None => error_result(format!("Service '{}' not found", svc_name)),
+                Err(e) => lookup_failed(
+                    e,
+                    format!("Event '{}' not found in any bounded context", event_name),
+                    event_name,
+                    registry.element_names(&["event"]),
+                ),
+            }
+        }
+
+        "list_elements" => {
+            let meta = match metadata_filter(args) {
+                Ok(filter) => filter,
+                Err(e) => return error_result(e),
+            };
+            let context = args["context"].as_str().filter(|c| !c.is_empty());
+            if let Some(ctx_name) = context {
+                if registry.find_context(ctx_name).is_none() {
+                    return not_found(
+                        format!("Bounded context '{}' not found", ctx_name),
+                        ctx_name,
+                        registry.context_names(),
+                    );
+                }
+            }
+            let query = ElementQuery {
+                kind: args["kind"].as_str().filter(|k| !k.is_empty()).map(String::from),
+                context: context.map(String::from),
+                aggregate_roots_only: args["aggregate_roots_only"].as_bool().unwrap_or(false),
+                meta,
+            };
+            text_result(serde_json::to_string(&registry.list_elements(&query)).unwrap())
+        }
+
+        "get_operation" => {
+            let op_name = args["name"].as_str().unwrap_or("");
+            match registry.find_operation(op_name) {
+                Ok((bc, kind, op)) => {
+                    let mut flow = operation_flow(bc, kind, op);
+                    flow["deprecation"] = deprecation(model, &bc.name, &op.name, &op.meta);
+                    text_result(serde_json::to_string(&flow).unwrap())
+                }
+                Err(e) => lookup_failed(
+                    e,
+                    format!("Command, query or use case '{}' not found in any bounded context", op_name),
+                    op_name,
+                    registry.element_names(&["command", "query", "use_case"]),
+                ),
+            }
+        }
+
+        "trace_event_flow" => {
+            let start = args["start"].as_str().unwrap_or("");
+            match flow::trace(model, start) {
+                Ok(flow) => text_result(serde_json::to_string(&flow).unwrap()),
+                Err(e) => error_result(e.to_string()),
+            }
+        }
+
+        "get_relevant_context" => {
+            let task = args["task"].as_str().unwrap_or("");
+            if task.trim().is_empty() {
+                return error_result("Describe the task to find the relevant context for".into());
+            }
+            let limit = args["limit"].as_u64().unwrap_or(12) as usize;
+            text_result(serde_json::to_string(&relevance::relevant(model, task, limit)).unwrap())
+        }
+
+        "analyze_impact" => {
+            let name = args["name"].as_str().unwrap_or("");
+            let max_distance = args["max_distance"].as_u64().unwrap_or(3) as usize;
+            match impact::analyze(model, name, max_distance) {
+                Ok(impact) => text_result(serde_json::to_string(&impact).unwrap()),
+                Err(e) => error_result(e.to_string()),
             }
         }
 
@@ -197,72 +784,248 @@ pub fn call_tool(model: &DomainModel, name: &str, args: &Value) -> ToolCallResul
             let from = args["from_context"].as_str().unwrap_or("");
             let to = args["to_context"].as_str().unwrap_or("");
 
-            match registry.find_context(from) {
-                Some(bc) => {
-                    let allowed = bc.dependencies.iter().any(|d| d.eq_ignore_ascii_case(to));
+            let Some(bc) = registry.find_context(from) else {
+                return not_found(format!("Bounded context '{}' not found", from), from, registry.context_names());
+            };
+
+            if registry.find_context(to).is_none() {
+                if let Some(system) = model.find_external_system(to) {
+                    let integration = system.integration(&bc.name);
                     let result = json!({
                         "from": from,
                         "to": to,
-                        "allowed": allowed,
-                        "explanation": if allowed {
-                            format!("'{}' is an allowed dependency of '{}'", to, from)
-                        } else {
-                            format!(
-                                "'{}' is NOT allowed to depend on '{}'. Allowed dependencies: {}",
+                        "external": true,
+                        "allowed": integration.is_some(),
+                        "adapter": integration.map(|i| &i.adapter).filter(|a| !a.is_empty()),
+                        "explanation": match integration {
+                            Some(i) if !i.adapter.is_empty() => format!(
+                                "'{}' integrates with external system '{}' through '{}'; call it only from that adapter",
+                                from, system.name, i.adapter
+                            ),
+                            Some(_) => format!("'{}' integrates with external system '{}'", from, system.name),
+                            None => format!(
+                                "'{}' has no integration with external system '{}'. Integrated contexts: {}",
                                 from,
-                                to,
-                                if bc.dependencies.is_empty() {
+                                system.name,
+                                if system.integrations.is_empty() {
                                     "none".to_string()
                                 } else {
-                                    bc.dependencies.join(", ")
+                                    system.integrations.iter().map(|i| i.context.as_str()).collect::<Vec<_>>().join(", ")
                                 }
-                            )
+                            ),
                         }
                     });
-                    text_result(serde_json::to_string(&result).unwrap())
+                    return text_result(serde_json::to_string(&result).unwrap());
                 }
-                None => error_result(format!("Bounded context '{}' not found", from)),
             }
+
+            let allowed = bc.dependencies.iter().any(|d| d.eq_ignore_ascii_case(to));
+            let result = json!({
+                "from": from,
+                "to": to,
+                "allowed": allowed,
+                "explanation": if allowed {
+                    format!("'{}' is an allowed dependency of '{}'", to, from)
+                } else {
+                    format!(
+                        "'{}' is NOT allowed to depend on '{}'. Allowed dependencies: {}",
+                        from,
+                        to,
+                        if bc.dependencies.is_empty() {
+                            "none".to_string()
+                        } else {
+                            bc.dependencies.join(", ")
+                        }
+                    )
+                }
+            });
+            text_result(serde_json::to_string(&result).unwrap())
         }
 
         "get_architectural_rules" => {
             text_result(serde_json::to_string(&model.rules).unwrap())
         }
 
+        "check_rules" => {
+            let imports: Vec<rules::Import> = match args.get("imports").filter(|v| !v.is_null()) {
+                Some(v) => match serde_json::from_value(v.clone()) {
+                    Ok(imports) => imports,
+                    Err(e) => return error_result(format!("Invalid imports: {e}")),
+                },
+                None => vec![],
+            };
+            let only = args["rule"].as_str().filter(|r| !r.is_empty());
+            let mut model = model.clone();
+            if let Some(id) = only {
+                model.rules.retain(|r| r.id.eq_ignore_ascii_case(id));
+                if model.rules.is_empty() {
+                    return error_result(format!("Rule '{id}' not found"));
+                }
+            }
+            let unchecked: Vec<_> = model.rules.iter().filter(|r| r.check.is_empty()).map(|r| &r.id).collect();
+            let result = json!({
+                "violations": rules::evaluate(&model, &imports),
+                "unchecked": unchecked,
+            });
+            text_result(serde_json::to_string(&result).unwrap())
+        }
+
+        "check_naming" => {
+            let kind = args["kind"].as_str().unwrap_or("");
+            let Some(rule) = model.conventions.naming.for_kind(kind) else {
+                let kinds: Vec<_> = model.conventions.naming.rules().iter().map(|(k, _)| *k).collect();
+                return error_result(format!("Unknown kind '{kind}'; use one of {}", kinds.join(", ")));
+            };
+            let (qualifier, name) = split_qualified(args["name"].as_str().unwrap_or(""));
+            if name.is_empty() {
+                return error_result("Give the name to check".into());
+            }
+            let context = qualifier.or(args["context"].as_str().filter(|c| !c.is_empty()));
+            let target = match context {
+                Some(ctx_name) => match registry.find_context(ctx_name) {
+                    Some(bc) => Some(bc.name.as_str()),
+                    None => {
+                        return not_found(
+                            format!("Bounded context '{}' not found", ctx_name),
+                            ctx_name,
+                            registry.context_names(),
+                        )
+                    }
+                },
+                None => None,
+            };
+            let mut problems = naming::problems(rule, name);
+            let mut existing = Vec::new();
+            for bc in &model.bounded_contexts {
+                for (k, n, _) in bc.elements() {
+                    if n.eq_ignore_ascii_case(name) {
+                        existing.push(format!("{}::{n} ({})", bc.name, k.replace('_', " ")));
+                        if target == Some(bc.name.as_str()) {
+                            problems.push(format!("is already taken in {} by a {}", bc.name, k.replace('_', " ")));
+                        }
+                    }
+                }
+            }
+            let result = json!({
+                "name": name,
+                "kind": kind,
+                "valid": problems.is_empty(),
+                "problems": problems,
+                "suggestion": naming::suggest(rule, name),
+                "existing": existing,
+                "convention": rule,
+            });
+            text_result(serde_json::to_string(&result).unwrap())
+        }
+
         "get_conventions" => {
             text_result(serde_json::to_string(&model.conventions).unwrap())
         }
 
+        "get_glossary" => {
+            let ctx_name = args["context"].as_str().unwrap_or("");
+            let contexts: Vec<&BoundedContext> = if ctx_name.is_empty() {
+                model.bounded_contexts.iter().collect()
+            } else {
+                match registry.find_context(ctx_name) {
+                    Some(bc) => vec![bc],
+                    None => {
+                        return not_found(
+                            format!("Bounded context '{}' not found", ctx_name),
+                            ctx_name,
+                            registry.context_names(),
+                        )
+                    }
+                }
+            };
+
+            match args["term"].as_str().filter(|t| !t.is_empty()) {
+                Some(word) => {
+                    let mut meanings = Vec::new();
+                    for bc in &contexts {
+                        if let Some(term) = bc.find_term(word) {
+                            meanings.push(json!({ "bounded_context": bc.name, "term": term }));
+                        }
+                        for term in bc.glossary.iter().filter(|t| t.forbids(word)) {
+                            meanings.push(json!({
+                                "bounded_context": bc.name,
+                                "forbidden": true,
+                                "use_instead": term.term,
+                            }));
+                        }
+                    }
+                    if meanings.is_empty() {
+                        return error_result(format!("'{}' is not in any glossary", word));
+                    }
+                    text_result(json!({ "word": word, "meanings": meanings }).to_string())
+                }
+                None => {
+                    let glossaries: Vec<_> = contexts
+                        .iter()
+                        .filter(|bc| !bc.glossary.is_empty() || !ctx_name.is_empty())
+                        .map(|bc| json!({ "bounded_context": bc.name, "glossary": bc.glossary }))
+                        .collect();
+                    text_result(serde_json::to_string(&glossaries).unwrap())
+                }
+            }
+        }
+
+        "search_model" => {
+            let query = args["query"].as_str().unwrap_or("");
+            let kind = args["kind"].as_str().unwrap_or("");
+            let limit = args["limit"].as_u64().unwrap_or(10) as usize;
+            let hits: Vec<_> = search::search(model, query, usize::MAX)
+                .into_iter()
+                .filter(|h| kind.is_empty() || h.kind == kind)
+                .take(limit)
+                .collect();
+            text_result(json!({ "query": query, "results": hits }).to_string())
+        }
+
+        "validate_model" => {
+            let findings = validation::check(model);
+            text_result(json!({ "findings": findings }).to_string())
+        }
+
         "suggest_file_path" => {
             let context = args["context"].as_str().unwrap_or("");
             let kind = args["kind"].as_str().unwrap_or("");
             let artifact_name = args["name"].as_str().unwrap_or("");
-            let pattern = &model.conventions.file_structure.pattern;
-
-            // Map artifact kind to the architectural layer
-            let layer = match kind {
-                "entity" | "value_object" | "event" => "domain",
-                "service" => "application",
-                "repository" => "infrastructure",
-                other => other,
+            let files = &model.conventions.file_structure;
+            let pattern = &files.pattern;
+
+            let bc = registry.find_context(context);
+
+            // A service goes to the layer of its kind: as given, else as modelled
+            let service_kind = match args["service_kind"].as_str().filter(|k| !k.is_empty()) {
+                Some(k) => match serde_json::from_value::<ServiceKind>(json!(k)) {
+                    Ok(k) => Some(k),
+                    Err(_) => return error_result(format!("Unknown service kind '{k}'; use domain, application or infrastructure")),
+                },
+                None => bc
+                    .and_then(|bc| bc.services.iter().find(|s| s.name.eq_ignore_ascii_case(artifact_name)))
+                    .map(|s| s.kind.clone()),
+            };
+            let vars = PathVars {
+                context,
+                module_path: bc.map_or("", |bc| &bc.module_path),
+                layer: "",
+                kind,
+                name: artifact_name,
             };
+            let element_files = files.element_files(&vars, service_kind.as_ref());
 
-            if pattern.is_empty() {
+            if pattern.is_empty() && !files.artifacts.contains_key(kind) {
                 return text_result(format!(
-                    "No file structure pattern configured. Suggested: src/{}/{}/{}.rs",
-                    to_snake(context),
-                    layer,
-                    to_snake(artifact_name)
+                    "No file structure pattern configured. Suggested: {}",
+                    element_files[0].path
                 ));
             }
 
-            let path = pattern
-                .replace("{context}", &to_snake(context))
-                .replace("{layer}", layer)
-                .replace("{type}", &to_snake(artifact_name));
-
             text_result(json!({
-                "suggested_path": path,
+                "suggested_path": element_files[0].path,
+                "files": element_files,
+                "layer": files.kind_layer(kind, service_kind.as_ref()),
                 "pattern": pattern,
             }).to_string())
         }
@@ -271,6 +1034,136 @@ pub fn call_tool(model: &DomainModel, name: &str, args: &Value) -> ToolCallResul
     }
 }
 
+/// Resolve an operation against its context and list what happens, in order:
+/// handler, aggregate load, invariant checks, use case steps, save, events, result.
+fn operation_flow(bc: &BoundedContext, kind: OperationKind, op: &Operation) -> Value {
+    let root = bc
+        .entities
+        .iter()
+        .find(|e| !op.aggregate.is_empty() && e.name.eq_ignore_ascii_case(&op.aggregate));
+    let repository = root.and_then(|r| {
+        bc.repositories
+            .iter()
+            .find(|repo| repo.aggregate.eq_ignore_ascii_case(&r.name))
+    });
+    let handler = bc
+        .services
+        .iter()
+        .find(|s| s.name.eq_ignore_ascii_case(&op.handler));
+    let events: Vec<_> = op
+        .emits
+        .iter()
+        .filter_map(|name| bc.events.iter().find(|e| e.name.eq_ignore_ascii_case(name)))
+        .collect();
+    let steps: Vec<_> = op
+        .steps
+        .iter()
+        .filter_map(|name| bc.find_operation(name))
+        .collect();
+
+    let mut flow = Vec::new();
+    if let Some(svc) = handler {
+        flow.push(format!("{} handles {}", svc.name, op.name));
+    }
+    if let Some(root) = root {
+        flow.push(match repository {
+            Some(repo) => format!("Load {} through {}", root.name, repo.name),
+            None => format!("Load {}", root.name),
+        });
+    }
+    // Entity invariants guard state changes, so only commands re-check the root's
+    let root_invariants = root
+        .filter(|_| kind == OperationKind::Command)
+        .map(|r| r.invariants.as_slice())
+        .unwrap_or_default();
+    for inv in op.invariants.iter().chain(root_invariants) {
+        flow.push(format!("Check: {inv}"));
+    }
+    for (step_kind, step) in &steps {
+        let mut line = format!("Run {} {}", step_kind.label().to_lowercase(), step.name);
+        if !step.emits.is_empty() {
+            line.push_str(&format!(" (emits {})", step.emits.join(", ")));
+        }
+        flow.push(line);
+    }
+    if let (OperationKind::Command, Some(root), Some(repo)) = (kind, root, repository) {
+        flow.push(format!("Save {} through {}", root.name, repo.name));
+    }
+    for event in &events {
+        flow.push(match (event.published, event.channel.as_str()) {
+            (true, "") => format!("Emit {} (published)", event.name),
+            (true, channel) => format!("Emit {} (published on {})", event.name, channel),
+            (false, _) => format!("Emit {}", event.name),
+        });
+    }
+    if !op.returns.is_empty() {
+        flow.push(format!("Return {}", op.returns));
+    }
+
+    json!({
+        "bounded_context": bc.name,
+        "kind": kind,
+        "operation": op,
+        "aggregate": root.map(|r| json!({ "root": r.name, "invariants": r.invariants })),
+        "handler": handler,
+        "events": events,
+        "steps": steps.iter().map(|(k, s)| json!({ "kind": k, "name": s.name, "emits": s.emits })).collect::<Vec<_>>(),
+        "flow": flow,
+    })
+}
+
+/// The warning and the replacement's spec for a deprecated element, `null` otherwise.
+fn deprecation(model: &DomainModel, context: &str, name: &str, meta: &Metadata) -> Value {
+    let Some(warning) = meta.deprecation_warning(name) else {
+        return Value::Null;
+    };
+    let replacement = model
+        .bounded_contexts
+        .iter()
+        .find(|bc| bc.name.eq_ignore_ascii_case(context))
+        .and_then(|bc| bc.elements().into_iter().find(|(_, n, _)| n.eq_ignore_ascii_case(&meta.replaced_by)))
+        .map(|(kind, name, _)| json!({ "kind": kind, "name": name }));
+    json!({
+        "warning": warning,
+        "replaced_by": replacement,
+        "sunset": meta.sunset,
+    })
+}
+
+/// The error for a lookup that failed: the qualified candidates when the name is
+/// ambiguous, otherwise `message` with did-you-mean suggestions.
+fn lookup_failed(err: LookupError, message: String, name: &str, candidates: Vec<&str>) -> ToolCallResult {
+    match err {
+        LookupError::Ambiguous(_) => error_result(err.to_string()),
+        LookupError::NotFound => not_found(message, split_qualified(name).1, candidates),
+    }
+}
+
+/// A "not found" error with did-you-mean suggestions drawn from `candidates`.
+fn not_found(message: String, name: &str, candidates: Vec<&str>) -> ToolCallResult {
+    let suggestions: Vec<String> = search::suggest(name, candidates)
+        .into_iter()
+        .map(|s| format!("'{s}'"))
+        .collect();
+    if suggestions.is_empty() {
+        error_result(message)
+    } else {
+        error_result(format!("{message}. Did you mean {}?", suggestions.join(" or ")))
+    }
+}
+
+/// The `tag` and `status` arguments of the listing tools.
+fn metadata_filter(args: &Value) -> Result<MetadataFilter, String> {
+    let status = match args["status"].as_str().filter(|s| !s.is_empty()) {
+        Some(s) => Some(Status::parse(s).ok_or_else(|| format!("Unknown status '{s}'. Use proposed, active or deprecated"))?),
+        None => None,
+    };
+    Ok(MetadataFilter {
+        tag: args["tag"].as_str().filter(|t| !t.is_empty()).map(String::from),
+        status,
+    })
+}
+
 fn text_result(text: String) -> ToolCallResult {
     ToolCallResult {
         content: vec![ContentBlock::Text { text }],
@@ -311,18 +1204,29 @@ mod tests {
                         }],
                         methods: vec![],
                         invariants: vec!["Email must be unique".into()],
+                        state_machine: None,
+                        meta: Metadata::default(),
                     }],
                     value_objects: vec![],
+                    enumerations: vec![],
+                    aggregates: vec![],
+                    commands: vec![],
+                    queries: vec![],
+                    use_cases: vec![],
+                    policies: vec![],
+                    glossary: vec![],
                     services: vec![Service {
                         name: "AuthService".into(),
                         description: "Handles auth".into(),
                         kind: ServiceKind::Application,
                         methods: vec![],
                         dependencies: vec![],
+                        meta: Metadata::default(),
                     }],
                     repositories: vec![],
                     events: vec![],
                     dependencies: vec![],
+                    meta: Metadata::default(),
                 },
                 BoundedContext {
                     name: "Billing".into(),
@@ -330,23 +1234,34 @@ mod tests {
                     module_path: "src/billing".into(),
                     entities: vec![],
                     value_objects: vec![],
+                    enumerations: vec![],
+                    aggregates: vec![],
+                    commands: vec![],
+                    queries: vec![],
+                    use_cases: vec![],
+                    policies: vec![],
+                    glossary: vec![],
                     services: vec![],
                     repositories: vec![],
                     events: vec![],
                     dependencies: vec!["Identity".into()],
+                    meta: Metadata::default(),
                 },
             ],
+            external_systems: vec![],
             rules: vec![ArchitecturalRule {
                 id: "LAYER-001".into(),
                 description: "Domain must not depend on infra".into(),
                 severity: Severity::Error,
                 scope: "domain".into(),
+                check: "".into(),
             }],
             tech_stack: TechStack::default(),
             conventions: Conventions {
                 file_structure: FileStructure {
                     pattern: "src/{context}/{layer}/{type}.rs".into(),
                     layers: vec!["domain".into(), "application".into()],
+                    ..Default::default()
                 },
                 ..Default::default()
             },
@@ -365,6 +1280,186 @@ mod tests {
         assert!(text.contains("Identity"));
     }
 
+    #[test]
+    fn test_get_entity_reports_aggregate_root() {
+        let mut model = test_model();
+        let identity = &mut model.bounded_contexts[0];
+        identity.entities.push(Entity {
+            name: "Session".into(),
+            description: "".into(),
+            aggregate_root: false,
+            fields: vec![],
+            methods: vec![],
+            invariants: vec![],
+            state_machine: None,
+            meta: Metadata::default(),
+        });
+        identity.aggregates.push(Aggregate {
+            name: "Account".into(),
+            description: "".into(),
+            root: "User".into(),
+            entities: vec!["Session".into()],
+            value_objects: vec![],
+            meta: Metadata::default(),
+        });
+
+        let result = call_tool(&model, "get_entity", &json!({"name": "Session"}));
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        assert!(text.contains("\"aggregate\":{\"is_root\":false,\"name\":\"Account\",\"root\":\"User\"}"));
+    }
+
+    #[test]
+    fn test_get_operation_returns_flow() {
+        let mut model = test_model();
+        let identity = &mut model.bounded_contexts[0];
+        identity.repositories.push(Repository {
+            name: "UserRepository".into(),
+            aggregate: "User".into(),
+            methods: vec![],
+            meta: Metadata::default(),
+        });
+        identity.events.push(DomainEvent {
+            name: "UserDeactivated".into(),
+            description: "".into(),
+            fields: vec![],
+            source: "User".into(),
+            published: true,
+            channel: "identity.user.deactivated".into(),
+            meta: Metadata::default(),
+        });
+        identity.commands.push(Operation {
+            name: "DeactivateUser".into(),
+            description: "".into(),
+            fields: vec![],
+            aggregate: "User".into(),
+            emits: vec!["UserDeactivated".into()],
+            invariants: vec!["User must be active".into()],
+            handler: "AuthService".into(),
+            returns: "".into(),
+            steps: vec![],
+            meta: Metadata::default(),
+        });
+
+        let result = call_tool(&model, "get_operation", &json!({"name": "deactivateuser"}));
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        let value: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(value["kind"], "command");
+        assert_eq!(
+            value["flow"],
+            json!([
+                "AuthService handles DeactivateUser",
+                "Load User through UserRepository",
+                "Check: User must be active",
+                "Check: Email must be unique",
+                "Save User through UserRepository",
+                "Emit UserDeactivated (published on identity.user.deactivated)"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_trace_event_flow_flags_violation() {
+        let mut model = test_model();
+        model.bounded_contexts[0].events.push(DomainEvent {
+            name: "UserRegistered".into(),
+            description: "".into(),
+            fields: vec![],
+            source: "User".into(),
+            published: false,
+            channel: "".into(),
+            meta: Metadata::default(),
+        });
+        // Identity reacts to its own event, but issues a command in Billing it may not depend on
+        model.bounded_contexts[1].commands.push(Operation {
+            name: "OpenAccount".into(),
+            description: "".into(),
+            fields: vec![],
+            aggregate: "".into(),
+            emits: vec![],
+            invariants: vec![],
+            handler: "".into(),
+            returns: "".into(),
+            steps: vec![],
+            meta: Metadata::default(),
+        });
+        model.bounded_contexts[0].policies.push(Policy {
+            name: "OpenAccountOnRegistration".into(),
+            description: "".into(),
+            kind: PolicyKind::Policy,
+            reacts_to: vec!["UserRegistered".into()],
+            issues: vec!["OpenAccount".into()],
+            meta: Metadata::default(),
+        });
+
+        let result = call_tool(&model, "trace_event_flow", &json!({"start": "UserRegistered"}));
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        let value: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(value["steps"].as_array().unwrap().len(), 3);
+        assert_eq!(value["steps"][2]["context"], "Billing");
+        assert!(value["violations"][0].as_str().unwrap().contains("'Identity' does not depend on 'Billing'"));
+    }
+
+    #[test]
+    fn test_get_state_machine() {
+        let mut model = test_model();
+        model.bounded_contexts[0].entities[0].state_machine = Some(StateMachine {
+            field: "status".into(),
+            states: vec!["Active".into(), "Deleted".into()],
+            initial: "Active".into(),
+            transitions: vec![Transition {
+                from: "Active".into(),
+                to: "Deleted".into(),
+                method: "delete".into(),
+                event: "".into(),
+            }],
+        });
+
+        let result = call_tool(&model, "get_state_machine", &json!({"entity": "User"}));
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        let value: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(value["terminal_states"], json!(["Deleted"]));
+        assert!(value["mermaid"].as_str().unwrap().contains("Active --> Deleted : delete()"));
+
+        let result = call_tool(&model, "get_state_machine", &json!({"entity": "Nonexistent"}));
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_get_entity_resolves_enumerations() {
+        let mut model = test_model();
+        model.bounded_contexts[1].enumerations.push(Enumeration {
+            name: "UserStatus".into(),
+            description: "".into(),
+            variants: vec![Variant {
+                name: "Active".into(),
+                description: "".into(),
+            }],
+            meta: Metadata::default(),
+        });
+        model.bounded_contexts[0].entities[0].fields.push(Field {
+            name: "status".into(),
+            field_type: "Option<UserStatus>".into(),
+            required: false,
+            description: "".into(),
+        });
+
+        let result = call_tool(&model, "get_entity", &json!({"name": "User"}));
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        let value: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(value["enumerations"][0]["bounded_context"], "Billing");
+        assert_eq!(value["enumerations"][0]["enumeration"]["variants"][0]["name"], "Active");
+    }
+
     #[test]
     fn test_get_entity_not_found() {
         let model = test_model();
@@ -407,6 +1502,31 @@ mod tests {
         assert!(text.contains("\"allowed\":false"));
     }
 
+    #[test]
+    fn test_validate_dependency_on_external_system() {
+        let mut model = test_model();
+        model.external_systems.push(ExternalSystem {
+            name: "Stripe".into(),
+            description: "Payments".into(),
+            protocol: "REST".into(),
+            integrations: vec![Integration {
+                context: "Billing".into(),
+                adapter: "".into(),
+            }],
+        });
+        let check = |from: &str| {
+            let result = call_tool(&model, "validate_dependency", &json!({"from_context": from, "to_context": "stripe"}));
+            let text = match &result.content[0] {
+                ContentBlock::Text { text } => text.clone(),
+            };
+            serde_json::from_str::<Value>(&text).unwrap()
+        };
+        assert_eq!(check("Billing")["allowed"], true);
+        assert_eq!(check("Billing")["external"], true);
+        assert_eq!(check("Identity")["allowed"], false);
+        assert!(check("Identity")["explanation"].as_str().unwrap().contains("Integrated contexts: Billing"));
+    }
+
     #[test]
     fn test_suggest_file_path_entity_maps_to_domain_layer() {
         let model = test_model();
@@ -435,6 +1555,65 @@ mod tests {
         assert!(text.contains("src/identity/infrastructure/user_repository.rs"));
     }
 
+    #[test]
+    fn test_suggest_file_path_follows_layer_mapping() {
+        let mut model = test_model();
+        model.conventions.file_structure.kind_layers.insert("event".into(), "application".into());
+        model.conventions.file_structure.service_layers.insert("infrastructure".into(), "domain".into());
+        let suggest = |args: Value| -> Value {
+            match &call_tool(&model, "suggest_file_path", &args).content[0] {
+                ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+            }
+        };
+        let event = suggest(json!({"context": "Identity", "kind": "event", "name": "UserRegistered"}));
+        assert_eq!(event["suggested_path"], "src/identity/application/user_registered.rs");
+        let mailer = suggest(json!({"context": "Identity", "kind": "service", "name": "Mailer", "service_kind": "infrastructure"}));
+        assert_eq!(mailer["suggested_path"], "src/identity/domain/mailer.rs");
+        let repository = suggest(json!({"context": "Identity", "kind": "repository", "name": "UserRepository"}));
+        assert_eq!(repository["files"][1], json!({"role": "trait", "path": "src/identity/domain/user_repository.rs"}));
+
+        let result = call_tool(&model, "suggest_file_path", &json!({"context": "Identity", "kind": "service", "name": "X", "service_kind": "adapter"}));
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_suggest_file_path_lists_artifacts() {
+        let mut model = test_model();
+        model.bounded_contexts[0].module_path = "crates/identity/src".into();
+        let artifact = |role: &str, pattern: &str| ArtifactPattern {
+            role: role.into(),
+            pattern: pattern.into(),
+            layer: "".into(),
+        };
+        model.conventions.file_structure.artifacts.insert(
+            "value_object".into(),
+            vec![
+                artifact("source", "{module_path}/{layer}/{kind}/{type}.rs"),
+                artifact("test", "tests/{context}/{name_kebab}.rs"),
+                artifact("module", "{module_path}/{layer}/mod.rs"),
+                artifact("schema", "schemas/{Name}.json"),
+            ],
+        );
+        let result = call_tool(
+            &model,
+            "suggest_file_path",
+            &json!({"context": "Identity", "kind": "value_object", "name": "EmailAddress"}),
+        );
+        let suggestion: Value = match &result.content[0] {
+            ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+        };
+        assert_eq!(suggestion["suggested_path"], "crates/identity/src/domain/value_object/email_address.rs");
+        let files: Vec<_> = suggestion["files"].as_array().unwrap().iter().map(|f| (f["role"].as_str().unwrap(), f["path"].as_str().unwrap())).collect();
+        assert_eq!(
+            files[1..],
+            [
+                ("test", "tests/identity/email-address.rs"),
+                ("module", "crates/identity/src/domain/mod.rs"),
+                ("schema", "schemas/EmailAddress.json"),
+            ]
+        );
+    }
+
     #[test]
     fn test_get_architectural_rules() {
         let model = test_model();
@@ -445,6 +1624,363 @@ mod tests {
         assert!(text.contains("LAYER-001"));
     }
 
+    fn with_glossary() -> DomainModel {
+        let mut model = test_model();
+        model.bounded_contexts[1].glossary.push(Term {
+            term: "Customer".into(),
+            definition: "A paying account holder".into(),
+            synonyms: vec![],
+            forbidden_aliases: vec!["User".into()],
+        });
+        model
+    }
+
+    #[test]
+    fn test_get_glossary_term_across_contexts() {
+        let result = call_tool(&with_glossary(), "get_glossary", &json!({"term": "user"}));
+        assert!(result.is_error.is_none());
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        let v: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(v["meanings"][0]["bounded_context"], "Billing");
+        assert_eq!(v["meanings"][0]["use_instead"], "Customer");
+
+        let result = call_tool(&with_glossary(), "get_glossary", &json!({"term": "Invoice"}));
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_validate_model_reports_forbidden_alias() {
+        let mut model = with_glossary();
+        model.bounded_contexts[1].events.push(DomainEvent {
+            name: "UserCharged".into(),
+            description: "".into(),
+            fields: vec![],
+            source: "".into(),
+            published: false,
+            channel: "".into(),
+            meta: Metadata::default(),
+        });
+        let result = call_tool(&model, "validate_model", &json!({}));
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        let v: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(v["findings"].as_array().unwrap().len(), 1);
+        assert_eq!(v["findings"][0]["path"], "Billing.UserCharged");
+    }
+
+    #[test]
+    fn test_overview_filters_by_status() {
+        let mut model = test_model();
+        model.bounded_contexts[0].services[0].meta.status = Status::Deprecated;
+        let result = call_tool(&model, "get_architecture_overview", &json!({}));
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        assert!(text.contains(r#""deprecated":[{"bounded_context":"Identity","kind":"service","name":"AuthService","replaced_by":""}]"#));
+
+        let result = call_tool(&model, "get_architecture_overview", &json!({"status": "deprecated"}));
+        let overview: Value = match &result.content[0] {
+            ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+        };
+        let contexts = overview["bounded_contexts"].as_array().unwrap();
+        assert_eq!(contexts.len(), 1);
+        assert!(contexts[0]["entities"].as_array().unwrap().is_empty());
+
+        let result = call_tool(&model, "get_bounded_context", &json!({"name": "Billing", "status": "deprecated"}));
+        assert_eq!(result.is_error, Some(true));
+        let result = call_tool(&model, "get_architecture_overview", &json!({"status": "retired"}));
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_overview_detail_focus_and_budget() {
+        let mut model = test_model();
+        for name in ["Invoice", "Subscription", "Payment"] {
+            let mut entity = model.bounded_contexts[0].entities[0].clone();
+            entity.name = name.into();
+            model.bounded_contexts[1].entities.push(entity);
+        }
+        let overview = |args: Value| -> Value {
+            match &call_tool(&model, "get_architecture_overview", &args).content[0] {
+                ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+            }
+        };
+
+        let v = overview(json!({"detail": "names"}));
+        assert_eq!(v["bounded_contexts"][1]["entities"], json!(["Invoice", "Subscription", "Payment"]));
+        assert_eq!(v["bounded_contexts"][0]["detail"], "names");
+
+        let v = overview(json!({"detail": "contexts", "focus": "identity"}));
+        assert_eq!(v["bounded_contexts"][0]["entities"][0]["name"], "User");
+        assert_eq!(v["bounded_contexts"][1]["detail"], "contexts");
+        assert!(v["bounded_contexts"][1].get("services").is_none());
+
+        // A budget that fits Identity in full only when Billing is reduced to names
+        let fitted = overview(json!({"detail": "names", "focus": "Identity"}));
+        let budget = crate::domain::registry::estimate_tokens(&fitted.to_string());
+        let v = overview(json!({"focus": "Identity", "max_tokens": budget}));
+        assert_eq!(v, fitted);
+        let v = overview(json!({"focus": "Identity", "max_tokens": 1}));
+        assert_eq!(v["bounded_contexts"][0]["entities"][0]["name"], "User");
+        assert_eq!(v["bounded_contexts"][1]["detail"], "contexts");
+        assert_eq!(v["over_budget"]["max_tokens"], 1);
+        assert!(v["rules"].is_array());
+        let v = overview(json!({"max_tokens": 1}));
+        assert_eq!(v["bounded_contexts"][0]["detail"], "contexts");
+        assert!(v["over_budget"]["reason"].as_str().unwrap().starts_with("every context"));
+
+        let result = call_tool(&model, "get_architecture_overview", &json!({"focus": "Shipping"}));
+        assert_eq!(result.is_error, Some(true));
+        let result = call_tool(&model, "get_architecture_overview", &json!({"detail": "everything"}));
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_get_entity_warns_when_deprecated() {
+        let mut model = test_model();
+        let mut account = model.bounded_contexts[0].entities[0].clone();
+        account.name = "Account".into();
+        model.bounded_contexts[0].entities.push(account);
+        let meta = &mut model.bounded_contexts[0].entities[0].meta;
+        meta.status = Status::Deprecated;
+        meta.replaced_by = "Account".into();
+        meta.sunset = "after 2.0".into();
+
+        let result = call_tool(&model, "get_entity", &json!({"name": "User"}));
+        let spec: Value = match &result.content[0] {
+            ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+        };
+        assert_eq!(spec["deprecation"]["warning"], "'User' is deprecated — use 'Account' instead (sunset: after 2.0)");
+        assert_eq!(spec["deprecation"]["replaced_by"]["kind"], "entity");
+
+        let result = call_tool(&model, "get_entity", &json!({"name": "Account"}));
+        let spec: Value = match &result.content[0] {
+            ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+        };
+        assert!(spec["deprecation"].is_null());
+    }
+
+    #[test]
+    fn test_search_model_and_suggestions() {
+        let model = test_model();
+        let result = call_tool(&model, "search_model", &json!({"query": "auth", "kind": "service"}));
+        let found: Value = match &result.content[0] {
+            ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+        };
+        assert_eq!(found["results"][0]["name"], "AuthService");
+        assert_eq!(found["results"].as_array().unwrap().len(), 1);
+
+        let result = call_tool(&model, "get_entity", &json!({"name": "Users"}));
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        assert_eq!(text, "Entity 'Users' not found in any bounded context. Did you mean 'User'?");
+    }
+
+    #[test]
+    fn test_get_value_object_repository_and_event() {
+        let mut model = test_model();
+        let identity = &mut model.bounded_contexts[0];
+        identity.entities[0].fields.push(Field {
+            name: "email".into(),
+            field_type: "Email".into(),
+            required: true,
+            description: "".into(),
+        });
+        identity.value_objects.push(ValueObject {
+            name: "Email".into(),
+            description: "".into(),
+            fields: vec![],
+            validation_rules: vec!["Must contain @".into()],
+            meta: Metadata::default(),
+        });
+        identity.repositories.push(Repository {
+            name: "UserRepository".into(),
+            aggregate: "User".into(),
+            methods: vec![],
+            meta: Metadata::default(),
+        });
+        identity.events.push(DomainEvent {
+            name: "UserRegistered".into(),
+            description: "".into(),
+            fields: vec![],
+            source: "User".into(),
+            published: true,
+            channel: "".into(),
+            meta: Metadata::default(),
+        });
+        identity.policies.push(Policy {
+            name: "SendWelcome".into(),
+            description: "".into(),
+            kind: PolicyKind::Policy,
+            reacts_to: vec!["UserRegistered".into()],
+            issues: vec![],
+            meta: Metadata::default(),
+        });
+        let get = |tool: &str, name: &str| -> Value {
+            match &call_tool(&model, tool, &json!({"name": name})).content[0] {
+                ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+            }
+        };
+
+        let vo = get("get_value_object", "email");
+        assert_eq!(vo["value_object"]["validation_rules"][0], "Must contain @");
+        assert_eq!(vo["used_by"][0]["element"], "User");
+        let repo = get("get_repository", "UserRepository");
+        assert_eq!(repo["aggregate"]["invariants"][0], "Email must be unique");
+        let event = get("get_event", "UserRegistered");
+        assert_eq!(event["emitted_by"], json!([]));
+        assert_eq!(event["reacted_by"][0]["element"], "SendWelcome");
+
+        let result = call_tool(&model, "get_event", &json!({"name": "UserRegister"}));
+        assert_eq!(result.is_error, Some(true));
+        match &result.content[0] {
+            ContentBlock::Text { text } => assert!(text.contains("Did you mean 'UserRegistered'"), "{text}"),
+        }
+    }
+
+    #[test]
+    fn test_list_elements_filters() {
+        let mut model = test_model();
+        let mut invoice = model.bounded_contexts[0].entities[0].clone();
+        invoice.name = "InvoiceLine".into();
+        invoice.aggregate_root = false;
+        model.bounded_contexts[1].entities.push(invoice);
+        let list = |args: Value| -> Vec<String> {
+            let text = match &call_tool(&model, "list_elements", &args).content[0] {
+                ContentBlock::Text { text } => text.clone(),
+            };
+            let items: Vec<Value> = serde_json::from_str(&text).unwrap();
+            items.iter().map(|i| format!("{}.{}", i["context"].as_str().unwrap(), i["name"].as_str().unwrap())).collect()
+        };
+
+        assert_eq!(list(json!({})), vec!["Identity.User", "Identity.AuthService", "Billing.InvoiceLine"]);
+        assert_eq!(list(json!({"kind": "entity"})), vec!["Identity.User", "Billing.InvoiceLine"]);
+        assert_eq!(list(json!({"context": "billing"})), vec!["Billing.InvoiceLine"]);
+        assert_eq!(list(json!({"aggregate_roots_only": true})), vec!["Identity.User"]);
+
+        let result = call_tool(&model, "list_elements", &json!({"context": "Biling"}));
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_ambiguous_and_qualified_lookups() {
+        let mut model = test_model();
+        let mut user = model.bounded_contexts[0].entities[0].clone();
+        user.invariants = vec!["A billing user has a payment method".into()];
+        model.bounded_contexts[1].entities.push(user);
+
+        let result = call_tool(&model, "get_entity", &json!({"name": "user"}));
+        assert_eq!(result.is_error, Some(true));
+        match &result.content[0] {
+            ContentBlock::Text { text } => assert_eq!(
+                text,
+                "'User' is ambiguous: it is defined in several contexts. Use a qualified name: Identity::User, Billing::User"
+            ),
+        }
+
+        let result = call_tool(&model, "get_entity", &json!({"name": "Billing::User"}));
+        let entity: Value = match &result.content[0] {
+            ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+        };
+        assert_eq!(entity["bounded_context"], "Billing");
+        assert_eq!(entity["entity"]["invariants"][0], "A billing user has a payment method");
+
+        let result = call_tool(&model, "analyze_impact", &json!({"name": "User"}));
+        assert_eq!(result.is_error, Some(true));
+        let result = call_tool(&model, "get_service_spec", &json!({"name": "Billing::AuthService"}));
+        assert_eq!(result.is_error, Some(true));
+        match &result.content[0] {
+            ContentBlock::Text { text } => assert!(text.contains("Did you mean 'AuthService'"), "{text}"),
+        }
+    }
+
+    #[test]
+    fn test_check_rules() {
+        let mut model = test_model();
+        model.rules[0].check = "layer domain must not depend on layer infrastructure".into();
+        let check = |args: Value| -> Value {
+            match &call_tool(&model, "check_rules", &args).content[0] {
+                ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+            }
+        };
+        let clean = check(json!({}));
+        assert_eq!(clean["violations"], json!([]));
+
+        let imports = json!([{"from": "src/identity/domain/user.rs", "to": "src/identity/infrastructure/db.rs"}]);
+        let v = check(json!({"imports": imports, "rule": "layer-001"}));
+        assert_eq!(v["violations"][0]["rule"], "LAYER-001");
+        assert_eq!(v["violations"][0]["severity"], "error");
+        assert_eq!(v["violations"][0]["subject"], "src/identity/domain/user.rs");
+
+        let result = call_tool(&model, "check_rules", &json!({"rule": "NOPE-1"}));
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_check_naming() {
+        let mut model = test_model();
+        model.conventions.naming.repositories = NamingRule {
+            case: Some(CaseStyle::Pascal),
+            prefix: "".into(),
+            suffix: "Repository".into(),
+            pattern: "".into(),
+            tense: None,
+            description: "".into(),
+        };
+        let check = |args: Value| -> Value {
+            match &call_tool(&model, "check_naming", &args).content[0] {
+                ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+            }
+        };
+        let ok = check(json!({"kind": "repository", "name": "SessionRepository"}));
+        assert_eq!(ok["valid"], true);
+        assert_eq!(ok["suggestion"], Value::Null);
+
+        let bad = check(json!({"kind": "repository", "name": "session_store", "context": "Identity"}));
+        assert_eq!(bad["valid"], false);
+        assert_eq!(bad["problems"], json!(["is not in PascalCase", "does not end with 'Repository'"]));
+        assert_eq!(bad["suggestion"], "SessionStoreRepository");
+
+        let taken = check(json!({"kind": "entity", "name": "Identity::User"}));
+        assert_eq!(taken["valid"], false);
+        assert_eq!(taken["existing"], json!(["Identity::User (entity)"]));
+
+        let result = call_tool(&model, "check_naming", &json!({"kind": "policy", "name": "Refund"}));
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_get_relevant_context() {
+        let model = test_model();
+        let result = call_tool(&model, "get_relevant_context", &json!({"task": "let users sign in"}));
+        let relevance: Value = match &result.content[0] {
+            ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+        };
+        assert_eq!(relevance["contexts"][0]["name"], "Identity");
+        assert_eq!(relevance["contexts"][0]["elements"][0]["name"], "User");
+
+        let result = call_tool(&model, "get_relevant_context", &json!({"task": " "}));
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_analyze_impact() {
+        let model = test_model();
+        let result = call_tool(&model, "analyze_impact", &json!({"name": "AuthService"}));
+        let impact: Value = match &result.content[0] {
+            ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+        };
+        assert_eq!(impact["kind"], "service");
+        assert_eq!(impact["rules"], json!([]));
+
+        let result = call_tool(&model, "analyze_impact", &json!({"name": "Nobody"}));
+        assert_eq!(result.is_error, Some(true));
+    }
+
     #[test]
     fn test_unknown_tool() {
         let model = test_model();
@@ -455,6 +1991,6 @@ mod tests {
     #[test]
     fn test_list_tools_count() {
         let tools = list_tools();
-        assert_eq!(tools.len(), 8);
+        assert_eq!(tools.len(), 22);
     }
 }
@@ -1,7 +1,9 @@
 use serde_json::{json, Value};
 
-use crate::domain::diff;
+use crate::domain::{diff, validation};
 use crate::domain::model::*;
+use crate::domain::registry::{split_qualified, DomainRegistry};
+use crate::import;
 use crate::mcp::protocol::*;
 use crate::store::Store;
 
@@ -32,12 +34,13 @@ pub fn list_write_tools() -> Vec<ToolDefinition> {
             name: "update_entity".into(),
             description: "Create or update an entity within a bounded context. \
                           Use when discovering entities in existing code or refactoring. \
-                          Fields, methods, and invariants are merged (not replaced)."
+                          Fields, methods, and invariants are merged (not replaced); a \
+                          state_machine replaces the existing lifecycle."
                 .into(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "context": { "type": "string", "description": "Bounded context name" },
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
                     "name": { "type": "string", "description": "Entity name" },
                     "description": { "type": "string" },
                     "aggregate_root": { "type": "boolean" },
@@ -80,9 +83,33 @@ pub fn list_write_tools() -> Vec<ToolDefinition> {
                     "invariants": {
                         "type": "array",
                         "items": { "type": "string" }
+                    },
+                    "state_machine": {
+                        "type": ["object", "null"],
+                        "description": "Lifecycle; replaces the existing one, null removes it. \
+                                        Every transition method must exist on the entity.",
+                        "properties": {
+                            "field": { "type": "string", "description": "Field holding the state, e.g. status" },
+                            "states": { "type": "array", "items": { "type": "string" } },
+                            "initial": { "type": "string" },
+                            "transitions": {
+                                "type": "array",
+                                "items": {
+                                    "type": "object",
+                                    "properties": {
+                                        "from": { "type": "string" },
+                                        "to": { "type": "string" },
+                                        "method": { "type": "string" },
+                                        "event": { "type": "string" }
+                                    },
+                                    "required": ["from", "to", "method"]
+                                }
+                            }
+                        },
+                        "required": ["states", "initial"]
                     }
                 },
-                "required": ["context", "name"]
+                "required": ["name"]
             }),
         },
         ToolDefinition {
@@ -93,7 +120,7 @@ pub fn list_write_tools() -> Vec<ToolDefinition> {
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "context": { "type": "string", "description": "Bounded context name" },
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
                     "name": { "type": "string", "description": "Service name" },
                     "description": { "type": "string" },
                     "kind": { "type": "string", "enum": ["domain", "application", "infrastructure"] },
@@ -122,7 +149,7 @@ pub fn list_write_tools() -> Vec<ToolDefinition> {
                     },
                     "dependencies": { "type": "array", "items": { "type": "string" } }
                 },
-                "required": ["context", "name"]
+                "required": ["name"]
             }),
         },
         ToolDefinition {
@@ -132,10 +159,95 @@ pub fn list_write_tools() -> Vec<ToolDefinition> {
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "context": { "type": "string", "description": "Bounded context name" },
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
                     "name": { "type": "string", "description": "Event name" },
                     "description": { "type": "string" },
                     "source": { "type": "string", "description": "Which entity emits this" },
+                    "published": { "type": "boolean", "description": "Published to other contexts via the message broker" },
+                    "channel": { "type": "string", "description": "Broker channel / subject, e.g. billing.invoice.paid" },
+                    "fields": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "name": { "type": "string" },
+                                "type": { "type": "string" },
+                                "description": { "type": "string" }
+                            },
+                            "required": ["name", "type"]
+                        }
+                    }
+                },
+                "required": ["name"]
+            }),
+        },
+        ToolDefinition {
+            name: "update_enumeration".into(),
+            description: "Create or update an enumeration (e.g. UserStatus) within a bounded context. \
+                          Variants are replaced when given, so removing a variant is a model change \
+                          that `draft_refactoring_plan` turns into match updates and data migrations."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
+                    "name": { "type": "string", "description": "Enumeration name" },
+                    "description": { "type": "string" },
+                    "variants": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "name": { "type": "string" },
+                                "description": { "type": "string" }
+                            },
+                            "required": ["name"]
+                        }
+                    }
+                },
+                "required": ["name"]
+            }),
+        },
+        ToolDefinition {
+            name: "update_aggregate".into(),
+            description: "Create or update an aggregate: a root entity plus the child entities \
+                          and value objects inside its consistency boundary. Member lists are \
+                          replaced when given. The root is marked as aggregate root; members \
+                          must exist in the context and belong to only one aggregate."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
+                    "name": { "type": "string", "description": "Aggregate name" },
+                    "description": { "type": "string" },
+                    "root": { "type": "string", "description": "Root entity (required when creating)" },
+                    "entities": {
+                        "type": "array", "items": { "type": "string" },
+                        "description": "Child entities, only reachable through the root"
+                    },
+                    "value_objects": { "type": "array", "items": { "type": "string" } }
+                },
+                "required": ["name"]
+            }),
+        },
+        ToolDefinition {
+            name: "update_operation".into(),
+            description: "Create or update a command, query or use case within a bounded context. \
+                          Fields are merged; emits, invariants and steps are replaced when given. \
+                          The aggregate must be an aggregate root, the handler a service and the \
+                          emitted events events of the same context."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
+                    "kind": { "type": "string", "enum": ["command", "query", "use_case"] },
+                    "name": { "type": "string", "description": "e.g. CancelSubscription" },
+                    "description": { "type": "string" },
+                    "aggregate": { "type": "string", "description": "Aggregate root it targets" },
+                    "handler": { "type": "string", "description": "Service that handles it" },
+                    "returns": { "type": "string", "description": "Result type (queries)" },
                     "fields": {
                         "type": "array",
                         "items": {
@@ -143,13 +255,133 @@ pub fn list_write_tools() -> Vec<ToolDefinition> {
                             "properties": {
                                 "name": { "type": "string" },
                                 "type": { "type": "string" },
+                                "required": { "type": "boolean" },
                                 "description": { "type": "string" }
                             },
                             "required": ["name", "type"]
                         }
+                    },
+                    "emits": { "type": "array", "items": { "type": "string" }, "description": "Domain events emitted on success" },
+                    "invariants": { "type": "array", "items": { "type": "string" } },
+                    "steps": {
+                        "type": "array", "items": { "type": "string" },
+                        "description": "Commands and queries run in order (use cases)"
+                    }
+                },
+                "required": ["kind", "name"]
+            }),
+        },
+        ToolDefinition {
+            name: "update_policy".into(),
+            description: "Create or update a policy (or saga) within a bounded context: it reacts \
+                          to domain events, from this or other contexts, and issues commands. \
+                          Event and command lists are replaced when given. Use `trace_event_flow` \
+                          to check the resulting cross-context flow against `dependencies`."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
+                    "name": { "type": "string", "description": "e.g. CancelSubscriptionsOnSuspension" },
+                    "description": { "type": "string" },
+                    "kind": { "type": "string", "enum": ["policy", "saga"] },
+                    "reacts_to": { "type": "array", "items": { "type": "string" }, "description": "Event names" },
+                    "issues": { "type": "array", "items": { "type": "string" }, "description": "Command names" }
+                },
+                "required": ["name"]
+            }),
+        },
+        ToolDefinition {
+            name: "define_term".into(),
+            description: "Add or update a term in a bounded context's glossary. Synonyms are other \
+                          accepted words; forbidden aliases are words that must not name the concept \
+                          in this context. Lists are replaced when given. Reports existing element \
+                          names that use a forbidden alias."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
+                    "term": { "type": "string", "description": "e.g. Customer" },
+                    "definition": { "type": "string" },
+                    "synonyms": { "type": "array", "items": { "type": "string" } },
+                    "forbidden_aliases": { "type": "array", "items": { "type": "string" }, "description": "e.g. [\"Client\", \"User\"]" }
+                },
+                "required": ["term"]
+            }),
+        },
+        ToolDefinition {
+            name: "set_metadata".into(),
+            description: "Set owners, tags, lifecycle status (proposed, active, deprecated) and links \
+                          of a bounded context, or of one of its elements when `element` is given. \
+                          Lists are replaced when given."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
+                    "element": { "type": "string", "description": "Entity, value object, service, event, ... of the context; omit for the context itself" },
+                    "owners": { "type": "array", "items": { "type": "string" }, "description": "e.g. [\"team-billing\"]" },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "status": { "type": "string", "enum": ["proposed", "active", "deprecated"] },
+                    "links": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "title": { "type": "string" },
+                                "url": { "type": "string" }
+                            },
+                            "required": ["url"]
+                        }
+                    }
+                },
+                "required": []
+            }),
+        },
+        ToolDefinition {
+            name: "deprecate_element".into(),
+            description: "Mark an element of a bounded context deprecated, optionally naming the element \
+                          that replaces it and a sunset note. Read tools then warn against it, and \
+                          `draft_refactoring_plan` stages its retirement: introduce the replacement, \
+                          migrate usages, delete. Returns the usages to migrate."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
+                    "element": { "type": "string", "description": "Entity, value object, service, event, ... to deprecate" },
+                    "replaced_by": { "type": "string", "description": "Element of the same context to use instead" },
+                    "sunset": { "type": "string", "description": "When it will be deleted, e.g. \"after the 2.0 release\"" }
+                },
+                "required": ["element"]
+            }),
+        },
+        ToolDefinition {
+            name: "update_external_system".into(),
+            description: "Create or update a system outside the bounded contexts (payment provider, \
+                          SMTP relay, legacy ERP) and the contexts that integrate with it, each \
+                          optionally through an adapter service. Integrations are replaced when given."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string", "description": "e.g. Stripe" },
+                    "description": { "type": "string" },
+                    "protocol": { "type": "string", "description": "e.g. REST, SMTP, SOAP" },
+                    "integrations": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "context": { "type": "string" },
+                                "adapter": { "type": "string", "description": "Service in the context that wraps the system" }
+                            },
+                            "required": ["context"]
+                        }
                     }
                 },
-                "required": ["context", "name"]
+                "required": ["name"]
             }),
         },
         ToolDefinition {
@@ -159,10 +391,33 @@ pub fn list_write_tools() -> Vec<ToolDefinition> {
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "context": { "type": "string" },
+                    "context": { "type": "string", "description": "Bounded context name; optional when the element name is qualified, e.g. Billing::Account" },
                     "name": { "type": "string" }
                 },
-                "required": ["context", "name"]
+                "required": ["name"]
+            }),
+        },
+        ToolDefinition {
+            name: "import_spec".into(),
+            description: "Import an external specification into a bounded context of the \
+                          in-memory model. Supported formats: openapi (OpenAPI 3), asyncapi \
+                          (AsyncAPI 2.x/3.x messages → published domain events) and sql \
+                          (CREATE/ALTER TABLE migrations → entities, applied in order). \
+                          Elements are merged, not replaced. Returns the resulting changes; \
+                          review them with `compare_model` before calling `save_model`."
+                .into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "format": { "type": "string", "enum": ["openapi", "asyncapi", "sql"] },
+                    "path": { "type": "string", "description": "Spec file inside the workspace, absolute or relative to it" },
+                    "paths": {
+                        "type": "array", "items": { "type": "string" },
+                        "description": "Several files, e.g. SQL migrations in the order they are applied"
+                    },
+                    "context": { "type": "string", "description": "Bounded context to import into" }
+                },
+                "required": ["format", "context"]
             }),
         },
         ToolDefinition {
@@ -213,6 +468,19 @@ pub fn call_write_tool(
     name: &str,
     args: &Value,
 ) -> ToolCallResult {
+    let qualified = match name {
+        "update_entity" | "update_service" | "update_event" | "update_enumeration" | "update_aggregate"
+        | "update_operation" | "update_policy" | "remove_entity" => Some("name"),
+        "set_metadata" | "deprecate_element" => Some("element"),
+        "define_term" => Some("term"),
+        _ => None,
+    };
+    let args = &match qualified.map(|key| unqualify(args, key)) {
+        Some(Ok(args)) => args,
+        Some(Err(e)) => return error_result(e),
+        None => args.clone(),
+    };
+
     match name {
         "update_bounded_context" => {
             let ctx_name = arg_str(args, "name");
@@ -248,11 +516,6 @@ pub fn call_write_tool(
                         name: ctx_name.clone(),
                         description: arg_str(args, "description"),
                         module_path: arg_str(args, "module_path"),
-                        entities: vec![],
-                        value_objects: vec![],
-                        services: vec![],
-                        repositories: vec![],
-                        events: vec![],
                         dependencies: args
                             .get("dependencies")
                             .and_then(|v| v.as_array())
@@ -262,6 +525,7 @@ pub fn call_write_tool(
                                     .collect()
                             })
                             .unwrap_or_default(),
+                        ..Default::default()
                     });
                     text_result(format!("Created bounded context '{ctx_name}'"))
                 }
@@ -271,6 +535,16 @@ pub fn call_write_tool(
         "update_entity" => {
             let ctx_name = arg_str(args, "context");
             let entity_name = arg_str(args, "name");
+            // `state_machine` replaces the lifecycle; `null` removes it
+            let state_machine = match args.get("state_machine") {
+                None => None,
+                Some(Value::Null) => Some(None),
+                Some(v) => match serde_json::from_value::<StateMachine>(v.clone()) {
+                    Ok(sm) => Some(Some(sm)),
+                    Err(e) => return error_result(format!("Invalid state_machine: {e}")),
+                },
+            };
+            let before = model.clone();
 
             let bc = match model
                 .bounded_contexts
@@ -286,7 +560,7 @@ pub fn call_write_tool(
                 .iter_mut()
                 .find(|e| e.name.eq_ignore_ascii_case(&entity_name));
 
-            match existing {
+            let result = match existing {
                 Some(entity) => {
                     // Merge updates
                     if let Some(desc) = args.get("description").and_then(|v| v.as_str()) {
@@ -310,6 +584,9 @@ pub fn call_write_tool(
                             }
                         }
                     }
+                    if let Some(sm) = state_machine {
+                        entity.state_machine = sm;
+                    }
                     text_result(format!("Updated entity '{entity_name}' in '{ctx_name}'"))
                 }
                 None => {
@@ -331,13 +608,21 @@ pub fn call_write_tool(
                                     .collect()
                             })
                             .unwrap_or_default(),
+                        state_machine: state_machine.flatten(),
+                        meta: Metadata::default(),
                     };
                     bc.entities.push(entity);
                     text_result(format!(
                         "Created entity '{entity_name}' in '{ctx_name}'"
                     ))
                 }
+            };
+
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Invalid entity: {e}"));
             }
+            result
         }
 
         "update_service" => {
@@ -396,6 +681,7 @@ pub fn call_write_tool(
                                     .collect()
                             })
                             .unwrap_or_default(),
+                        meta: Metadata::default(),
                     });
                     text_result(format!("Created service '{svc_name}' in '{ctx_name}'"))
                 }
@@ -405,6 +691,7 @@ pub fn call_write_tool(
         "update_event" => {
             let ctx_name = arg_str(args, "context");
             let event_name = arg_str(args, "name");
+            let before = model.clone();
 
             let bc = match model
                 .bounded_contexts
@@ -420,7 +707,7 @@ pub fn call_write_tool(
                 .iter_mut()
                 .find(|e| e.name.eq_ignore_ascii_case(&event_name));
 
-            match existing {
+            let created = match existing {
                 Some(evt) => {
                     if let Some(desc) = args.get("description").and_then(|v| v.as_str()) {
                         evt.description = desc.to_string();
@@ -428,10 +715,16 @@ pub fn call_write_tool(
                     if let Some(src) = args.get("source").and_then(|v| v.as_str()) {
                         evt.source = src.to_string();
                     }
+                    if let Some(published) = args.get("published").and_then(|v| v.as_bool()) {
+                        evt.published = published;
+                    }
+                    if let Some(channel) = args.get("channel").and_then(|v| v.as_str()) {
+                        evt.channel = channel.to_string();
+                    }
                     if let Some(fields) = args.get("fields").and_then(|v| v.as_array()) {
                         merge_fields(&mut evt.fields, fields);
                     }
-                    text_result(format!("Updated event '{event_name}' in '{ctx_name}'"))
+                    false
                 }
                 None => {
                     bc.events.push(DomainEvent {
@@ -439,15 +732,32 @@ pub fn call_write_tool(
                         description: arg_str(args, "description"),
                         fields: parse_fields(args.get("fields")),
                         source: arg_str(args, "source"),
+                        published: args
+                            .get("published")
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(false),
+                        channel: arg_str(args, "channel"),
+                        meta: Metadata::default(),
                     });
-                    text_result(format!("Created event '{event_name}' in '{ctx_name}'"))
+                    true
                 }
+            };
+
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Invalid event: {e}"));
             }
+            let verb = if created { "Created" } else { "Updated" };
+            text_result(format!("{verb} event '{event_name}' in '{ctx_name}'"))
         }
 
-        "remove_entity" => {
+        "update_enumeration" => {
             let ctx_name = arg_str(args, "context");
-            let entity_name = arg_str(args, "name");
+            let enum_name = arg_str(args, "name");
+            if enum_name.is_empty() {
+                return error_result("'name' is required");
+            }
+            let before = model.clone();
 
             let bc = match model
                 .bounded_contexts
@@ -458,88 +768,682 @@ pub fn call_write_tool(
                 None => return error_result(format!("Bounded context '{ctx_name}' not found")),
             };
 
-            let before = bc.entities.len();
-            bc.entities
-                .retain(|e| !e.name.eq_ignore_ascii_case(&entity_name));
-
-            if bc.entities.len() < before {
-                text_result(format!(
-                    "Removed entity '{entity_name}' from '{ctx_name}'"
-                ))
-            } else {
-                error_result(format!(
-                    "Entity '{entity_name}' not found in '{ctx_name}'"
-                ))
-            }
-        }
+            // Variants may be given as objects or as bare names
+            let variants = args.get("variants").and_then(|v| v.as_array()).map(|a| {
+                a.iter()
+                    .filter_map(|v| match v {
+                        Value::String(name) => Some(Variant {
+                            name: name.clone(),
+                            description: String::new(),
+                        }),
+                        _ => Some(Variant {
+                            name: v.get("name")?.as_str()?.to_string(),
+                            description: arg_str(v, "description"),
+                        }),
+                    })
+                    .collect::<Vec<_>>()
+            });
 
-        "compare_model" => {
-            // Load the persisted model from the store and diff against current in-memory state
-            match load_changes(store, workspace_path, model) {
-                Ok(changes) => {
-                    if changes.is_empty() {
-                        text_result(
-                            json!({
-                                "status": "no_changes",
-                                "message": "In-memory model matches persisted model"
-                            })
-                            .to_string(),
-                        )
-                    } else {
-                        text_result(
-                            json!({
-                                "status": "changes_detected",
-                                "change_count": changes.len(),
-                                "changes": changes
-                            })
-                            .to_string(),
-                        )
+            let created = match bc
+                .enumerations
+                .iter_mut()
+                .find(|e| e.name.eq_ignore_ascii_case(&enum_name))
+            {
+                Some(en) => {
+                    if let Some(desc) = args.get("description").and_then(|v| v.as_str()) {
+                        en.description = desc.to_string();
                     }
+                    if let Some(variants) = variants {
+                        en.variants = variants;
+                    }
+                    false
                 }
-                Err(e) => error_result(format!("Failed to compare models: {e}")),
+                None => {
+                    bc.enumerations.push(Enumeration {
+                        name: enum_name.clone(),
+                        description: arg_str(args, "description"),
+                        variants: variants.unwrap_or_default(),
+                        meta: Metadata::default(),
+                    });
+                    true
+                }
+            };
+
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Invalid enumeration: {e}"));
             }
+            let verb = if created { "Created" } else { "Updated" };
+            text_result(format!("{verb} enumeration '{enum_name}' in '{ctx_name}'"))
         }
 
-        "draft_refactoring_plan" => {
-            match load_changes(store, workspace_path, model) {
-                Ok(changes) => {
-                    if changes.is_empty() {
-                        text_result(
-                            json!({
-                                "status": "no_changes",
-                                "message": "In-memory model matches persisted model. Nothing to refactor."
-                            })
-                            .to_string(),
-                        )
-                    } else {
-                        let plan = diff::plan_refactoring(&changes, &model.conventions);
-                        text_result(serde_json::to_string(&plan).unwrap())
-                    }
-                }
-                Err(e) => error_result(format!("Failed to load persisted model: {e}")),
+        "update_aggregate" => {
+            let ctx_name = arg_str(args, "context");
+            let agg_name = arg_str(args, "name");
+            if agg_name.is_empty() {
+                return error_result("'name' is required");
             }
-        }
+            let before = model.clone();
 
-        "save_model" => match store.save(workspace_path, model) {
-            Ok(()) => text_result(format!("Domain model saved to store for workspace: {workspace_path}")),
-            Err(e) => error_result(format!("Failed to save: {e}")),
-        },
+            let bc = match model
+                .bounded_contexts
+                .iter_mut()
+                .find(|bc| bc.name.eq_ignore_ascii_case(&ctx_name))
+            {
+                Some(bc) => bc,
+                None => return error_result(format!("Bounded context '{ctx_name}' not found")),
+            };
 
-        _ => error_result(format!("Unknown write tool: {name}")),
-    }
-}
+            let names = |key: &str| {
+                args.get(key).and_then(|v| v.as_array()).map(|a| {
+                    a.iter()
+                        .filter_map(|n| n.as_str().map(String::from))
+                        .collect::<Vec<_>>()
+                })
+            };
 
-// ─── Helpers ───────────────────────────────────────────────────────────────
+            let created = match bc
+                .aggregates
+                .iter_mut()
+                .find(|a| a.name.eq_ignore_ascii_case(&agg_name))
+            {
+                Some(agg) => {
+                    if let Some(desc) = args.get("description").and_then(|v| v.as_str()) {
+                        agg.description = desc.to_string();
+                    }
+                    if let Some(root) = args.get("root").and_then(|v| v.as_str()) {
+                        agg.root = root.to_string();
+                    }
+                    if let Some(entities) = names("entities") {
+                        agg.entities = entities;
+                    }
+                    if let Some(vos) = names("value_objects") {
+                        agg.value_objects = vos;
+                    }
+                    false
+                }
+                None => {
+                    let root = arg_str(args, "root");
+                    if root.is_empty() {
+                        return error_result("'root' is required when creating an aggregate");
+                    }
+                    bc.aggregates.push(Aggregate {
+                        name: agg_name.clone(),
+                        description: arg_str(args, "description"),
+                        root,
+                        entities: names("entities").unwrap_or_default(),
+                        value_objects: names("value_objects").unwrap_or_default(),
+                        meta: Metadata::default(),
+                    });
+                    true
+                }
+            };
 
-fn text_result(text: impl Into<String>) -> ToolCallResult {
-    ToolCallResult {
-        content: vec![ContentBlock::Text { text: text.into() }],
-        is_error: None,
-    }
-}
+            let root = bc
+                .aggregates
+                .iter()
+                .find(|a| a.name.eq_ignore_ascii_case(&agg_name))
+                .map(|a| a.root.clone())
+                .unwrap_or_default();
+            if let Some(entity) = bc.entities.iter_mut().find(|e| e.name.eq_ignore_ascii_case(&root)) {
+                entity.aggregate_root = true;
+            }
 
-fn error_result(msg: impl Into<String>) -> ToolCallResult {
-    ToolCallResult {
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Invalid aggregate: {e}"));
+            }
+            let verb = if created { "Created" } else { "Updated" };
+            text_result(format!("{verb} aggregate '{agg_name}' (root '{root}') in '{ctx_name}'"))
+        }
+
+        "update_operation" => {
+            let ctx_name = arg_str(args, "context");
+            let op_name = arg_str(args, "name");
+            if op_name.is_empty() {
+                return error_result("'name' is required");
+            }
+            let Some(kind) = OperationKind::parse(&arg_str(args, "kind")) else {
+                return error_result("'kind' must be one of: command, query, use_case");
+            };
+            let before = model.clone();
+
+            let bc = match model
+                .bounded_contexts
+                .iter_mut()
+                .find(|bc| bc.name.eq_ignore_ascii_case(&ctx_name))
+            {
+                Some(bc) => bc,
+                None => return error_result(format!("Bounded context '{ctx_name}' not found")),
+            };
+
+            let names = |key: &str| {
+                args.get(key).and_then(|v| v.as_array()).map(|a| {
+                    a.iter()
+                        .filter_map(|n| n.as_str().map(String::from))
+                        .collect::<Vec<_>>()
+                })
+            };
+
+            let ops = bc.operations_mut(kind);
+            let created = match ops.iter_mut().find(|o| o.name.eq_ignore_ascii_case(&op_name)) {
+                Some(op) => {
+                    for (key, target) in [
+                        ("description", &mut op.description),
+                        ("aggregate", &mut op.aggregate),
+                        ("handler", &mut op.handler),
+                        ("returns", &mut op.returns),
+                    ] {
+                        if let Some(v) = args.get(key).and_then(|v| v.as_str()) {
+                            *target = v.to_string();
+                        }
+                    }
+                    if let Some(fields) = args.get("fields").and_then(|v| v.as_array()) {
+                        merge_fields(&mut op.fields, fields);
+                    }
+                    for (key, target) in [
+                        ("emits", &mut op.emits),
+                        ("invariants", &mut op.invariants),
+                        ("steps", &mut op.steps),
+                    ] {
+                        if let Some(list) = names(key) {
+                            *target = list;
+                        }
+                    }
+                    false
+                }
+                None => {
+                    ops.push(Operation {
+                        name: op_name.clone(),
+                        description: arg_str(args, "description"),
+                        fields: parse_fields(args.get("fields")),
+                        aggregate: arg_str(args, "aggregate"),
+                        emits: names("emits").unwrap_or_default(),
+                        invariants: names("invariants").unwrap_or_default(),
+                        handler: arg_str(args, "handler"),
+                        returns: arg_str(args, "returns"),
+                        steps: names("steps").unwrap_or_default(),
+                        meta: Metadata::default(),
+                    });
+                    true
+                }
+            };
+
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Invalid {}: {e}", kind.label().to_lowercase()));
+            }
+            let verb = if created { "Created" } else { "Updated" };
+            text_result(format!(
+                "{verb} {} '{op_name}' in '{ctx_name}'",
+                kind.label().to_lowercase()
+            ))
+        }
+
+        "update_policy" => {
+            let ctx_name = arg_str(args, "context");
+            let policy_name = arg_str(args, "name");
+            if policy_name.is_empty() {
+                return error_result("'name' is required");
+            }
+            let before = model.clone();
+
+            let bc = match model
+                .bounded_contexts
+                .iter_mut()
+                .find(|bc| bc.name.eq_ignore_ascii_case(&ctx_name))
+            {
+                Some(bc) => bc,
+                None => return error_result(format!("Bounded context '{ctx_name}' not found")),
+            };
+
+            let names = |key: &str| {
+                args.get(key).and_then(|v| v.as_array()).map(|a| {
+                    a.iter()
+                        .filter_map(|n| n.as_str().map(String::from))
+                        .collect::<Vec<_>>()
+                })
+            };
+            let kind = args.get("kind").and_then(|v| v.as_str()).map(|k| match k {
+                "saga" => PolicyKind::Saga,
+                _ => PolicyKind::Policy,
+            });
+
+            let created = match bc
+                .policies
+                .iter_mut()
+                .find(|p| p.name.eq_ignore_ascii_case(&policy_name))
+            {
+                Some(policy) => {
+                    if let Some(desc) = args.get("description").and_then(|v| v.as_str()) {
+                        policy.description = desc.to_string();
+                    }
+                    if let Some(kind) = kind {
+                        policy.kind = kind;
+                    }
+                    if let Some(events) = names("reacts_to") {
+                        policy.reacts_to = events;
+                    }
+                    if let Some(commands) = names("issues") {
+                        policy.issues = commands;
+                    }
+                    false
+                }
+                None => {
+                    bc.policies.push(Policy {
+                        name: policy_name.clone(),
+                        description: arg_str(args, "description"),
+                        kind: kind.unwrap_or_default(),
+                        reacts_to: names("reacts_to").unwrap_or_default(),
+                        issues: names("issues").unwrap_or_default(),
+                        meta: Metadata::default(),
+                    });
+                    true
+                }
+            };
+
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Invalid policy: {e}"));
+            }
+            let verb = if created { "Created" } else { "Updated" };
+            text_result(format!("{verb} policy '{policy_name}' in '{ctx_name}'"))
+        }
+
+        "define_term" => {
+            let ctx_name = arg_str(args, "context");
+            let word = arg_str(args, "term");
+            if word.is_empty() {
+                return error_result("'term' is required");
+            }
+            let before = model.clone();
+
+            let bc = match model
+                .bounded_contexts
+                .iter_mut()
+                .find(|bc| bc.name.eq_ignore_ascii_case(&ctx_name))
+            {
+                Some(bc) => bc,
+                None => return error_result(format!("Bounded context '{ctx_name}' not found")),
+            };
+
+            let words = |key: &str| {
+                args.get(key).and_then(|v| v.as_array()).map(|a| {
+                    a.iter()
+                        .filter_map(|n| n.as_str().map(String::from))
+                        .collect::<Vec<_>>()
+                })
+            };
+
+            let created = match bc.glossary.iter_mut().find(|t| t.term.eq_ignore_ascii_case(&word)) {
+                Some(term) => {
+                    if let Some(def) = args.get("definition").and_then(|v| v.as_str()) {
+                        term.definition = def.to_string();
+                    }
+                    if let Some(synonyms) = words("synonyms") {
+                        term.synonyms = synonyms;
+                    }
+                    if let Some(aliases) = words("forbidden_aliases") {
+                        term.forbidden_aliases = aliases;
+                    }
+                    false
+                }
+                None => {
+                    bc.glossary.push(Term {
+                        term: word.clone(),
+                        definition: arg_str(args, "definition"),
+                        synonyms: words("synonyms").unwrap_or_default(),
+                        forbidden_aliases: words("forbidden_aliases").unwrap_or_default(),
+                    });
+                    true
+                }
+            };
+
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Invalid term: {e}"));
+            }
+            let verb = if created { "Defined" } else { "Updated" };
+            let prefix = format!("{}.", ctx_name.to_lowercase());
+            let clashes: Vec<String> = validation::check(model)
+                .into_iter()
+                .filter(|f| f.path.to_lowercase().starts_with(&prefix))
+                .map(|f| format!("{}: {}", f.path, f.message))
+                .collect();
+            if clashes.is_empty() {
+                text_result(format!("{verb} term '{word}' in '{ctx_name}' glossary"))
+            } else {
+                text_result(format!(
+                    "{verb} term '{word}' in '{ctx_name}' glossary. Names to fix:\n{}",
+                    clashes.join("\n")
+                ))
+            }
+        }
+
+        "set_metadata" => {
+            let ctx_name = arg_str(args, "context");
+            let element = arg_str(args, "element");
+            let status = match args.get("status").and_then(|v| v.as_str()) {
+                Some(s) => match Status::parse(s) {
+                    Some(status) => Some(status),
+                    None => return error_result(format!("Unknown status '{s}'. Use proposed, active or deprecated")),
+                },
+                None => None,
+            };
+            let links = match args.get("links") {
+                Some(v) => match serde_json::from_value::<Vec<Link>>(v.clone()) {
+                    Ok(links) => Some(links),
+                    Err(e) => return error_result(format!("Invalid links: {e}")),
+                },
+                None => None,
+            };
+            let before = model.clone();
+
+            let bc = match model
+                .bounded_contexts
+                .iter_mut()
+                .find(|bc| bc.name.eq_ignore_ascii_case(&ctx_name))
+            {
+                Some(bc) => bc,
+                None => return error_result(format!("Bounded context '{ctx_name}' not found")),
+            };
+            let (kind, meta) = if element.is_empty() {
+                ("bounded context", &mut bc.meta)
+            } else {
+                match bc.element_meta_mut(&element) {
+                    Some((kind, meta)) => (kind, meta),
+                    None => return error_result(format!("'{element}' not found in '{ctx_name}'")),
+                }
+            };
+
+            let words = |key: &str| {
+                args.get(key).and_then(|v| v.as_array()).map(|a| {
+                    a.iter()
+                        .filter_map(|n| n.as_str().map(String::from))
+                        .collect::<Vec<_>>()
+                })
+            };
+            if let Some(owners) = words("owners") {
+                meta.owners = owners;
+            }
+            if let Some(tags) = words("tags") {
+                meta.tags = tags;
+            }
+            if let Some(status) = status {
+                // A replacement and sunset only describe a deprecation
+                if status != Status::Deprecated {
+                    meta.replaced_by.clear();
+                    meta.sunset.clear();
+                }
+                meta.status = status;
+            }
+            if let Some(links) = links {
+                meta.links = links;
+            }
+
+            let target = if element.is_empty() { ctx_name } else { element };
+            let message = format!(
+                "Updated metadata of {} '{}': {}",
+                kind.replace('_', " "),
+                target,
+                serde_json::to_string(meta).unwrap()
+            );
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Invalid metadata: {e}"));
+            }
+            text_result(message)
+        }
+
+        "deprecate_element" => {
+            let ctx_name = arg_str(args, "context");
+            let element = arg_str(args, "element");
+            let before = model.clone();
+
+            let bc = match model
+                .bounded_contexts
+                .iter_mut()
+                .find(|bc| bc.name.eq_ignore_ascii_case(&ctx_name))
+            {
+                Some(bc) => bc,
+                None => return error_result(format!("Bounded context '{ctx_name}' not found")),
+            };
+            let Some((_, meta)) = bc.element_meta_mut(&element) else {
+                return error_result(format!("'{element}' not found in '{ctx_name}'"));
+            };
+            meta.status = Status::Deprecated;
+            meta.replaced_by = arg_str(args, "replaced_by");
+            meta.sunset = arg_str(args, "sunset");
+            let warning = meta.deprecation_warning(&element).unwrap_or_default();
+
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Invalid deprecation: {e}"));
+            }
+            let usages: Vec<String> = DomainRegistry::new(model)
+                .usages(&ctx_name, &element)
+                .into_iter()
+                .filter(|u| !(u.context.eq_ignore_ascii_case(&ctx_name) && u.element.eq_ignore_ascii_case(&element)))
+                .map(|u| format!("- {}.{} ({})", u.context, u.element, u.via))
+                .collect();
+            if usages.is_empty() {
+                text_result(format!("{warning}. Nothing uses it."))
+            } else {
+                text_result(format!("{warning}. Usages to migrate:\n{}", usages.join("\n")))
+            }
+        }
+
+        "update_external_system" => {
+            let system_name = arg_str(args, "name");
+            if system_name.is_empty() {
+                return error_result("'name' is required");
+            }
+            let before = model.clone();
+
+            let integrations = args.get("integrations").and_then(|v| v.as_array()).map(|a| {
+                a.iter()
+                    .filter_map(|i| match i {
+                        Value::String(context) => Some(Integration {
+                            context: context.clone(),
+                            adapter: String::new(),
+                        }),
+                        Value::Object(_) => Some(Integration {
+                            context: arg_str(i, "context"),
+                            adapter: arg_str(i, "adapter"),
+                        }),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            });
+
+            let created = match model
+                .external_systems
+                .iter_mut()
+                .find(|s| s.name.eq_ignore_ascii_case(&system_name))
+            {
+                Some(system) => {
+                    if let Some(desc) = args.get("description").and_then(|v| v.as_str()) {
+                        system.description = desc.to_string();
+                    }
+                    if let Some(protocol) = args.get("protocol").and_then(|v| v.as_str()) {
+                        system.protocol = protocol.to_string();
+                    }
+                    if let Some(integrations) = integrations {
+                        system.integrations = integrations;
+                    }
+                    false
+                }
+                None => {
+                    model.external_systems.push(ExternalSystem {
+                        name: system_name.clone(),
+                        description: arg_str(args, "description"),
+                        protocol: arg_str(args, "protocol"),
+                        integrations: integrations.unwrap_or_default(),
+                    });
+                    true
+                }
+            };
+
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Invalid external system: {e}"));
+            }
+            let verb = if created { "Created" } else { "Updated" };
+            text_result(format!("{verb} external system '{system_name}'"))
+        }
+
+        "remove_entity" => {
+            let ctx_name = arg_str(args, "context");
+            let entity_name = arg_str(args, "name");
+            let snapshot = model.clone();
+
+            let bc = match model
+                .bounded_contexts
+                .iter_mut()
+                .find(|bc| bc.name.eq_ignore_ascii_case(&ctx_name))
+            {
+                Some(bc) => bc,
+                None => return error_result(format!("Bounded context '{ctx_name}' not found")),
+            };
+
+            let before = bc.entities.len();
+            bc.entities
+                .retain(|e| !e.name.eq_ignore_ascii_case(&entity_name));
+
+            if bc.entities.len() == before {
+                return error_result(format!(
+                    "Entity '{entity_name}' not found in '{ctx_name}'"
+                ));
+            }
+
+            if let Err(e) = model.validate() {
+                *model = snapshot;
+                let usages: Vec<String> = DomainRegistry::new(model)
+                    .usages(&ctx_name, &entity_name)
+                    .into_iter()
+                    .filter(|u| !(u.context.eq_ignore_ascii_case(&ctx_name) && u.element.eq_ignore_ascii_case(&entity_name)))
+                    .map(|u| format!("- {}.{} ({})", u.context, u.element, u.via))
+                    .collect();
+                return error_result(format!(
+                    "Cannot remove entity '{entity_name}': {e}. Still used by:\n{}",
+                    usages.join("\n")
+                ));
+            }
+            text_result(format!(
+                "Removed entity '{entity_name}' from '{ctx_name}'"
+            ))
+        }
+
+        "import_spec" => {
+            let format = arg_str(args, "format");
+            let ctx_name = arg_str(args, "context");
+            let mut paths: Vec<String> = args
+                .get("paths")
+                .and_then(|v| v.as_array())
+                .map(|a| a.iter().filter_map(|p| p.as_str().map(String::from)).collect())
+                .unwrap_or_default();
+            if let Some(p) = args.get("path").and_then(|v| v.as_str()) {
+                paths.insert(0, p.to_string());
+            }
+            let paths = match paths.iter().map(|p| workspace_file(workspace_path, p)).collect::<anyhow::Result<Vec<_>>>() {
+                Ok(paths) => paths,
+                Err(e) => return error_result(format!("Failed to import: {e:#}")),
+            };
+
+            let drafts = match import::import_files(&format, &paths, &ctx_name) {
+                Ok(d) => d,
+                Err(e) => return error_result(format!("Failed to import {}: {e:#}", paths.join(", "))),
+            };
+            let before = model.clone();
+            for draft in drafts {
+                import::merge_context(model, draft);
+            }
+            if let Err(e) = model.validate() {
+                *model = before;
+                return error_result(format!("Import would leave the model invalid: {e}"));
+            }
+            let changes = diff::diff_models(&before, model);
+
+            text_result(
+                json!({
+                    "status": "imported",
+                    "format": format,
+                    "context": ctx_name,
+                    "change_count": changes.len(),
+                    "changes": changes,
+                    "next": "Review with compare_model, then save_model to persist"
+                })
+                .to_string(),
+            )
+        }
+
+        "compare_model" => {
+            // Load the persisted model from the store and diff against current in-memory state
+            match load_changes(store, workspace_path, model) {
+                Ok(changes) => {
+                    if changes.is_empty() {
+                        text_result(
+                            json!({
+                                "status": "no_changes",
+                                "message": "In-memory model matches persisted model"
+                            })
+                            .to_string(),
+                        )
+                    } else {
+                        text_result(
+                            json!({
+                                "status": "changes_detected",
+                                "change_count": changes.len(),
+                                "changes": changes
+                            })
+                            .to_string(),
+                        )
+                    }
+                }
+                Err(e) => error_result(format!("Failed to compare models: {e}")),
+            }
+        }
+
+        "draft_refactoring_plan" => {
+            match load_changes(store, workspace_path, model) {
+                Ok(changes) => {
+                    if changes.is_empty() {
+                        text_result(
+                            json!({
+                                "status": "no_changes",
+                                "message": "In-memory model matches persisted model. Nothing to refactor."
+                            })
+                            .to_string(),
+                        )
+                    } else {
+                        let plan = diff::plan_refactoring(&changes, model);
+                        text_result(serde_json::to_string(&plan).unwrap())
+                    }
+                }
+                Err(e) => error_result(format!("Failed to load persisted model: {e}")),
+            }
+        }
+
+        "save_model" => match store.save(workspace_path, model) {
+            Ok(()) => text_result(format!("Domain model saved to store for workspace: {workspace_path}")),
+            Err(e) => error_result(format!("Failed to save: {e}")),
+        },
+
+        _ => error_result(format!("Unknown write tool: {name}")),
+    }
+}
+
+// ─── Helpers ───────────────────────────────────────────────────────────────
+
+fn text_result(text: impl Into<String>) -> ToolCallResult {
+    ToolCallResult {
+        content: vec![ContentBlock::Text { text: text.into() }],
+        is_error: None,
+    }
+}
+
+fn error_result(msg: impl Into<String>) -> ToolCallResult {
+    ToolCallResult {
         content: vec![ContentBlock::Text { text: msg.into() }],
         is_error: Some(true),
     }
@@ -558,6 +1462,40 @@ fn load_changes(
     Ok(diff::diff_models(&persisted, model))
 }
 
+/// Resolve a tool-supplied file path against the workspace root, refusing files outside it.
+fn workspace_file(workspace_path: &str, path: &str) -> anyhow::Result<String> {
+    use anyhow::Context;
+    let root = std::path::Path::new(workspace_path)
+        .canonicalize()
+        .with_context(|| format!("workspace '{workspace_path}' is not accessible"))?;
+    let file = root.join(path).canonicalize().with_context(|| format!("cannot read '{path}'"))?;
+    if !file.starts_with(&root) {
+        anyhow::bail!("'{path}' is outside the workspace");
+    }
+    Ok(file.to_string_lossy().to_string())
+}
+
+/// Accept a qualified element name (`Billing::Account`) wherever a tool takes a context
+/// and an element: the qualifier fills in `context` and must agree with it when both
+/// are given.
+fn unqualify(args: &Value, key: &str) -> Result<Value, String> {
+    let given = arg_str(args, "context");
+    let (context, element) = split_qualified(args.get(key).and_then(|v| v.as_str()).unwrap_or(""));
+    let Some(context) = context else {
+        if given.is_empty() {
+            return Err(format!("'context' is required unless '{key}' is qualified, e.g. Billing::Account"));
+        }
+        return Ok(args.clone());
+    };
+    if !given.is_empty() && !given.eq_ignore_ascii_case(context) {
+        return Err(format!("'{key}' is qualified with '{context}' but 'context' is '{given}'"));
+    }
+    let mut args = args.clone();
+    args[key] = json!(element);
+    args["context"] = json!(context);
+    Ok(args)
+}
+
 fn arg_str(args: &Value, key: &str) -> String {
     args.get(key)
         .and_then(|v| v.as_str())
@@ -686,13 +1624,24 @@ mod tests {
                     }],
                     methods: vec![],
                     invariants: vec!["Email must be unique".into()],
+                    state_machine: None,
+                    meta: Metadata::default(),
                 }],
                 value_objects: vec![],
+                enumerations: vec![],
+                aggregates: vec![],
+                commands: vec![],
+                queries: vec![],
+                use_cases: vec![],
+                policies: vec![],
+                glossary: vec![],
                 services: vec![],
                 repositories: vec![],
                 events: vec![],
                 dependencies: vec![],
+                meta: Metadata::default(),
             }],
+            external_systems: vec![],
             rules: vec![],
             tech_stack: TechStack::default(),
             conventions: Conventions::default(),
@@ -701,7 +1650,7 @@ mod tests {
 
     #[test]
     fn test_list_write_tools_count() {
-        assert_eq!(list_write_tools().len(), 8);
+        assert_eq!(list_write_tools().len(), 17);
     }
 
     #[test]
@@ -740,14 +1689,50 @@ mod tests {
                 "fields": [{"name": "id", "type": "Uuid"}]
             }),
         );
-        assert!(result.is_error.is_none());
-        let user = &model.bounded_contexts[0].entities[0];
-        assert_eq!(user.fields.len(), 1);
-        assert_eq!(user.fields[0].field_type, "Uuid");
+        assert!(result.is_error.is_none());
+        let user = &model.bounded_contexts[0].entities[0];
+        assert_eq!(user.fields.len(), 1);
+        assert_eq!(user.fields[0].field_type, "Uuid");
+    }
+
+    #[test]
+    fn test_create_new_entity() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_entity",
+            &json!({
+                "context": "Identity",
+                "name": "Role",
+                "description": "A role assignment",
+                "aggregate_root": false,
+                "fields": [{"name": "name", "type": "String"}]
+            }),
+        );
+        assert!(result.is_error.is_none());
+        assert_eq!(model.bounded_contexts[0].entities.len(), 2);
+        assert_eq!(model.bounded_contexts[0].entities[1].name, "Role");
+    }
+
+    #[test]
+    fn test_update_entity_context_not_found() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_entity",
+            &json!({"context": "Nonexistent", "name": "Foo"}),
+        );
+        assert_eq!(result.is_error, Some(true));
     }
 
     #[test]
-    fn test_create_new_entity() {
+    fn test_qualified_element_names() {
         let mut model = test_model();
         let store = test_store();
         let result = call_write_tool(
@@ -756,30 +1741,26 @@ mod tests {
             &store,
             "update_entity",
             &json!({
-                "context": "Identity",
-                "name": "Role",
-                "description": "A role assignment",
-                "aggregate_root": false,
-                "fields": [{"name": "name", "type": "String"}]
+                "name": "identity::User",
+                "fields": [{"name": "email", "type": "String"}]
             }),
         );
         assert!(result.is_error.is_none());
-        assert_eq!(model.bounded_contexts[0].entities.len(), 2);
-        assert_eq!(model.bounded_contexts[0].entities[1].name, "Role");
-    }
+        assert_eq!(model.bounded_contexts[0].entities[0].fields.len(), 2);
 
-    #[test]
-    fn test_update_entity_context_not_found() {
-        let mut model = test_model();
-        let store = test_store();
         let result = call_write_tool(
             &mut model,
             "/tmp/test-ws",
             &store,
-            "update_entity",
-            &json!({"context": "Nonexistent", "name": "Foo"}),
+            "set_metadata",
+            &json!({"context": "Billing", "element": "Identity::User", "tags": ["core"]}),
         );
         assert_eq!(result.is_error, Some(true));
+        let result = call_write_tool(&mut model, "/tmp/test-ws", &store, "remove_entity", &json!({"name": "User"}));
+        assert_eq!(result.is_error, Some(true));
+        match &result.content[0] {
+            ContentBlock::Text { text } => assert!(text.contains("unless 'name' is qualified")),
+        }
     }
 
     #[test]
@@ -838,6 +1819,30 @@ mod tests {
         assert_eq!(model.bounded_contexts[0].entities.len(), 0);
     }
 
+    #[test]
+    fn test_remove_entity_still_referenced() {
+        let mut model = test_model();
+        model.bounded_contexts[0].repositories.push(Repository {
+            name: "UserRepository".into(),
+            aggregate: "User".into(),
+            methods: vec![],
+            meta: Metadata::default(),
+        });
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "remove_entity",
+            &json!({"context": "Identity", "name": "User"}),
+        );
+        assert_eq!(result.is_error, Some(true));
+        match &result.content[0] {
+            ContentBlock::Text { text } => assert!(text.contains("- Identity.UserRepository (aggregate)")),
+        }
+        assert_eq!(model.bounded_contexts[0].entities.len(), 1);
+    }
+
     #[test]
     fn test_remove_entity_not_found() {
         let mut model = test_model();
@@ -897,6 +1902,320 @@ mod tests {
         assert_eq!(model.bounded_contexts[0].events[0].name, "UserRegistered");
     }
 
+    #[test]
+    fn test_update_aggregate_with_child_entity() {
+        let mut model = test_model();
+        let store = test_store();
+        call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_entity",
+            &json!({"context": "Identity", "name": "Session"}),
+        );
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_aggregate",
+            &json!({"context": "Identity", "name": "Account", "root": "User", "entities": ["Session"]}),
+        );
+        assert!(result.is_error.is_none());
+        let bc = &model.bounded_contexts[0];
+        assert_eq!(bc.aggregates[0].entities, vec!["Session"]);
+        assert_eq!(bc.aggregate_of("Session"), Some(("Account", "User")));
+    }
+
+    #[test]
+    fn test_update_aggregate_rejects_unknown_member() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_aggregate",
+            &json!({"context": "Identity", "name": "Account", "root": "User", "entities": ["Ghost"]}),
+        );
+        assert_eq!(result.is_error, Some(true));
+        assert!(model.bounded_contexts[0].aggregates.is_empty());
+    }
+
+    #[test]
+    fn test_update_entity_state_machine_requires_methods() {
+        let mut model = test_model();
+        let store = test_store();
+        let args = json!({
+            "context": "Identity",
+            "name": "User",
+            "state_machine": {
+                "field": "status",
+                "states": ["Active", "Suspended"],
+                "initial": "Active",
+                "transitions": [{"from": "Active", "to": "Suspended", "method": "suspend"}]
+            }
+        });
+        let result = call_write_tool(&mut model, "/tmp/test-ws", &store, "update_entity", &args);
+        assert_eq!(result.is_error, Some(true));
+        assert!(model.bounded_contexts[0].entities[0].state_machine.is_none());
+
+        let mut args = args;
+        args["fields"] = json!([{"name": "status", "type": "UserStatus"}]);
+        args["methods"] = json!([{"name": "suspend"}]);
+        let result = call_write_tool(&mut model, "/tmp/test-ws", &store, "update_entity", &args);
+        assert!(result.is_error.is_none());
+        let sm = model.bounded_contexts[0].entities[0].state_machine.as_ref().unwrap();
+        assert_eq!(sm.transitions[0].method, "suspend");
+    }
+
+    #[test]
+    fn test_update_enumeration_replaces_variants() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_enumeration",
+            &json!({"context": "Identity", "name": "UserStatus", "variants": ["Active", {"name": "Suspended", "description": "Temporarily locked"}]}),
+        );
+        assert!(result.is_error.is_none());
+        assert_eq!(model.bounded_contexts[0].enumerations[0].variants[1].description, "Temporarily locked");
+
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_enumeration",
+            &json!({"context": "Identity", "name": "UserStatus", "variants": ["Active", "Active"]}),
+        );
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(model.bounded_contexts[0].enumerations[0].variants.len(), 2);
+    }
+
+    #[test]
+    fn test_update_operation_creates_command() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_operation",
+            &json!({
+                "context": "Identity",
+                "kind": "command",
+                "name": "DeactivateUser",
+                "aggregate": "User",
+                "fields": [{"name": "reason", "type": "String"}],
+                "invariants": ["User must be active"]
+            }),
+        );
+        assert!(result.is_error.is_none());
+        let cmd = &model.bounded_contexts[0].commands[0];
+        assert_eq!(cmd.aggregate, "User");
+        assert_eq!(cmd.fields[0].name, "reason");
+        assert_eq!(cmd.invariants, vec!["User must be active"]);
+    }
+
+    #[test]
+    fn test_update_operation_rejects_unknown_event() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_operation",
+            &json!({"context": "Identity", "kind": "command", "name": "DeactivateUser", "emits": ["Ghost"]}),
+        );
+        assert_eq!(result.is_error, Some(true));
+        assert!(model.bounded_contexts[0].commands.is_empty());
+    }
+
+    #[test]
+    fn test_update_policy_requires_known_event() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_policy",
+            &json!({"context": "Identity", "name": "WelcomeUser", "reacts_to": ["UserRegistered"]}),
+        );
+        assert_eq!(result.is_error, Some(true));
+        assert!(model.bounded_contexts[0].policies.is_empty());
+
+        call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_event",
+            &json!({"context": "Identity", "name": "UserRegistered"}),
+        );
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_policy",
+            &json!({"context": "Identity", "name": "WelcomeUser", "kind": "saga", "reacts_to": ["UserRegistered"]}),
+        );
+        assert!(result.is_error.is_none());
+        assert_eq!(model.bounded_contexts[0].policies[0].kind, PolicyKind::Saga);
+    }
+
+    #[test]
+    fn test_define_term_reports_forbidden_names() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "define_term",
+            &json!({"context": "Identity", "term": "Member", "definition": "A registered person", "forbidden_aliases": ["User"]}),
+        );
+        assert!(result.is_error.is_none());
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        assert!(text.contains("Identity.User:"));
+        assert_eq!(model.bounded_contexts[0].glossary[0].definition, "A registered person");
+
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "define_term",
+            &json!({"context": "Identity", "term": "Person", "synonyms": ["member"]}),
+        );
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(model.bounded_contexts[0].glossary.len(), 1);
+    }
+
+    #[test]
+    fn test_set_metadata() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "set_metadata",
+            &json!({"context": "Identity", "owners": ["team-identity"], "links": [{"title": "ADR-7", "url": "https://example.com/adr/7"}]}),
+        );
+        assert!(result.is_error.is_none());
+        assert_eq!(model.bounded_contexts[0].meta.owners, vec!["team-identity"]);
+        assert_eq!(model.bounded_contexts[0].meta.links[0].title, "ADR-7");
+
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "set_metadata",
+            &json!({"context": "Identity", "element": "user", "status": "deprecated", "tags": ["legacy"]}),
+        );
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        assert!(text.starts_with("Updated metadata of entity 'user'"));
+        assert_eq!(model.bounded_contexts[0].entities[0].meta.status, Status::Deprecated);
+
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "set_metadata",
+            &json!({"context": "Identity", "element": "User", "status": "retired"}),
+        );
+        assert_eq!(result.is_error, Some(true));
+
+        // Reactivating drops the replacement, so the model stays valid
+        let mut account = model.bounded_contexts[0].entities[0].clone();
+        account.name = "Account".into();
+        model.bounded_contexts[0].entities.push(account);
+        let meta = &mut model.bounded_contexts[0].entities[0].meta;
+        meta.replaced_by = "Account".into();
+        meta.sunset = "Q3".into();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "set_metadata",
+            &json!({"context": "Identity", "element": "User", "status": "active"}),
+        );
+        assert!(result.is_error.is_none());
+        let meta = &model.bounded_contexts[0].entities[0].meta;
+        assert!(meta.replaced_by.is_empty() && meta.sunset.is_empty());
+        assert!(model.validate().is_ok());
+    }
+
+    #[test]
+    fn test_deprecate_element_lists_usages() {
+        let mut model = test_model();
+        let store = test_store();
+        let mut account = model.bounded_contexts[0].entities[0].clone();
+        account.name = "Account".into();
+        model.bounded_contexts[0].entities.push(account);
+        model.bounded_contexts[0].repositories.push(Repository {
+            name: "UserRepository".into(),
+            aggregate: "User".into(),
+            methods: vec![],
+            meta: Metadata::default(),
+        });
+
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "deprecate_element",
+            &json!({"context": "Identity", "element": "User", "replaced_by": "Member"}),
+        );
+        assert_eq!(result.is_error, Some(true));
+        assert!(model.bounded_contexts[0].entities[0].meta.status.is_active());
+
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "deprecate_element",
+            &json!({"context": "Identity", "element": "User", "replaced_by": "Account", "sunset": "Q3"}),
+        );
+        let text = match &result.content[0] {
+            ContentBlock::Text { text } => text,
+        };
+        assert_eq!(
+            text,
+            "'User' is deprecated — use 'Account' instead (sunset: Q3). Usages to migrate:\n- Identity.UserRepository (aggregate)"
+        );
+    }
+
+    #[test]
+    fn test_update_external_system() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_external_system",
+            &json!({"name": "Stripe", "protocol": "REST", "integrations": [{"context": "Identity", "adapter": "StripeGateway"}]}),
+        );
+        assert_eq!(result.is_error, Some(true));
+        assert!(model.external_systems.is_empty());
+
+        let result = call_write_tool(
+            &mut model,
+            "/tmp/test-ws",
+            &store,
+            "update_external_system",
+            &json!({"name": "Stripe", "protocol": "REST", "integrations": ["Identity"]}),
+        );
+        assert!(result.is_error.is_none());
+        assert_eq!(model.external_systems[0].integrations[0].context, "Identity");
+    }
+
     #[test]
     fn test_unknown_write_tool() {
         let mut model = test_model();
@@ -942,6 +2261,7 @@ mod tests {
             file_structure: FileStructure {
                 pattern: "src/{context}/{layer}/{type}.rs".into(),
                 layers: vec!["domain".into(), "application".into()],
+                ..Default::default()
             },
             ..Default::default()
         };
@@ -993,4 +2313,68 @@ mod tests {
         );
         assert_eq!(model.bounded_contexts[0].services[0].methods.len(), 2);
     }
+
+    #[test]
+    fn test_import_spec_openapi_merges_into_context() {
+        let mut model = test_model();
+        let store = test_store();
+        let workspace = temp_dir().join(format!("domcp_import_ws_{}", std::process::id()));
+        std::fs::create_dir_all(&workspace).unwrap();
+        std::fs::write(
+            workspace.join("openapi.json"),
+            json!({
+                "openapi": "3.0.0",
+                "paths": {},
+                "components": { "schemas": {
+                    "User": { "type": "object", "required": ["email"],
+                              "properties": { "id": { "type": "string" }, "email": { "type": "string" } } }
+                } }
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let ws = workspace.to_string_lossy();
+
+        let result = call_write_tool(
+            &mut model, &ws, &store, "import_spec",
+            &json!({"format": "openapi", "path": "openapi.json", "context": "Identity"}),
+        );
+        assert!(result.is_error.is_none());
+        let user = &model.bounded_contexts[0].entities[0];
+        assert_eq!(user.fields.len(), 2);
+        assert!(user.fields.iter().any(|f| f.name == "email" && f.required));
+
+        // An invalid result is rolled back
+        let before = serde_json::to_value(&model).unwrap();
+        let result = call_write_tool(
+            &mut model, &ws, &store, "import_spec",
+            &json!({"format": "openapi", "path": "openapi.json", "context": ""}),
+        );
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(serde_json::to_value(&model).unwrap(), before);
+
+        // Files outside the workspace are refused
+        std::fs::write(temp_dir().join("domcp_outside.json"), "{}").unwrap();
+        for path in ["/etc/passwd", "../domcp_outside.json"] {
+            let result = call_write_tool(
+                &mut model, &ws, &store, "import_spec",
+                &json!({"format": "openapi", "path": path, "context": "Identity"}),
+            );
+            assert_eq!(result.is_error, Some(true));
+            match &result.content[0] {
+                ContentBlock::Text { text } => assert!(text.contains("outside the workspace")),
+            }
+        }
+    }
+
+    #[test]
+    fn test_import_spec_unknown_format() {
+        let mut model = test_model();
+        let store = test_store();
+        let result = call_write_tool(
+            &mut model, "/tmp/test-ws", &store, "import_spec",
+            &json!({"format": "wsdl", "path": "/dev/null", "context": "Identity"}),
+        );
+        assert_eq!(result.is_error, Some(true));
+    }
 }
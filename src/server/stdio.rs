@@ -12,7 +12,16 @@ const WRITE_TOOLS: &[&str] = &[
     "update_entity",
     "update_service",
     "update_event",
+    "update_enumeration",
+    "update_aggregate",
+    "update_operation",
+    "update_policy",
+    "define_term",
+    "set_metadata",
+    "deprecate_element",
+    "update_external_system",
     "remove_entity",
+    "import_spec",
     "compare_model",
     "draft_refactoring_plan",
     "save_model",
@@ -65,7 +65,9 @@ impl Store {
     }
 
     /// Save (upsert) a domain model for a workspace.
+    /// Rejects models that fail validation (e.g. repositories for non-root entities).
     pub fn save(&self, workspace_path: &str, model: &DomainModel) -> Result<()> {
+        model.validate()?;
         let canonical = canonicalize_path(workspace_path);
         let json = serde_json::to_string_pretty(model)
             .context("Failed to serialize domain model")?;
@@ -158,6 +160,7 @@ mod tests {
             name: name.to_string(),
             description: "Test project".into(),
             bounded_contexts: vec![],
+            external_systems: vec![],
             rules: vec![],
             tech_stack: TechStack::default(),
             conventions: Conventions::default(),
@@ -212,4 +215,12 @@ mod tests {
         let loaded = store.load("/tmp/my-project").unwrap().unwrap();
         assert_eq!(loaded.name, "V2");
     }
+
+    #[test]
+    fn test_save_rejects_invalid_model() {
+        let store = temp_store();
+        let model = test_model("");
+        assert!(store.save("/tmp/my-project", &model).is_err());
+        assert!(store.load("/tmp/my-project").unwrap().is_none());
+    }
 }